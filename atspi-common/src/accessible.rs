@@ -4,6 +4,12 @@ use zvariant::{ObjectPath, OwnedObjectPath, Signature, Type};
 
 pub const ACCESSIBLE_PAIR_SIGNATURE: Signature<'_> = Signature::from_static_str_unchecked("(so)");
 
+/// The object path used by implementations to refer to "no object".
+pub const NULL_PATH: &str = "/org/a11y/atspi/null";
+
+/// The object path of the root object of every application.
+pub const ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+
 // TODO: Try to make borrowed versions work,
 // check where the lifetimes of the borrow are tied to, see also: comment on `interface()` method
 // in `DefaultEvent` impl
@@ -27,6 +33,30 @@ impl Default for Accessible {
 	}
 }
 
+impl Accessible {
+	/// Whether this refers to no object at all.
+	///
+	/// Methods and properties that return an [`Accessible`] use either [`NULL_PATH`]
+	/// or the path of [`Accessible::default`] to signal the absence of an object,
+	/// for example a table without a caption.
+	#[must_use]
+	pub fn is_null(&self) -> bool {
+		self.path.as_str() == NULL_PATH || self.path.as_str() == "/org/a11y/atspi/accessible/null"
+	}
+}
+
+#[test]
+fn test_accessible_is_null() {
+	assert!(Accessible::default().is_null());
+	let acc = Accessible { name: String::new(), path: NULL_PATH.try_into().unwrap() };
+	assert!(acc.is_null());
+	let acc = Accessible {
+		name: ":1.1".into(),
+		path: "/org/a11y/atspi/accessible/root".try_into().unwrap(),
+	};
+	assert!(!acc.is_null());
+}
+
 #[test]
 fn test_accessible_signature() {
 	assert_eq!(
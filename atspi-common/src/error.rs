@@ -42,6 +42,24 @@ pub enum AtspiError {
 	/// A `zbus` or `zbus::Fdo` error. variant.
 	Zbus(String),
 
+	/// The object no longer exists, usually because it was destroyed.
+	ObjectDefunct(String),
+
+	/// The object does not have the method or property called.
+	UnknownMethod(String),
+
+	/// The object does not implement the interface called.
+	InterfaceNotImplemented(String),
+
+	/// The application owning the object is gone from the bus, usually because it exited.
+	ServiceUnknown(String),
+
+	/// No reply arrived in time.
+	Timeout(String),
+
+	/// The call was refused.
+	AccessDenied(String),
+
 	/// A `zbus_names` error variant
 	ZBusNames(zbus_names::Error),
 
@@ -87,6 +105,14 @@ impl std::fmt::Display for AtspiError {
 			Self::CacheVariantMismatch => f.write_str("atspi: Cache variant mismatch"),
 			Self::Owned(e) => f.write_str(&format!("atspi: other error: {e}")),
 			Self::Zbus(e) => f.write_str(&format!("ZBus Error: {e}")),
+			Self::ObjectDefunct(e) => f.write_str(&format!("atspi: object is defunct: {e}")),
+			Self::UnknownMethod(e) => f.write_str(&format!("atspi: unknown method: {e}")),
+			Self::InterfaceNotImplemented(e) => {
+				f.write_str(&format!("atspi: interface not implemented: {e}"))
+			}
+			Self::ServiceUnknown(e) => f.write_str(&format!("atspi: application is gone: {e}")),
+			Self::Timeout(e) => f.write_str(&format!("atspi: call timed out: {e}")),
+			Self::AccessDenied(e) => f.write_str(&format!("atspi: access denied: {e}")),
 			Self::Zvariant(e) => f.write_str(&format!("Zvariant error: {e}")),
 			Self::ZBusNames(e) => f.write_str(&format!("ZBus_names Error: {e}")),
 			Self::ParseError(e) => f.write_str(e),
@@ -103,6 +129,41 @@ impl std::fmt::Display for AtspiError {
 	}
 }
 
+impl AtspiError {
+	/// Classifies a D-Bus error reply by its error name, such as
+	/// `org.freedesktop.DBus.Error.UnknownObject`.
+	///
+	/// Returns `None` for errors without a variant of their own.
+	#[must_use]
+	pub fn from_dbus_error(name: &str, description: Option<&str>) -> Option<Self> {
+		let description = description.unwrap_or(name).to_string();
+		let error = match name.strip_prefix("org.freedesktop.DBus.Error.")? {
+			"UnknownObject" => Self::ObjectDefunct(description),
+			"UnknownMethod" | "UnknownProperty" => Self::UnknownMethod(description),
+			"UnknownInterface" => Self::InterfaceNotImplemented(description),
+			"ServiceUnknown" | "NameHasNoOwner" => Self::ServiceUnknown(description),
+			"NoReply" | "Timeout" | "TimedOut" => Self::Timeout(description),
+			"AccessDenied" | "AuthFailed" => Self::AccessDenied(description),
+			_ => return None,
+		};
+		Some(error)
+	}
+
+	/// Whether the object called, or the application owning it, is gone.
+	///
+	/// Such errors are expected while walking a tree which changes, and the object is best skipped.
+	#[must_use]
+	pub fn is_defunct(&self) -> bool {
+		matches!(self, Self::ObjectDefunct(_) | Self::ServiceUnknown(_))
+	}
+
+	/// Whether the object does not support the method or interface called.
+	#[must_use]
+	pub fn is_unsupported(&self) -> bool {
+		matches!(self, Self::UnknownMethod(_) | Self::InterfaceNotImplemented(_))
+	}
+}
+
 impl From<std::convert::Infallible> for AtspiError {
 	fn from(_e: std::convert::Infallible) -> Self {
 		Self::Infallible
@@ -117,14 +178,27 @@ impl From<std::num::TryFromIntError> for AtspiError {
 #[cfg(feature = "zbus")]
 impl From<zbus::fdo::Error> for AtspiError {
 	fn from(e: zbus::fdo::Error) -> Self {
-		Self::Zbus(format!("{e:?}"))
+		use zbus::DBusError;
+		match e {
+			zbus::fdo::Error::ZBus(e) => e.into(),
+			e => Self::from_dbus_error(e.name().as_str(), e.description())
+				.unwrap_or_else(|| Self::Zbus(format!("{e:?}"))),
+		}
 	}
 }
 
 #[cfg(feature = "zbus")]
 impl From<zbus::Error> for AtspiError {
 	fn from(e: zbus::Error) -> Self {
-		Self::Zbus(format!("{e:?}"))
+		match e {
+			zbus::Error::MethodError(name, description, _) => {
+				Self::from_dbus_error(name.as_str(), description.as_deref()).unwrap_or_else(|| {
+					Self::Zbus(format!("MethodError({name:?}, {description:?})"))
+				})
+			}
+			zbus::Error::FDO(e) => (*e).into(),
+			e => Self::Zbus(format!("{e:?}")),
+		}
 	}
 }
 
@@ -167,3 +241,37 @@ impl std::fmt::Display for ObjectPathConversionError {
 	}
 }
 impl std::error::Error for ObjectPathConversionError {}
+
+#[cfg(test)]
+mod tests {
+	use super::AtspiError;
+
+	#[test]
+	fn classifies_dbus_errors_by_name() {
+		let defunct = AtspiError::from_dbus_error("org.freedesktop.DBus.Error.UnknownObject", None);
+		assert!(matches!(defunct, Some(AtspiError::ObjectDefunct(_))));
+		assert!(defunct.unwrap().is_defunct());
+		let gone = AtspiError::from_dbus_error("org.freedesktop.DBus.Error.ServiceUnknown", None);
+		assert!(gone.unwrap().is_defunct());
+		let method = AtspiError::from_dbus_error(
+			"org.freedesktop.DBus.Error.UnknownMethod",
+			Some("No such method 'GetText'"),
+		);
+		assert!(
+			matches!(&method, Some(AtspiError::UnknownMethod(m)) if m == "No such method 'GetText'")
+		);
+		assert!(method.unwrap().is_unsupported());
+		assert!(AtspiError::from_dbus_error("org.freedesktop.DBus.Error.Failed", None).is_none());
+		assert!(AtspiError::from_dbus_error("org.example.Error.UnknownObject", None).is_none());
+	}
+
+	#[test]
+	fn converts_zbus_errors() {
+		let fdo = zbus::fdo::Error::UnknownInterface("org.a11y.atspi.Text".into());
+		assert!(matches!(AtspiError::from(fdo), AtspiError::InterfaceNotImplemented(_)));
+		let wrapped = zbus::Error::FDO(Box::new(zbus::fdo::Error::AccessDenied(String::new())));
+		assert!(matches!(AtspiError::from(wrapped), AtspiError::AccessDenied(_)));
+		let other = zbus::fdo::Error::Failed("failed".into());
+		assert!(matches!(AtspiError::from(other), AtspiError::Zbus(_)));
+	}
+}
@@ -1,10 +1,17 @@
+//! The events of `org.a11y.atspi.Event.Document`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
+
 use crate::{
 	error::AtspiError,
-	events::{Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString},
+	events::{
+		signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString,
+	},
 	Event,
 };
 use zvariant::ObjectPath;
 
+/// The events of `org.a11y.atspi.Event.Document`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum DocumentEvents {
 	LoadComplete(LoadCompleteEvent),
@@ -18,45 +25,49 @@ impl_event_conversions!(DocumentEvents, Event::Document);
 event_wrapper_test_cases!(DocumentEvents, LoadCompleteEvent);
 
 impl HasMatchRule for DocumentEvents {
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Document'";
+	const MATCH_RULE_STRING: &'static str = signals::DOCUMENT_MATCH_RULE;
 }
 
+/// The `org.a11y.atspi.Event.Document:LoadComplete` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct LoadCompleteEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Document:Reload` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ReloadEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Document:LoadStopped` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct LoadStoppedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Document:ContentChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ContentChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Document:AttributesChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct AttributesChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Document:PageChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct PageChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
 impl GenericEvent<'_> for LoadCompleteEvent {
-	const DBUS_MEMBER: &'static str = "LoadComplete";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Document";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Document',member='LoadComplete'";
+	const DBUS_MEMBER: &'static str = signals::DOCUMENT_LOAD_COMPLETE.member;
+	const DBUS_INTERFACE: &'static str = signals::DOCUMENT_LOAD_COMPLETE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::DOCUMENT_LOAD_COMPLETE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Document:";
 
 	type Body = EventBodyOwned;
@@ -77,10 +88,9 @@ impl GenericEvent<'_> for LoadCompleteEvent {
 }
 
 impl GenericEvent<'_> for ReloadEvent {
-	const DBUS_MEMBER: &'static str = "Reload";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Document";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Document',member='Reload'";
+	const DBUS_MEMBER: &'static str = signals::DOCUMENT_RELOAD.member;
+	const DBUS_INTERFACE: &'static str = signals::DOCUMENT_RELOAD.interface;
+	const MATCH_RULE_STRING: &'static str = signals::DOCUMENT_RELOAD.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Document:";
 
 	type Body = EventBodyOwned;
@@ -101,10 +111,9 @@ impl GenericEvent<'_> for ReloadEvent {
 }
 
 impl GenericEvent<'_> for LoadStoppedEvent {
-	const DBUS_MEMBER: &'static str = "LoadStopped";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Document";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Document',member='LoadStopped'";
+	const DBUS_MEMBER: &'static str = signals::DOCUMENT_LOAD_STOPPED.member;
+	const DBUS_INTERFACE: &'static str = signals::DOCUMENT_LOAD_STOPPED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::DOCUMENT_LOAD_STOPPED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Document:";
 
 	type Body = EventBodyOwned;
@@ -125,10 +134,9 @@ impl GenericEvent<'_> for LoadStoppedEvent {
 }
 
 impl GenericEvent<'_> for ContentChangedEvent {
-	const DBUS_MEMBER: &'static str = "ContentChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Document";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Document',member='ContentChanged'";
+	const DBUS_MEMBER: &'static str = signals::DOCUMENT_CONTENT_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::DOCUMENT_CONTENT_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::DOCUMENT_CONTENT_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Document:";
 
 	type Body = EventBodyOwned;
@@ -149,10 +157,9 @@ impl GenericEvent<'_> for ContentChangedEvent {
 }
 
 impl GenericEvent<'_> for AttributesChangedEvent {
-	const DBUS_MEMBER: &'static str = "AttributesChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Document";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Document',member='AttributesChanged'";
+	const DBUS_MEMBER: &'static str = signals::DOCUMENT_ATTRIBUTES_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::DOCUMENT_ATTRIBUTES_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::DOCUMENT_ATTRIBUTES_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Document:";
 
 	type Body = EventBodyOwned;
@@ -173,10 +180,9 @@ impl GenericEvent<'_> for AttributesChangedEvent {
 }
 
 impl GenericEvent<'_> for PageChangedEvent {
-	const DBUS_MEMBER: &'static str = "PageChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Document";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Document',member='PageChanged'";
+	const DBUS_MEMBER: &'static str = signals::DOCUMENT_PAGE_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::DOCUMENT_PAGE_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::DOCUMENT_PAGE_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Document:";
 
 	type Body = EventBodyOwned;
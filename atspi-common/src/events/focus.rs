@@ -1,10 +1,17 @@
+//! The events of `org.a11y.atspi.Event.Focus`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
+
 use crate::{
 	error::AtspiError,
-	events::{Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString},
+	events::{
+		signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString,
+	},
 	Event,
 };
 use zvariant::ObjectPath;
 
+/// The events of `org.a11y.atspi.Event.Focus`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum FocusEvents {
 	Focus(FocusEvent),
@@ -13,19 +20,19 @@ impl_event_conversions!(FocusEvents, Event::Focus);
 event_wrapper_test_cases!(FocusEvents, FocusEvent);
 
 impl HasMatchRule for FocusEvents {
-	const MATCH_RULE_STRING: &'static str = "type='signal',interface='org.a11y.atspi.Event.Focus'";
+	const MATCH_RULE_STRING: &'static str = signals::FOCUS_MATCH_RULE;
 }
 
+/// The `org.a11y.atspi.Event.Focus:Focus` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct FocusEvent {
 	pub item: crate::events::Accessible,
 }
 
 impl GenericEvent<'_> for FocusEvent {
-	const DBUS_MEMBER: &'static str = "Focus";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Focus";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Focus',member='Focus'";
+	const DBUS_MEMBER: &'static str = signals::FOCUS_FOCUS.member;
+	const DBUS_INTERFACE: &'static str = signals::FOCUS_FOCUS.interface;
+	const MATCH_RULE_STRING: &'static str = signals::FOCUS_FOCUS.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Focus:";
 
 	type Body = EventBodyOwned;
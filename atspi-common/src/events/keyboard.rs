@@ -1,10 +1,17 @@
+//! The events of `org.a11y.atspi.Event.Keyboard`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
+
 use crate::{
 	error::AtspiError,
-	events::{Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString},
+	events::{
+		signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString,
+	},
 	Event,
 };
 use zvariant::ObjectPath;
 
+/// The events of `org.a11y.atspi.Event.Keyboard`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum KeyboardEvents {
 	Modifiers(ModifiersEvent),
@@ -13,10 +20,10 @@ impl_event_conversions!(KeyboardEvents, Event::Keyboard);
 event_wrapper_test_cases!(KeyboardEvents, ModifiersEvent);
 
 impl HasMatchRule for KeyboardEvents {
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Keyboard'";
+	const MATCH_RULE_STRING: &'static str = signals::KEYBOARD_MATCH_RULE;
 }
 
+/// The `org.a11y.atspi.Event.Keyboard:Modifiers` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ModifiersEvent {
 	pub item: crate::events::Accessible,
@@ -25,10 +32,9 @@ pub struct ModifiersEvent {
 }
 
 impl GenericEvent<'_> for ModifiersEvent {
-	const DBUS_MEMBER: &'static str = "Modifiers";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Keyboard";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Keyboard',member='Modifiers'";
+	const DBUS_MEMBER: &'static str = signals::KEYBOARD_MODIFIERS.member;
+	const DBUS_INTERFACE: &'static str = signals::KEYBOARD_MODIFIERS.interface;
+	const MATCH_RULE_STRING: &'static str = signals::KEYBOARD_MODIFIERS.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Keyboard:";
 
 	type Body = EventBodyOwned;
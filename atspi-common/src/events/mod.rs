@@ -3,6 +3,9 @@ pub mod focus;
 pub mod keyboard;
 pub mod mouse;
 pub mod object;
+mod property;
+/// Signal names, signatures and match rules, generated from the introspection XML.
+pub mod signals;
 pub mod terminal;
 pub mod window;
 
@@ -122,6 +125,17 @@ pub struct EventBodyOwned {
 	pub properties: HashMap<String, OwnedValue>,
 }
 
+impl std::hash::Hash for EventBodyOwned {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.kind.hash(state);
+		self.detail1.hash(state);
+		self.detail2.hash(state);
+	}
+}
+
+// Do not derive Eq if not all fields implement Eq
+impl Eq for EventBodyOwned {}
+
 impl From<EventBodyQT> for EventBodyOwned {
 	fn from(body: EventBodyQT) -> Self {
 		let accessible = Accessible { name: body.properties.name, path: body.properties.path };
@@ -170,10 +184,13 @@ pub enum Event {
 	///
 	/// (eg. "Cache:AddAccessible:")
 	Listener(EventListenerEvents),
+	/// A signal without a typed event: a custom event of a toolkit, or one added by a newer
+	/// version of AT-SPI.
+	Unknown(UnknownEvent),
 }
 
 impl HasMatchRule for CacheEvents {
-	const MATCH_RULE_STRING: &'static str = "type='signal',interface='org.a11y.atspi.Event.Cache'";
+	const MATCH_RULE_STRING: &'static str = signals::CACHE_MATCH_RULE;
 }
 
 impl HasRegistryEventString for CacheEvents {
@@ -181,8 +198,7 @@ impl HasRegistryEventString for CacheEvents {
 }
 
 impl HasMatchRule for EventListenerEvents {
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Registry'";
+	const MATCH_RULE_STRING: &'static str = signals::REGISTRY_MATCH_RULE;
 }
 
 impl HasRegistryEventString for EventListenerEvents {
@@ -216,10 +232,9 @@ impl_to_dbus_message!(LegacyAddAccessibleEvent);
 
 impl GenericEvent<'_> for LegacyAddAccessibleEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Cache:Add";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Cache',member='AddAccessible'";
-	const DBUS_MEMBER: &'static str = "AddAccessible";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Cache";
+	const MATCH_RULE_STRING: &'static str = signals::CACHE_ADD_ACCESSIBLE.match_rule;
+	const DBUS_MEMBER: &'static str = signals::CACHE_ADD_ACCESSIBLE.member;
+	const DBUS_INTERFACE: &'static str = signals::CACHE_ADD_ACCESSIBLE.interface;
 
 	type Body = LegacyCacheItem;
 
@@ -250,10 +265,9 @@ event_test_cases!(AddAccessibleEvent);
 
 impl GenericEvent<'_> for AddAccessibleEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Cache:Add";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Cache',member='AddAccessible'";
-	const DBUS_MEMBER: &'static str = "AddAccessible";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Cache";
+	const MATCH_RULE_STRING: &'static str = signals::CACHE_ADD_ACCESSIBLE.match_rule;
+	const DBUS_MEMBER: &'static str = signals::CACHE_ADD_ACCESSIBLE.member;
+	const DBUS_INTERFACE: &'static str = signals::CACHE_ADD_ACCESSIBLE.interface;
 
 	type Body = CacheItem;
 
@@ -292,10 +306,9 @@ impl_event_conversions!(RemoveAccessibleEvent, CacheEvents, CacheEvents::Remove,
 event_test_cases!(RemoveAccessibleEvent);
 impl GenericEvent<'_> for RemoveAccessibleEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Cache:Remove";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Cache',member='RemoveAccessible'";
-	const DBUS_MEMBER: &'static str = "RemoveAccessible";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Cache";
+	const MATCH_RULE_STRING: &'static str = signals::CACHE_REMOVE_ACCESSIBLE.match_rule;
+	const DBUS_MEMBER: &'static str = signals::CACHE_REMOVE_ACCESSIBLE.member;
+	const DBUS_INTERFACE: &'static str = signals::CACHE_REMOVE_ACCESSIBLE.interface;
 
 	type Body = Accessible;
 
@@ -358,15 +371,14 @@ pub mod accessible_tests {
 impl TryFrom<&zbus::Message> for Accessible {
 	type Error = AtspiError;
 	fn try_from(message: &zbus::Message) -> Result<Self, Self::Error> {
-		let path = message.path().expect("returned path is either Some or panics");
+		let path = message.path().ok_or(AtspiError::Conversion(
+			"Unable to convert zbus::Message without a path to Accessible",
+		))?;
 		let owned_path = OwnedObjectPath::try_from(path)?;
 		let fields = message.fields()?;
-		let sender = fields.get_field(MessageFieldCode::Sender);
-		let sender = sender
-			.expect("We get the sender field from a valid MessageFieldCode, so it should be there");
-
-		let MessageField::Sender(unique_name) = sender else {
-			return Err(AtspiError::Conversion("Unable to convert zbus::Message to Accessible"));
+		let Some(MessageField::Sender(unique_name)) = fields.get_field(MessageFieldCode::Sender)
+		else {
+			return Err(AtspiError::MissingName);
 		};
 		let name_string = unique_name.as_str().to_owned();
 
@@ -444,9 +456,9 @@ event_test_cases!(EventListenerDeregisteredEvent);
 impl GenericEvent<'_> for EventListenerDeregisteredEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Registry:EventListenerDeregistered";
 	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Registry',member='EventListenerDeregistered'";
-	const DBUS_MEMBER: &'static str = "EventListenerDeregistered";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Registry";
+		signals::REGISTRY_EVENT_LISTENER_DEREGISTERED.match_rule;
+	const DBUS_MEMBER: &'static str = signals::REGISTRY_EVENT_LISTENER_DEREGISTERED.member;
+	const DBUS_INTERFACE: &'static str = signals::REGISTRY_EVENT_LISTENER_DEREGISTERED.interface;
 
 	type Body = EventListeners;
 
@@ -481,10 +493,9 @@ impl_event_conversions!(
 event_test_cases!(EventListenerRegisteredEvent);
 impl GenericEvent<'_> for EventListenerRegisteredEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Registry:EventListenerRegistered";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Registry',member='EventListenerRegistered'";
-	const DBUS_MEMBER: &'static str = "EventListenerRegistered";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Registry";
+	const MATCH_RULE_STRING: &'static str = signals::REGISTRY_EVENT_LISTENER_REGISTERED.match_rule;
+	const DBUS_MEMBER: &'static str = signals::REGISTRY_EVENT_LISTENER_REGISTERED.member;
+	const DBUS_INTERFACE: &'static str = signals::REGISTRY_EVENT_LISTENER_REGISTERED.interface;
 
 	type Body = EventListeners;
 
@@ -528,10 +539,9 @@ impl TryFrom<Event> for AvailableEvent {
 event_test_cases!(AvailableEvent);
 impl GenericEvent<'_> for AvailableEvent {
 	const REGISTRY_EVENT_STRING: &'static str = "Socket:Available";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Socket',member='Available'";
-	const DBUS_MEMBER: &'static str = "Available";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Socket";
+	const MATCH_RULE_STRING: &'static str = signals::SOCKET_AVAILABLE.match_rule;
+	const DBUS_MEMBER: &'static str = signals::SOCKET_AVAILABLE.member;
+	const DBUS_INTERFACE: &'static str = signals::SOCKET_AVAILABLE.interface;
 
 	type Body = Accessible;
 
@@ -551,6 +561,36 @@ impl GenericEvent<'_> for AvailableEvent {
 impl_from_dbus_message!(AvailableEvent);
 impl_to_dbus_message!(AvailableEvent);
 
+/// A signal without a typed event, such as a custom event of a toolkit, or one added by a newer
+/// version of AT-SPI.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, Eq, Hash)]
+pub struct UnknownEvent {
+	/// The sender and path of the signal.
+	pub item: Accessible,
+	pub interface: String,
+	pub member: String,
+	/// The signature of the body, without outer parentheses.
+	pub signature: String,
+	/// The body, if it has the signature of an event body.
+	pub body: Option<EventBodyOwned>,
+}
+impl_event_conversions!(UnknownEvent, Event::Unknown);
+
+#[cfg(feature = "zbus")]
+impl TryFrom<&zbus::Message> for UnknownEvent {
+	type Error = AtspiError;
+
+	fn try_from(msg: &zbus::Message) -> Result<Self, Self::Error> {
+		Ok(Self {
+			item: msg.try_into()?,
+			interface: msg.interface().ok_or(AtspiError::MissingInterface)?.to_string(),
+			member: msg.member().ok_or(AtspiError::MissingMember)?.to_string(),
+			signature: msg.body_signature()?.to_string(),
+			body: EventBodyOwned::try_from(msg).ok(),
+		})
+	}
+}
+
 #[cfg(feature = "zbus")]
 impl TryFrom<&zbus::Message> for Event {
 	type Error = AtspiError;
@@ -574,13 +614,19 @@ impl TryFrom<&zbus::Message> for Event {
 					let ev = AvailableEvent::try_from(msg)?;
 					Ok(Event::Available(ev))
 				}
-				_ => Err(AtspiError::UnknownSignal),
+				_ => Ok(Event::Unknown(msg.try_into()?)),
 			},
 			// Atspi / Qspi signature
 			"siiva{sv}" | "siiv(so)" | "(siiva{sv})" | "(siiv(so))" => {
 				let Some(interface) = msg.interface() else {
 					return Err(AtspiError::MissingInterface);
 				};
+				let known = signals::SIGNALS.iter().any(|signal| {
+					signal.interface == interface.as_str() && signal.member == member_str
+				});
+				if !known {
+					return Ok(Event::Unknown(msg.try_into()?));
+				}
 				match interface.as_str() {
 					"org.a11y.atspi.Event.Document" => {
 						Ok(Event::Document(DocumentEvents::try_from(msg)?))
@@ -599,7 +645,7 @@ impl TryFrom<&zbus::Message> for Event {
 					"org.a11y.atspi.Event.Window" => {
 						Ok(Event::Window(WindowEvents::try_from(msg)?))
 					}
-					_ => Err(AtspiError::UnknownInterface),
+					_ => Ok(Event::Unknown(msg.try_into()?)),
 				}
 			}
 			"ss" | "(ss)" => {
@@ -609,7 +655,7 @@ impl TryFrom<&zbus::Message> for Event {
 				if let Ok(ev) = EventListenerDeregisteredEvent::try_from(msg) {
 					return Ok(Event::Listener(EventListenerEvents::Deregistered(ev)));
 				}
-				Err(AtspiError::UnknownSignal)
+				Ok(Event::Unknown(msg.try_into()?))
 			}
 			// Marshalled `AddAccessible` signature
 			"(so)(so)(so)iiassusau" | "((so)(so)(so)iiassusau)" => {
@@ -621,8 +667,106 @@ impl TryFrom<&zbus::Message> for Event {
 				let ev = LegacyAddAccessibleEvent::try_from(msg)?;
 				Ok(Event::Cache(CacheEvents::LegacyAdd(ev)))
 			}
-			sig => Err(AtspiError::UnknownBusSignature(sig.to_string())),
+			_ => Ok(Event::Unknown(msg.try_into()?)),
+		}
+	}
+}
+
+/// Evaluates `$body` with `$ev` bound to the specific event inside `$event`.
+macro_rules! with_specific_event {
+	($event:expr, $ev:ident => $body:expr, $unknown:pat => $unknown_body:expr) => {
+		match $event {
+			Event::Document(DocumentEvents::LoadComplete($ev)) => $body,
+			Event::Document(DocumentEvents::Reload($ev)) => $body,
+			Event::Document(DocumentEvents::LoadStopped($ev)) => $body,
+			Event::Document(DocumentEvents::ContentChanged($ev)) => $body,
+			Event::Document(DocumentEvents::AttributesChanged($ev)) => $body,
+			Event::Document(DocumentEvents::PageChanged($ev)) => $body,
+			Event::Focus(FocusEvents::Focus($ev)) => $body,
+			Event::Keyboard(KeyboardEvents::Modifiers($ev)) => $body,
+			Event::Mouse(MouseEvents::Abs($ev)) => $body,
+			Event::Mouse(MouseEvents::Rel($ev)) => $body,
+			Event::Mouse(MouseEvents::Button($ev)) => $body,
+			Event::Object(ObjectEvents::PropertyChange($ev)) => $body,
+			Event::Object(ObjectEvents::BoundsChanged($ev)) => $body,
+			Event::Object(ObjectEvents::LinkSelected($ev)) => $body,
+			Event::Object(ObjectEvents::StateChanged($ev)) => $body,
+			Event::Object(ObjectEvents::ChildrenChanged($ev)) => $body,
+			Event::Object(ObjectEvents::VisibleDataChanged($ev)) => $body,
+			Event::Object(ObjectEvents::SelectionChanged($ev)) => $body,
+			Event::Object(ObjectEvents::ModelChanged($ev)) => $body,
+			Event::Object(ObjectEvents::ActiveDescendantChanged($ev)) => $body,
+			Event::Object(ObjectEvents::Announcement($ev)) => $body,
+			Event::Object(ObjectEvents::AttributesChanged($ev)) => $body,
+			Event::Object(ObjectEvents::RowInserted($ev)) => $body,
+			Event::Object(ObjectEvents::RowReordered($ev)) => $body,
+			Event::Object(ObjectEvents::RowDeleted($ev)) => $body,
+			Event::Object(ObjectEvents::ColumnInserted($ev)) => $body,
+			Event::Object(ObjectEvents::ColumnReordered($ev)) => $body,
+			Event::Object(ObjectEvents::ColumnDeleted($ev)) => $body,
+			Event::Object(ObjectEvents::TextBoundsChanged($ev)) => $body,
+			Event::Object(ObjectEvents::TextSelectionChanged($ev)) => $body,
+			Event::Object(ObjectEvents::TextChanged($ev)) => $body,
+			Event::Object(ObjectEvents::TextAttributesChanged($ev)) => $body,
+			Event::Object(ObjectEvents::TextCaretMoved($ev)) => $body,
+			Event::Terminal(TerminalEvents::LineChanged($ev)) => $body,
+			Event::Terminal(TerminalEvents::ColumnCountChanged($ev)) => $body,
+			Event::Terminal(TerminalEvents::LineCountChanged($ev)) => $body,
+			Event::Terminal(TerminalEvents::ApplicationChanged($ev)) => $body,
+			Event::Terminal(TerminalEvents::CharWidthChanged($ev)) => $body,
+			Event::Window(WindowEvents::PropertyChange($ev)) => $body,
+			Event::Window(WindowEvents::Minimize($ev)) => $body,
+			Event::Window(WindowEvents::Maximize($ev)) => $body,
+			Event::Window(WindowEvents::Restore($ev)) => $body,
+			Event::Window(WindowEvents::Close($ev)) => $body,
+			Event::Window(WindowEvents::Create($ev)) => $body,
+			Event::Window(WindowEvents::Reparent($ev)) => $body,
+			Event::Window(WindowEvents::DesktopCreate($ev)) => $body,
+			Event::Window(WindowEvents::DesktopDestroy($ev)) => $body,
+			Event::Window(WindowEvents::Destroy($ev)) => $body,
+			Event::Window(WindowEvents::Activate($ev)) => $body,
+			Event::Window(WindowEvents::Deactivate($ev)) => $body,
+			Event::Window(WindowEvents::Raise($ev)) => $body,
+			Event::Window(WindowEvents::Lower($ev)) => $body,
+			Event::Window(WindowEvents::Move($ev)) => $body,
+			Event::Window(WindowEvents::Resize($ev)) => $body,
+			Event::Window(WindowEvents::Shade($ev)) => $body,
+			Event::Window(WindowEvents::UUshade($ev)) => $body,
+			Event::Window(WindowEvents::Restyle($ev)) => $body,
+			Event::Cache(CacheEvents::Add($ev)) => $body,
+			Event::Cache(CacheEvents::LegacyAdd($ev)) => $body,
+			Event::Cache(CacheEvents::Remove($ev)) => $body,
+			Event::Listener(EventListenerEvents::Registered($ev)) => $body,
+			Event::Listener(EventListenerEvents::Deregistered($ev)) => $body,
+			Event::Available($ev) => $body,
+			$unknown => $unknown_body,
+		}
+	};
+}
+
+impl Event {
+	/// The object the event is about.
+	#[must_use]
+	pub fn item(&self) -> &Accessible {
+		with_specific_event!(self, ev => &ev.item, Event::Unknown(ev) => &ev.item)
+	}
+
+	/// The D-Bus interface of the signal, for example `org.a11y.atspi.Event.Object`.
+	#[must_use]
+	pub fn interface(&self) -> &str {
+		fn interface<'a, T: GenericEvent<'a>>(_: &T) -> &'static str {
+			T::DBUS_INTERFACE
+		}
+		with_specific_event!(self, ev => interface(ev), Event::Unknown(ev) => &ev.interface)
+	}
+
+	/// The D-Bus member of the signal, for example `StateChanged`.
+	#[must_use]
+	pub fn member(&self) -> &str {
+		fn member<'a, T: GenericEvent<'a>>(_: &T) -> &'static str {
+			T::DBUS_MEMBER
 		}
+		with_specific_event!(self, ev => member(ev), Event::Unknown(ev) => &ev.member)
 	}
 }
 
@@ -672,10 +816,11 @@ pub trait HasRegistryEventString {
 mod tests {
 	use atspi_common::events::{
 		AddAccessibleEvent, CacheEvents, Event, EventBodyOwned, EventBodyQT, RemoveAccessibleEvent,
-		ATSPI_EVENT_SIGNATURE, CACHE_ADD_SIGNATURE, QSPI_EVENT_SIGNATURE,
+		UnknownEvent, ATSPI_EVENT_SIGNATURE, CACHE_ADD_SIGNATURE, QSPI_EVENT_SIGNATURE,
 	};
 	use atspi_common::{
-		accessible::ACCESSIBLE_PAIR_SIGNATURE, Accessible, CacheItem, InterfaceSet, Role, StateSet,
+		accessible::ACCESSIBLE_PAIR_SIGNATURE, Accessible, AtspiError, CacheItem, InterfaceSet,
+		Role, StateSet,
 	};
 	use atspi_connection::AccessibilityConnection;
 	use std::{collections::HashMap, time::Duration};
@@ -685,6 +830,125 @@ mod tests {
 
 	use super::signatures_are_eq;
 
+	#[test]
+	fn event_item_interface_and_member() {
+		use atspi_common::events::{object::StateChangedEvent, AvailableEvent};
+		let item = Accessible {
+			name: ":1.7".into(),
+			path: "/org/a11y/atspi/accessible/12".try_into().unwrap(),
+		};
+		let event: Event = StateChangedEvent { item: item.clone(), ..Default::default() }.into();
+		assert_eq!(event.item(), &item);
+		assert_eq!(event.interface(), "org.a11y.atspi.Event.Object");
+		assert_eq!(event.member(), "StateChanged");
+
+		let event = Event::Available(AvailableEvent { item, socket: Accessible::default() });
+		assert_eq!(event.interface(), "org.a11y.atspi.Socket");
+		assert_eq!(event.member(), "Available");
+	}
+
+	fn signal_message<B: serde::Serialize + Type>(
+		interface: &str,
+		member: &str,
+		body: &B,
+	) -> zbus::Message {
+		MessageBuilder::signal("/org/a11y/atspi/accessible/null", interface, member)
+			.unwrap()
+			.sender(":0.0")
+			.unwrap()
+			.build(body)
+			.unwrap()
+	}
+
+	#[test]
+	fn accessible_from_message_without_sender_is_an_error() {
+		let message = MessageBuilder::signal(
+			"/org/a11y/atspi/accessible/null",
+			"org.a11y.atspi.Event.Object",
+			"StateChanged",
+		)
+		.unwrap()
+		.build(&EventBodyOwned::default())
+		.unwrap();
+		assert!(matches!(Accessible::try_from(&message), Err(AtspiError::MissingName)));
+	}
+
+	/// A body whose `any_data` has the type the event of `member` reads from it.
+	fn event_body(member: &str) -> EventBodyOwned {
+		let any_data = match member {
+			"ChildrenChanged" | "ActiveDescendantChanged" => {
+				zvariant::Value::from(Accessible::default()).into()
+			}
+			"TextChanged" => zvariant::Value::from("text").into(),
+			_ => zvariant::Value::U8(0).into(),
+		};
+		EventBodyOwned { any_data, ..Default::default() }
+	}
+
+	#[test]
+	fn every_signal_in_the_xml_decodes_to_a_typed_event() {
+		use atspi_common::events::{signals::SIGNALS, EventListeners};
+		// Listeners for device events are called directly, these signals never reach an event stream.
+		let signals = SIGNALS
+			.iter()
+			.filter(|signal| signal.interface != "org.a11y.atspi.DeviceEventListener");
+		for signal in signals {
+			let (interface, member) = (signal.interface, signal.member);
+			let message = match signal.signature {
+				"siiva{sv}" => signal_message(interface, member, &event_body(member)),
+				"(so)" => signal_message(interface, member, &Accessible::default()),
+				"ss" => signal_message(interface, member, &EventListeners::default()),
+				"((so)(so)(so)iiassusau)" => {
+					signal_message(interface, member, &CacheItem::default())
+				}
+				signature => panic!("no test body for {interface}.{member}: {signature}"),
+			};
+			match Event::try_from(&message) {
+				Ok(Event::Unknown(_)) => panic!("{interface}.{member} has no typed event"),
+				Ok(event) => assert_eq!((event.interface(), event.member()), (interface, member)),
+				Err(e) => panic!("{interface}.{member}: {e}"),
+			}
+		}
+	}
+
+	#[test]
+	fn unknown_signals_decode_to_unknown_events() {
+		let body = EventBodyOwned { kind: "new-kind".into(), detail1: 4, ..Default::default() };
+		for interface in ["org.a11y.atspi.Event.Object", "org.a11y.atspi.Event.Newer"] {
+			let message = signal_message(interface, "SomethingChanged", &body);
+			let event = Event::try_from(&message).unwrap();
+			assert_eq!(
+				event,
+				Event::Unknown(UnknownEvent {
+					item: Accessible::default(),
+					interface: interface.into(),
+					member: "SomethingChanged".into(),
+					signature: "siiva{sv}".into(),
+					body: Some(body.clone()),
+				})
+			);
+			assert_eq!(event.interface(), interface);
+			assert_eq!(event.member(), "SomethingChanged");
+			assert_eq!(event.item(), &Accessible::default());
+		}
+
+		// A custom event of a toolkit, with a body of its own.
+		let message = signal_message("org.example.Toolkit", "Custom", &("text", 7u32));
+		let Event::Unknown(event) = Event::try_from(&message).unwrap() else {
+			panic!("custom signal decoded to a typed event");
+		};
+		assert_eq!(
+			(event.interface.as_str(), event.member.as_str()),
+			("org.example.Toolkit", "Custom")
+		);
+		assert_eq!(event.signature, "su");
+		assert_eq!(event.body, None);
+		// Unknown members with the signature of a known signal are not mistaken for it.
+		let message =
+			signal_message("org.a11y.atspi.Socket", "Unavailable", &Accessible::default());
+		assert!(matches!(Event::try_from(&message), Ok(Event::Unknown(_))));
+	}
+
 	#[test]
 	fn check_event_body_qt_signature() {
 		assert_eq_signatures!(&<EventBodyQT as Type>::signature(), &QSPI_EVENT_SIGNATURE);
@@ -1,10 +1,17 @@
+//! The events of `org.a11y.atspi.Event.Mouse`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
+
 use crate::{
 	error::AtspiError,
-	events::{Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString},
+	events::{
+		signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString,
+	},
 	Event,
 };
 use zvariant::ObjectPath;
 
+/// The events of `org.a11y.atspi.Event.Mouse`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum MouseEvents {
 	Abs(AbsEvent),
@@ -15,9 +22,10 @@ impl_event_conversions!(MouseEvents, Event::Mouse);
 event_wrapper_test_cases!(MouseEvents, AbsEvent);
 
 impl HasMatchRule for MouseEvents {
-	const MATCH_RULE_STRING: &'static str = "type='signal',interface='org.a11y.atspi.Event.Mouse'";
+	const MATCH_RULE_STRING: &'static str = signals::MOUSE_MATCH_RULE;
 }
 
+/// The `org.a11y.atspi.Event.Mouse:Abs` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct AbsEvent {
 	pub item: crate::events::Accessible,
@@ -25,6 +33,7 @@ pub struct AbsEvent {
 	pub y: i32,
 }
 
+/// The `org.a11y.atspi.Event.Mouse:Rel` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct RelEvent {
 	pub item: crate::events::Accessible,
@@ -32,6 +41,7 @@ pub struct RelEvent {
 	pub y: i32,
 }
 
+/// The `org.a11y.atspi.Event.Mouse:Button` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ButtonEvent {
 	pub item: crate::events::Accessible,
@@ -41,10 +51,9 @@ pub struct ButtonEvent {
 }
 
 impl GenericEvent<'_> for AbsEvent {
-	const DBUS_MEMBER: &'static str = "Abs";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Mouse";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Mouse',member='Abs'";
+	const DBUS_MEMBER: &'static str = signals::MOUSE_ABS.member;
+	const DBUS_INTERFACE: &'static str = signals::MOUSE_ABS.interface;
+	const MATCH_RULE_STRING: &'static str = signals::MOUSE_ABS.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Mouse:";
 
 	type Body = EventBodyOwned;
@@ -65,10 +74,9 @@ impl GenericEvent<'_> for AbsEvent {
 }
 
 impl GenericEvent<'_> for RelEvent {
-	const DBUS_MEMBER: &'static str = "Rel";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Mouse";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Mouse',member='Rel'";
+	const DBUS_MEMBER: &'static str = signals::MOUSE_REL.member;
+	const DBUS_INTERFACE: &'static str = signals::MOUSE_REL.interface;
+	const MATCH_RULE_STRING: &'static str = signals::MOUSE_REL.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Mouse:";
 
 	type Body = EventBodyOwned;
@@ -89,10 +97,9 @@ impl GenericEvent<'_> for RelEvent {
 }
 
 impl GenericEvent<'_> for ButtonEvent {
-	const DBUS_MEMBER: &'static str = "Button";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Mouse";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Mouse',member='Button'";
+	const DBUS_MEMBER: &'static str = signals::MOUSE_BUTTON.member;
+	const DBUS_INTERFACE: &'static str = signals::MOUSE_BUTTON.interface;
+	const MATCH_RULE_STRING: &'static str = signals::MOUSE_BUTTON.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Mouse:";
 
 	type Body = EventBodyOwned;
@@ -1,12 +1,18 @@
-use std::hash::Hash;
+//! The events of `org.a11y.atspi.Event.Object`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
 
+pub use super::property::Property;
 use crate::{
 	error::AtspiError,
-	events::{Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString},
-	Event, State,
+	events::{
+		signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString,
+	},
+	Event,
 };
-use zvariant::{ObjectPath, OwnedValue, Value};
+use zvariant::ObjectPath;
 
+/// The events of `org.a11y.atspi.Event.Object`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum ObjectEvents {
 	PropertyChange(PropertyChangeEvent),
@@ -36,168 +42,38 @@ impl_event_conversions!(ObjectEvents, Event::Object);
 event_wrapper_test_cases!(ObjectEvents, PropertyChangeEvent);
 
 impl HasMatchRule for ObjectEvents {
-	const MATCH_RULE_STRING: &'static str = "type='signal',interface='org.a11y.atspi.Event.Object'";
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_MATCH_RULE;
 }
 
 /// The `org.a11y.atspi.Event.Object:PropertyChange` event.
-#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct PropertyChangeEvent {
 	pub item: crate::events::Accessible,
 	pub property: String,
 	pub value: Property,
 }
 
-impl Hash for PropertyChangeEvent {
-	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-		self.item.hash(state);
-		self.property.hash(state);
-	}
-}
-
-// Do not derive Eq if not all fields implement Eq
-impl Eq for PropertyChangeEvent {}
-
-// Looks like a false positive Clippy lint
-#[allow(clippy::derivable_impls)]
-impl Default for PropertyChangeEvent {
-	fn default() -> Self {
-		Self {
-			item: Accessible::default(),
-			property: String::default(),
-			value: Property::default(),
-		}
-	}
-}
-
-#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
-#[non_exhaustive]
-pub enum Property {
-	Name(String),
-	Description(String),
-	Role(crate::Role),
-	Parent(Accessible),
-	TableCaption(String),
-	TableColumnDescription(String),
-	TableColumnHeader(String),
-	TableRowDescription(String),
-	TableRowHeader(String),
-	TableSummary(String),
-	Other((String, OwnedValue)),
-}
-
-impl Default for Property {
-	fn default() -> Self {
-		Self::Other((String::default(), zvariant::Value::U64(0).into()))
-	}
-}
-
-impl TryFrom<EventBodyOwned> for Property {
-	type Error = AtspiError;
-
-	fn try_from(body: EventBodyOwned) -> Result<Self, Self::Error> {
-		let property = body.kind;
-
-		match property.as_str() {
-			"accessible-name" => Ok(Self::Name(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("accessible-name"))?,
-			)),
-			"accessible-description" => Ok(Self::Description(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("accessible-description"))?,
-			)),
-			"accessible-role" => Ok(Self::Role({
-				let role_int: u32 = body
-					.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("accessible-role"))?;
-				let role: crate::Role = crate::Role::try_from(role_int)
-					.map_err(|_| AtspiError::ParseError("accessible-role"))?;
-				role
-			})),
-			"accessible-parent" => Ok(Self::Parent(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("accessible-parent"))?,
-			)),
-			"accessible-table-caption" => Ok(Self::TableCaption(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("accessible-table-caption"))?,
-			)),
-			"table-column-description" => Ok(Self::TableColumnDescription(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("table-column-description"))?,
-			)),
-			"table-column-header" => Ok(Self::TableColumnHeader(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("table-column-header"))?,
-			)),
-			"table-row-description" => Ok(Self::TableRowDescription(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("table-row-description"))?,
-			)),
-			"table-row-header" => Ok(Self::TableRowHeader(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("table-row-header"))?,
-			)),
-			"table-summary" => Ok(Self::TableSummary(
-				body.any_data
-					.try_into()
-					.map_err(|_| AtspiError::ParseError("table-summary"))?,
-			)),
-			_ => Ok(Self::Other((property, body.any_data))),
-		}
-	}
-}
-
-impl From<Property> for OwnedValue {
-	fn from(property: Property) -> Self {
-		match property {
-			Property::Name(name) => Value::from(name).into(),
-			Property::Description(description) => Value::from(description).into(),
-			Property::Role(role) => Value::from(role as u32).into(),
-			Property::Parent(parent) => Value::from(parent).into(),
-			Property::TableCaption(table_caption) => Value::from(table_caption).into(),
-			Property::TableColumnDescription(table_column_description) => {
-				Value::from(table_column_description).into()
-			}
-			Property::TableColumnHeader(table_column_header) => {
-				Value::from(table_column_header).into()
-			}
-			Property::TableRowDescription(table_row_description) => {
-				Value::from(table_row_description).into()
-			}
-			Property::TableRowHeader(table_row_header) => Value::from(table_row_header).into(),
-			Property::TableSummary(table_summary) => Value::from(table_summary).into(),
-			Property::Other((_, value)) => value,
-		}
-	}
-}
-
+/// The `org.a11y.atspi.Event.Object:BoundsChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct BoundsChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:LinkSelected` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct LinkSelectedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:StateChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct StateChangedEvent {
 	pub item: crate::events::Accessible,
-	pub state: State,
+	pub state: crate::State,
 	pub enabled: i32,
 }
 
+/// The `org.a11y.atspi.Event.Object:ChildrenChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ChildrenChangedEvent {
 	pub item: crate::events::Accessible,
@@ -206,78 +82,95 @@ pub struct ChildrenChangedEvent {
 	pub child: Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:VisibleDataChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct VisibleDataChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:SelectionChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct SelectionChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:ModelChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ModelChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:ActiveDescendantChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ActiveDescendantChangedEvent {
 	pub item: crate::events::Accessible,
 	pub child: Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:Announcement` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct AnnouncementEvent {
 	pub item: crate::events::Accessible,
 	pub text: String,
+	/// How urgent the announcement is: 1 for polite, 2 for assertive; `ATSPI_LIVE_*` in libatspi.
+	pub politeness: i32,
 }
 
+/// The `org.a11y.atspi.Event.Object:AttributesChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct AttributesChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:RowInserted` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct RowInsertedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:RowReordered` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct RowReorderedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:RowDeleted` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct RowDeletedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:ColumnInserted` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ColumnInsertedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:ColumnReordered` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ColumnReorderedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:ColumnDeleted` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ColumnDeletedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:TextBoundsChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct TextBoundsChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:TextSelectionChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct TextSelectionChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:TextChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct TextChangedEvent {
 	pub item: crate::events::Accessible,
@@ -287,11 +180,13 @@ pub struct TextChangedEvent {
 	pub text: String,
 }
 
+/// The `org.a11y.atspi.Event.Object:TextAttributesChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct TextAttributesChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Object:TextCaretMoved` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct TextCaretMovedEvent {
 	pub item: crate::events::Accessible,
@@ -299,18 +194,15 @@ pub struct TextCaretMovedEvent {
 }
 
 impl GenericEvent<'_> for PropertyChangeEvent {
-	const DBUS_MEMBER: &'static str = "PropertyChange";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='PropertyChange'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_PROPERTY_CHANGE.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_PROPERTY_CHANGE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_PROPERTY_CHANGE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
 
 	fn build(item: Accessible, body: Self::Body) -> Result<Self, AtspiError> {
-		let property = body.kind.clone();
-		let value: Property = body.try_into()?;
-		Ok(Self { item, property, value })
+		Ok(Self { item, property: body.kind.clone(), value: body.try_into()? })
 	}
 	fn sender(&self) -> String {
 		self.item.name.clone()
@@ -325,10 +217,9 @@ impl GenericEvent<'_> for PropertyChangeEvent {
 }
 
 impl GenericEvent<'_> for BoundsChangedEvent {
-	const DBUS_MEMBER: &'static str = "BoundsChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='BoundsChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_BOUNDS_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_BOUNDS_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_BOUNDS_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -349,10 +240,9 @@ impl GenericEvent<'_> for BoundsChangedEvent {
 }
 
 impl GenericEvent<'_> for LinkSelectedEvent {
-	const DBUS_MEMBER: &'static str = "LinkSelected";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='LinkSelected'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_LINK_SELECTED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_LINK_SELECTED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_LINK_SELECTED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -373,10 +263,9 @@ impl GenericEvent<'_> for LinkSelectedEvent {
 }
 
 impl GenericEvent<'_> for StateChangedEvent {
-	const DBUS_MEMBER: &'static str = "StateChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='StateChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_STATE_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_STATE_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_STATE_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -397,10 +286,9 @@ impl GenericEvent<'_> for StateChangedEvent {
 }
 
 impl GenericEvent<'_> for ChildrenChangedEvent {
-	const DBUS_MEMBER: &'static str = "ChildrenChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='ChildrenChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_CHILDREN_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_CHILDREN_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_CHILDREN_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -426,10 +314,9 @@ impl GenericEvent<'_> for ChildrenChangedEvent {
 }
 
 impl GenericEvent<'_> for VisibleDataChangedEvent {
-	const DBUS_MEMBER: &'static str = "VisibleDataChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='VisibleDataChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_VISIBLE_DATA_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_VISIBLE_DATA_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_VISIBLE_DATA_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -450,10 +337,9 @@ impl GenericEvent<'_> for VisibleDataChangedEvent {
 }
 
 impl GenericEvent<'_> for SelectionChangedEvent {
-	const DBUS_MEMBER: &'static str = "SelectionChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='SelectionChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_SELECTION_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_SELECTION_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_SELECTION_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -474,10 +360,9 @@ impl GenericEvent<'_> for SelectionChangedEvent {
 }
 
 impl GenericEvent<'_> for ModelChangedEvent {
-	const DBUS_MEMBER: &'static str = "ModelChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='ModelChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_MODEL_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_MODEL_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_MODEL_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -498,10 +383,9 @@ impl GenericEvent<'_> for ModelChangedEvent {
 }
 
 impl GenericEvent<'_> for ActiveDescendantChangedEvent {
-	const DBUS_MEMBER: &'static str = "ActiveDescendantChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='ActiveDescendantChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_ACTIVE_DESCENDANT_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_ACTIVE_DESCENDANT_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_ACTIVE_DESCENDANT_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -522,16 +406,15 @@ impl GenericEvent<'_> for ActiveDescendantChangedEvent {
 }
 
 impl GenericEvent<'_> for AnnouncementEvent {
-	const DBUS_MEMBER: &'static str = "Announcement";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='Announcement'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_ANNOUNCEMENT.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_ANNOUNCEMENT.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_ANNOUNCEMENT.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
 
 	fn build(item: Accessible, body: Self::Body) -> Result<Self, AtspiError> {
-		Ok(Self { item, text: body.kind })
+		Ok(Self { item, text: body.kind, politeness: body.detail1 })
 	}
 	fn sender(&self) -> String {
 		self.item.name.clone()
@@ -546,10 +429,9 @@ impl GenericEvent<'_> for AnnouncementEvent {
 }
 
 impl GenericEvent<'_> for AttributesChangedEvent {
-	const DBUS_MEMBER: &'static str = "AttributesChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='AttributesChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_ATTRIBUTES_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_ATTRIBUTES_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_ATTRIBUTES_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -570,10 +452,9 @@ impl GenericEvent<'_> for AttributesChangedEvent {
 }
 
 impl GenericEvent<'_> for RowInsertedEvent {
-	const DBUS_MEMBER: &'static str = "RowInserted";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='RowInserted'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_ROW_INSERTED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_ROW_INSERTED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_ROW_INSERTED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -594,10 +475,9 @@ impl GenericEvent<'_> for RowInsertedEvent {
 }
 
 impl GenericEvent<'_> for RowReorderedEvent {
-	const DBUS_MEMBER: &'static str = "RowReordered";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='RowReordered'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_ROW_REORDERED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_ROW_REORDERED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_ROW_REORDERED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -618,10 +498,9 @@ impl GenericEvent<'_> for RowReorderedEvent {
 }
 
 impl GenericEvent<'_> for RowDeletedEvent {
-	const DBUS_MEMBER: &'static str = "RowDeleted";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='RowDeleted'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_ROW_DELETED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_ROW_DELETED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_ROW_DELETED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -642,10 +521,9 @@ impl GenericEvent<'_> for RowDeletedEvent {
 }
 
 impl GenericEvent<'_> for ColumnInsertedEvent {
-	const DBUS_MEMBER: &'static str = "ColumnInserted";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='ColumnInserted'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_COLUMN_INSERTED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_COLUMN_INSERTED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_COLUMN_INSERTED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -666,10 +544,9 @@ impl GenericEvent<'_> for ColumnInsertedEvent {
 }
 
 impl GenericEvent<'_> for ColumnReorderedEvent {
-	const DBUS_MEMBER: &'static str = "ColumnReordered";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='ColumnReordered'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_COLUMN_REORDERED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_COLUMN_REORDERED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_COLUMN_REORDERED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -690,10 +567,9 @@ impl GenericEvent<'_> for ColumnReorderedEvent {
 }
 
 impl GenericEvent<'_> for ColumnDeletedEvent {
-	const DBUS_MEMBER: &'static str = "ColumnDeleted";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='ColumnDeleted'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_COLUMN_DELETED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_COLUMN_DELETED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_COLUMN_DELETED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -714,10 +590,9 @@ impl GenericEvent<'_> for ColumnDeletedEvent {
 }
 
 impl GenericEvent<'_> for TextBoundsChangedEvent {
-	const DBUS_MEMBER: &'static str = "TextBoundsChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='TextBoundsChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_TEXT_BOUNDS_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_TEXT_BOUNDS_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_TEXT_BOUNDS_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -738,10 +613,9 @@ impl GenericEvent<'_> for TextBoundsChangedEvent {
 }
 
 impl GenericEvent<'_> for TextSelectionChangedEvent {
-	const DBUS_MEMBER: &'static str = "TextSelectionChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='TextSelectionChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_TEXT_SELECTION_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_TEXT_SELECTION_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_TEXT_SELECTION_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -762,10 +636,9 @@ impl GenericEvent<'_> for TextSelectionChangedEvent {
 }
 
 impl GenericEvent<'_> for TextChangedEvent {
-	const DBUS_MEMBER: &'static str = "TextChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='TextChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_TEXT_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_TEXT_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_TEXT_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -792,10 +665,9 @@ impl GenericEvent<'_> for TextChangedEvent {
 }
 
 impl GenericEvent<'_> for TextAttributesChangedEvent {
-	const DBUS_MEMBER: &'static str = "TextAttributesChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='TextAttributesChanged'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_TEXT_ATTRIBUTES_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_TEXT_ATTRIBUTES_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_TEXT_ATTRIBUTES_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -816,10 +688,9 @@ impl GenericEvent<'_> for TextAttributesChangedEvent {
 }
 
 impl GenericEvent<'_> for TextCaretMovedEvent {
-	const DBUS_MEMBER: &'static str = "TextCaretMoved";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Object";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Object',member='TextCaretMoved'";
+	const DBUS_MEMBER: &'static str = signals::OBJECT_TEXT_CARET_MOVED.member;
+	const DBUS_INTERFACE: &'static str = signals::OBJECT_TEXT_CARET_MOVED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::OBJECT_TEXT_CARET_MOVED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Object:";
 
 	type Body = EventBodyOwned;
@@ -880,11 +751,9 @@ impl_event_conversions!(
 	ObjectEvents::PropertyChange,
 	Event::Object
 );
-
 event_test_cases!(PropertyChangeEvent);
 impl_to_dbus_message!(PropertyChangeEvent);
 impl_from_dbus_message!(PropertyChangeEvent);
-
 impl From<PropertyChangeEvent> for EventBodyOwned {
 	fn from(event: PropertyChangeEvent) -> Self {
 		EventBodyOwned {
@@ -1059,7 +928,7 @@ impl From<AnnouncementEvent> for EventBodyOwned {
 		EventBodyOwned {
 			properties: std::collections::HashMap::new(),
 			kind: event.text,
-			detail1: i32::default(),
+			detail1: event.politeness,
 			detail2: i32::default(),
 			any_data: zvariant::Value::U8(0).into(),
 		}
@@ -0,0 +1,132 @@
+//! The value of `org.a11y.atspi.Event.Object:PropertyChange`, and what
+//! [`PropertyChangeEvent`] can't derive because of it.
+
+use std::hash::Hash;
+
+use crate::{
+	error::AtspiError,
+	events::{object::PropertyChangeEvent, Accessible, EventBodyOwned},
+};
+use zvariant::{OwnedValue, Value};
+
+impl Hash for PropertyChangeEvent {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.item.hash(state);
+		self.property.hash(state);
+	}
+}
+
+// Do not derive Eq if not all fields implement Eq
+impl Eq for PropertyChangeEvent {}
+
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum Property {
+	Name(String),
+	Description(String),
+	Role(crate::Role),
+	Parent(Accessible),
+	TableCaption(String),
+	TableColumnDescription(String),
+	TableColumnHeader(String),
+	TableRowDescription(String),
+	TableRowHeader(String),
+	TableSummary(String),
+	Other((String, OwnedValue)),
+}
+
+impl Default for Property {
+	fn default() -> Self {
+		Self::Other((String::default(), zvariant::Value::U64(0).into()))
+	}
+}
+
+impl TryFrom<EventBodyOwned> for Property {
+	type Error = AtspiError;
+
+	fn try_from(body: EventBodyOwned) -> Result<Self, Self::Error> {
+		let property = body.kind;
+
+		match property.as_str() {
+			"accessible-name" => Ok(Self::Name(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("accessible-name"))?,
+			)),
+			"accessible-description" => Ok(Self::Description(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("accessible-description"))?,
+			)),
+			"accessible-role" => Ok(Self::Role({
+				let role_int: u32 = body
+					.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("accessible-role"))?;
+				let role: crate::Role = crate::Role::try_from(role_int)
+					.map_err(|_| AtspiError::ParseError("accessible-role"))?;
+				role
+			})),
+			"accessible-parent" => Ok(Self::Parent(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("accessible-parent"))?,
+			)),
+			"accessible-table-caption" => Ok(Self::TableCaption(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("accessible-table-caption"))?,
+			)),
+			"table-column-description" => Ok(Self::TableColumnDescription(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("table-column-description"))?,
+			)),
+			"table-column-header" => Ok(Self::TableColumnHeader(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("table-column-header"))?,
+			)),
+			"table-row-description" => Ok(Self::TableRowDescription(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("table-row-description"))?,
+			)),
+			"table-row-header" => Ok(Self::TableRowHeader(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("table-row-header"))?,
+			)),
+			"table-summary" => Ok(Self::TableSummary(
+				body.any_data
+					.try_into()
+					.map_err(|_| AtspiError::ParseError("table-summary"))?,
+			)),
+			_ => Ok(Self::Other((property, body.any_data))),
+		}
+	}
+}
+
+impl From<Property> for OwnedValue {
+	fn from(property: Property) -> Self {
+		match property {
+			Property::Name(name) => Value::from(name).into(),
+			Property::Description(description) => Value::from(description).into(),
+			Property::Role(role) => Value::from(role as u32).into(),
+			Property::Parent(parent) => Value::from(parent).into(),
+			Property::TableCaption(table_caption) => Value::from(table_caption).into(),
+			Property::TableColumnDescription(table_column_description) => {
+				Value::from(table_column_description).into()
+			}
+			Property::TableColumnHeader(table_column_header) => {
+				Value::from(table_column_header).into()
+			}
+			Property::TableRowDescription(table_row_description) => {
+				Value::from(table_row_description).into()
+			}
+			Property::TableRowHeader(table_row_header) => Value::from(table_row_header).into(),
+			Property::TableSummary(table_summary) => Value::from(table_summary).into(),
+			Property::Other((_, value)) => value,
+		}
+	}
+}
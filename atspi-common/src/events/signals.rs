@@ -0,0 +1,704 @@
+//! The signals declared in the introspection XML of AT-SPI.
+//!
+//! This file is generated from the `xml/` directory by `cargo xtask codegen`; do not edit it.
+
+/// A signal, as declared in the introspection XML.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Signal {
+	/// The D-Bus interface the signal belongs to.
+	pub interface: &'static str,
+	/// The D-Bus member name of the signal.
+	pub member: &'static str,
+	/// The match rule selecting the signal.
+	pub match_rule: &'static str,
+	/// The signature of the body of the signal, without outer parentheses.
+	pub signature: &'static str,
+	/// The names of the arguments; arguments without a name have an empty one.
+	pub arguments: &'static [&'static str],
+}
+
+/// The match rule selecting all signals of `org.a11y.atspi.Cache`.
+pub const CACHE_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Cache'";
+
+/// `org.a11y.atspi.Cache.AddAccessible`.
+pub const CACHE_ADD_ACCESSIBLE: Signal = Signal {
+	interface: "org.a11y.atspi.Cache",
+	member: "AddAccessible",
+	match_rule: "type='signal',interface='org.a11y.atspi.Cache',member='AddAccessible'",
+	signature: "((so)(so)(so)iiassusau)",
+	arguments: &["nodeAdded"],
+};
+
+/// `org.a11y.atspi.Cache.RemoveAccessible`.
+pub const CACHE_REMOVE_ACCESSIBLE: Signal = Signal {
+	interface: "org.a11y.atspi.Cache",
+	member: "RemoveAccessible",
+	match_rule: "type='signal',interface='org.a11y.atspi.Cache',member='RemoveAccessible'",
+	signature: "(so)",
+	arguments: &["nodeRemoved"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.DeviceEventListener`.
+pub const DEVICE_EVENT_LISTENER_MATCH_RULE: &str =
+	"type='signal',interface='org.a11y.atspi.DeviceEventListener'";
+
+/// `org.a11y.atspi.DeviceEventListener.KeystrokeListenerRegistered`.
+pub const DEVICE_EVENT_LISTENER_KEYSTROKE_LISTENER_REGISTERED: Signal = Signal {
+	interface: "org.a11y.atspi.DeviceEventListener",
+	member: "KeystrokeListenerRegistered",
+	match_rule: "type='signal',interface='org.a11y.atspi.DeviceEventListener',member='KeystrokeListenerRegistered'",
+	signature: "(souua(iisi)u(bbb))",
+	arguments: &["listener"],
+};
+
+/// `org.a11y.atspi.DeviceEventListener.KeystrokeListenerDeregistered`.
+pub const DEVICE_EVENT_LISTENER_KEYSTROKE_LISTENER_DEREGISTERED: Signal = Signal {
+	interface: "org.a11y.atspi.DeviceEventListener",
+	member: "KeystrokeListenerDeregistered",
+	match_rule: "type='signal',interface='org.a11y.atspi.DeviceEventListener',member='KeystrokeListenerDeregistered'",
+	signature: "(souua(iisi)u(bbb))",
+	arguments: &["listener"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Event.Object`.
+pub const OBJECT_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Event.Object'";
+
+/// `org.a11y.atspi.Event.Object.PropertyChange`.
+pub const OBJECT_PROPERTY_CHANGE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "PropertyChange",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='PropertyChange'",
+	signature: "siiva{sv}",
+	arguments: &["property", "", "", "value", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.BoundsChanged`.
+pub const OBJECT_BOUNDS_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "BoundsChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='BoundsChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.LinkSelected`.
+pub const OBJECT_LINK_SELECTED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "LinkSelected",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='LinkSelected'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.StateChanged`.
+pub const OBJECT_STATE_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "StateChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='StateChanged'",
+	signature: "siiva{sv}",
+	arguments: &["state", "enabled", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.ChildrenChanged`.
+pub const OBJECT_CHILDREN_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "ChildrenChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='ChildrenChanged'",
+	signature: "siiva{sv}",
+	arguments: &["operation", "index_in_parent", "", "child", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.VisibleDataChanged`.
+pub const OBJECT_VISIBLE_DATA_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "VisibleDataChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='VisibleDataChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.SelectionChanged`.
+pub const OBJECT_SELECTION_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "SelectionChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='SelectionChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.ModelChanged`.
+pub const OBJECT_MODEL_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "ModelChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='ModelChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.ActiveDescendantChanged`.
+pub const OBJECT_ACTIVE_DESCENDANT_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "ActiveDescendantChanged",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Event.Object',member='ActiveDescendantChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "child", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.Announcement`.
+pub const OBJECT_ANNOUNCEMENT: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "Announcement",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='Announcement'",
+	signature: "siiva{sv}",
+	arguments: &["text", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.AttributesChanged`.
+pub const OBJECT_ATTRIBUTES_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "AttributesChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='AttributesChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.RowInserted`.
+pub const OBJECT_ROW_INSERTED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "RowInserted",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='RowInserted'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.RowReordered`.
+pub const OBJECT_ROW_REORDERED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "RowReordered",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='RowReordered'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.RowDeleted`.
+pub const OBJECT_ROW_DELETED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "RowDeleted",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='RowDeleted'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.ColumnInserted`.
+pub const OBJECT_COLUMN_INSERTED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "ColumnInserted",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='ColumnInserted'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.ColumnReordered`.
+pub const OBJECT_COLUMN_REORDERED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "ColumnReordered",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='ColumnReordered'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.ColumnDeleted`.
+pub const OBJECT_COLUMN_DELETED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "ColumnDeleted",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='ColumnDeleted'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.TextBoundsChanged`.
+pub const OBJECT_TEXT_BOUNDS_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "TextBoundsChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='TextBoundsChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.TextSelectionChanged`.
+pub const OBJECT_TEXT_SELECTION_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "TextSelectionChanged",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Event.Object',member='TextSelectionChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.TextChanged`.
+pub const OBJECT_TEXT_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "TextChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='TextChanged'",
+	signature: "siiva{sv}",
+	arguments: &["detail", "start_pos", "length", "text", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.TextAttributesChanged`.
+pub const OBJECT_TEXT_ATTRIBUTES_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "TextAttributesChanged",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Event.Object',member='TextAttributesChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Object.TextCaretMoved`.
+pub const OBJECT_TEXT_CARET_MOVED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Object",
+	member: "TextCaretMoved",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Object',member='TextCaretMoved'",
+	signature: "siiva{sv}",
+	arguments: &["", "position", "", "", "properties"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Event.Window`.
+pub const WINDOW_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Event.Window'";
+
+/// `org.a11y.atspi.Event.Window.PropertyChange`.
+pub const WINDOW_PROPERTY_CHANGE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "PropertyChange",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='PropertyChange'",
+	signature: "siiva{sv}",
+	arguments: &["property", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Minimize`.
+pub const WINDOW_MINIMIZE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Minimize",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Minimize'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Maximize`.
+pub const WINDOW_MAXIMIZE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Maximize",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Maximize'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Restore`.
+pub const WINDOW_RESTORE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Restore",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Restore'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Close`.
+pub const WINDOW_CLOSE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Close",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Close'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Create`.
+pub const WINDOW_CREATE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Create",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Create'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Reparent`.
+pub const WINDOW_REPARENT: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Reparent",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Reparent'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.DesktopCreate`.
+pub const WINDOW_DESKTOP_CREATE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "DesktopCreate",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='DesktopCreate'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.DesktopDestroy`.
+pub const WINDOW_DESKTOP_DESTROY: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "DesktopDestroy",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='DesktopDestroy'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Destroy`.
+pub const WINDOW_DESTROY: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Destroy",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Destroy'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Activate`.
+pub const WINDOW_ACTIVATE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Activate",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Activate'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Deactivate`.
+pub const WINDOW_DEACTIVATE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Deactivate",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Deactivate'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Raise`.
+pub const WINDOW_RAISE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Raise",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Raise'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Lower`.
+pub const WINDOW_LOWER: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Lower",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Lower'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Move`.
+pub const WINDOW_MOVE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Move",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Move'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Resize`.
+pub const WINDOW_RESIZE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Resize",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Resize'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Shade`.
+pub const WINDOW_SHADE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Shade",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Shade'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.uUshade`.
+pub const WINDOW_U_USHADE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "uUshade",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='uUshade'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Window.Restyle`.
+pub const WINDOW_RESTYLE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Window",
+	member: "Restyle",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Window',member='Restyle'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Event.Mouse`.
+pub const MOUSE_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Event.Mouse'";
+
+/// `org.a11y.atspi.Event.Mouse.Abs`.
+pub const MOUSE_ABS: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Mouse",
+	member: "Abs",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Mouse',member='Abs'",
+	signature: "siiva{sv}",
+	arguments: &["", "x", "y", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Mouse.Rel`.
+pub const MOUSE_REL: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Mouse",
+	member: "Rel",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Mouse',member='Rel'",
+	signature: "siiva{sv}",
+	arguments: &["", "x", "y", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Mouse.Button`.
+pub const MOUSE_BUTTON: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Mouse",
+	member: "Button",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Mouse',member='Button'",
+	signature: "siiva{sv}",
+	arguments: &["detail", "mouse_x", "mouse_y", "", "properties"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Event.Keyboard`.
+pub const KEYBOARD_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Event.Keyboard'";
+
+/// `org.a11y.atspi.Event.Keyboard.Modifiers`.
+pub const KEYBOARD_MODIFIERS: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Keyboard",
+	member: "Modifiers",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Keyboard',member='Modifiers'",
+	signature: "siiva{sv}",
+	arguments: &["", "previous_modifiers", "current_modifiers", "", "properties"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Event.Terminal`.
+pub const TERMINAL_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Event.Terminal'";
+
+/// `org.a11y.atspi.Event.Terminal.LineChanged`.
+pub const TERMINAL_LINE_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Terminal",
+	member: "LineChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Terminal',member='LineChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Terminal.ColumncountChanged`.
+pub const TERMINAL_COLUMNCOUNT_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Terminal",
+	member: "ColumncountChanged",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Event.Terminal',member='ColumncountChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Terminal.LinecountChanged`.
+pub const TERMINAL_LINECOUNT_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Terminal",
+	member: "LinecountChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Terminal',member='LinecountChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Terminal.ApplicationChanged`.
+pub const TERMINAL_APPLICATION_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Terminal",
+	member: "ApplicationChanged",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Event.Terminal',member='ApplicationChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Terminal.CharwidthChanged`.
+pub const TERMINAL_CHARWIDTH_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Terminal",
+	member: "CharwidthChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Terminal',member='CharwidthChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Event.Document`.
+pub const DOCUMENT_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Event.Document'";
+
+/// `org.a11y.atspi.Event.Document.LoadComplete`.
+pub const DOCUMENT_LOAD_COMPLETE: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Document",
+	member: "LoadComplete",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Document',member='LoadComplete'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Document.Reload`.
+pub const DOCUMENT_RELOAD: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Document",
+	member: "Reload",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Document',member='Reload'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Document.LoadStopped`.
+pub const DOCUMENT_LOAD_STOPPED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Document",
+	member: "LoadStopped",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Document',member='LoadStopped'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Document.ContentChanged`.
+pub const DOCUMENT_CONTENT_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Document",
+	member: "ContentChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Document',member='ContentChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Document.AttributesChanged`.
+pub const DOCUMENT_ATTRIBUTES_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Document",
+	member: "AttributesChanged",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Event.Document',member='AttributesChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// `org.a11y.atspi.Event.Document.PageChanged`.
+pub const DOCUMENT_PAGE_CHANGED: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Document",
+	member: "PageChanged",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Document',member='PageChanged'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Event.Focus`.
+pub const FOCUS_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Event.Focus'";
+
+/// `org.a11y.atspi.Event.Focus.Focus`.
+pub const FOCUS_FOCUS: Signal = Signal {
+	interface: "org.a11y.atspi.Event.Focus",
+	member: "Focus",
+	match_rule: "type='signal',interface='org.a11y.atspi.Event.Focus',member='Focus'",
+	signature: "siiva{sv}",
+	arguments: &["", "", "", "", "properties"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Registry`.
+pub const REGISTRY_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Registry'";
+
+/// `org.a11y.atspi.Registry.EventListenerRegistered`.
+pub const REGISTRY_EVENT_LISTENER_REGISTERED: Signal = Signal {
+	interface: "org.a11y.atspi.Registry",
+	member: "EventListenerRegistered",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Registry',member='EventListenerRegistered'",
+	signature: "ss",
+	arguments: &["bus", "path"],
+};
+
+/// `org.a11y.atspi.Registry.EventListenerDeregistered`.
+pub const REGISTRY_EVENT_LISTENER_DEREGISTERED: Signal = Signal {
+	interface: "org.a11y.atspi.Registry",
+	member: "EventListenerDeregistered",
+	match_rule:
+		"type='signal',interface='org.a11y.atspi.Registry',member='EventListenerDeregistered'",
+	signature: "ss",
+	arguments: &["bus", "path"],
+};
+
+/// The match rule selecting all signals of `org.a11y.atspi.Socket`.
+pub const SOCKET_MATCH_RULE: &str = "type='signal',interface='org.a11y.atspi.Socket'";
+
+/// `org.a11y.atspi.Socket.Available`.
+pub const SOCKET_AVAILABLE: Signal = Signal {
+	interface: "org.a11y.atspi.Socket",
+	member: "Available",
+	match_rule: "type='signal',interface='org.a11y.atspi.Socket',member='Available'",
+	signature: "(so)",
+	arguments: &["socket"],
+};
+
+/// All signals, in the order of the XML files and their declarations.
+pub const SIGNALS: &[Signal] = &[
+	CACHE_ADD_ACCESSIBLE,
+	CACHE_REMOVE_ACCESSIBLE,
+	DEVICE_EVENT_LISTENER_KEYSTROKE_LISTENER_REGISTERED,
+	DEVICE_EVENT_LISTENER_KEYSTROKE_LISTENER_DEREGISTERED,
+	OBJECT_PROPERTY_CHANGE,
+	OBJECT_BOUNDS_CHANGED,
+	OBJECT_LINK_SELECTED,
+	OBJECT_STATE_CHANGED,
+	OBJECT_CHILDREN_CHANGED,
+	OBJECT_VISIBLE_DATA_CHANGED,
+	OBJECT_SELECTION_CHANGED,
+	OBJECT_MODEL_CHANGED,
+	OBJECT_ACTIVE_DESCENDANT_CHANGED,
+	OBJECT_ANNOUNCEMENT,
+	OBJECT_ATTRIBUTES_CHANGED,
+	OBJECT_ROW_INSERTED,
+	OBJECT_ROW_REORDERED,
+	OBJECT_ROW_DELETED,
+	OBJECT_COLUMN_INSERTED,
+	OBJECT_COLUMN_REORDERED,
+	OBJECT_COLUMN_DELETED,
+	OBJECT_TEXT_BOUNDS_CHANGED,
+	OBJECT_TEXT_SELECTION_CHANGED,
+	OBJECT_TEXT_CHANGED,
+	OBJECT_TEXT_ATTRIBUTES_CHANGED,
+	OBJECT_TEXT_CARET_MOVED,
+	WINDOW_PROPERTY_CHANGE,
+	WINDOW_MINIMIZE,
+	WINDOW_MAXIMIZE,
+	WINDOW_RESTORE,
+	WINDOW_CLOSE,
+	WINDOW_CREATE,
+	WINDOW_REPARENT,
+	WINDOW_DESKTOP_CREATE,
+	WINDOW_DESKTOP_DESTROY,
+	WINDOW_DESTROY,
+	WINDOW_ACTIVATE,
+	WINDOW_DEACTIVATE,
+	WINDOW_RAISE,
+	WINDOW_LOWER,
+	WINDOW_MOVE,
+	WINDOW_RESIZE,
+	WINDOW_SHADE,
+	WINDOW_U_USHADE,
+	WINDOW_RESTYLE,
+	MOUSE_ABS,
+	MOUSE_REL,
+	MOUSE_BUTTON,
+	KEYBOARD_MODIFIERS,
+	TERMINAL_LINE_CHANGED,
+	TERMINAL_COLUMNCOUNT_CHANGED,
+	TERMINAL_LINECOUNT_CHANGED,
+	TERMINAL_APPLICATION_CHANGED,
+	TERMINAL_CHARWIDTH_CHANGED,
+	DOCUMENT_LOAD_COMPLETE,
+	DOCUMENT_RELOAD,
+	DOCUMENT_LOAD_STOPPED,
+	DOCUMENT_CONTENT_CHANGED,
+	DOCUMENT_ATTRIBUTES_CHANGED,
+	DOCUMENT_PAGE_CHANGED,
+	FOCUS_FOCUS,
+	REGISTRY_EVENT_LISTENER_REGISTERED,
+	REGISTRY_EVENT_LISTENER_DEREGISTERED,
+	SOCKET_AVAILABLE,
+];
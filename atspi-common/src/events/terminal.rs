@@ -1,10 +1,17 @@
+//! The events of `org.a11y.atspi.Event.Terminal`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
+
 use crate::{
 	error::AtspiError,
-	events::{Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString},
+	events::{
+		signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString,
+	},
 	Event,
 };
 use zvariant::ObjectPath;
 
+/// The events of `org.a11y.atspi.Event.Terminal`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum TerminalEvents {
 	LineChanged(LineChangedEvent),
@@ -17,40 +24,43 @@ impl_event_conversions!(TerminalEvents, Event::Terminal);
 event_wrapper_test_cases!(TerminalEvents, LineChangedEvent);
 
 impl HasMatchRule for TerminalEvents {
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Terminal'";
+	const MATCH_RULE_STRING: &'static str = signals::TERMINAL_MATCH_RULE;
 }
 
+/// The `org.a11y.atspi.Event.Terminal:LineChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct LineChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Terminal:ColumncountChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ColumnCountChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Terminal:LinecountChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct LineCountChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Terminal:ApplicationChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ApplicationChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Terminal:CharwidthChanged` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct CharWidthChangedEvent {
 	pub item: crate::events::Accessible,
 }
 
 impl GenericEvent<'_> for LineChangedEvent {
-	const DBUS_MEMBER: &'static str = "LineChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Terminal";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Terminal',member='LineChanged'";
+	const DBUS_MEMBER: &'static str = signals::TERMINAL_LINE_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::TERMINAL_LINE_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::TERMINAL_LINE_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 
 	type Body = EventBodyOwned;
@@ -71,10 +81,9 @@ impl GenericEvent<'_> for LineChangedEvent {
 }
 
 impl GenericEvent<'_> for ColumnCountChangedEvent {
-	const DBUS_MEMBER: &'static str = "ColumncountChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Terminal";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Terminal',member='ColumncountChanged'";
+	const DBUS_MEMBER: &'static str = signals::TERMINAL_COLUMNCOUNT_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::TERMINAL_COLUMNCOUNT_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::TERMINAL_COLUMNCOUNT_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 
 	type Body = EventBodyOwned;
@@ -95,10 +104,9 @@ impl GenericEvent<'_> for ColumnCountChangedEvent {
 }
 
 impl GenericEvent<'_> for LineCountChangedEvent {
-	const DBUS_MEMBER: &'static str = "LinecountChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Terminal";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Terminal',member='LinecountChanged'";
+	const DBUS_MEMBER: &'static str = signals::TERMINAL_LINECOUNT_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::TERMINAL_LINECOUNT_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::TERMINAL_LINECOUNT_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 
 	type Body = EventBodyOwned;
@@ -119,10 +127,9 @@ impl GenericEvent<'_> for LineCountChangedEvent {
 }
 
 impl GenericEvent<'_> for ApplicationChangedEvent {
-	const DBUS_MEMBER: &'static str = "ApplicationChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Terminal";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Terminal',member='ApplicationChanged'";
+	const DBUS_MEMBER: &'static str = signals::TERMINAL_APPLICATION_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::TERMINAL_APPLICATION_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::TERMINAL_APPLICATION_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 
 	type Body = EventBodyOwned;
@@ -143,10 +150,9 @@ impl GenericEvent<'_> for ApplicationChangedEvent {
 }
 
 impl GenericEvent<'_> for CharWidthChangedEvent {
-	const DBUS_MEMBER: &'static str = "CharwidthChanged";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Terminal";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Terminal',member='CharwidthChanged'";
+	const DBUS_MEMBER: &'static str = signals::TERMINAL_CHARWIDTH_CHANGED.member;
+	const DBUS_INTERFACE: &'static str = signals::TERMINAL_CHARWIDTH_CHANGED.interface;
+	const MATCH_RULE_STRING: &'static str = signals::TERMINAL_CHARWIDTH_CHANGED.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Terminal:";
 
 	type Body = EventBodyOwned;
@@ -1,10 +1,17 @@
+//! The events of `org.a11y.atspi.Event.Window`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
+
 use crate::{
 	error::AtspiError,
-	events::{Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString},
+	events::{
+		signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString,
+	},
 	Event,
 };
 use zvariant::ObjectPath;
 
+/// The events of `org.a11y.atspi.Event.Window`.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub enum WindowEvents {
 	PropertyChange(PropertyChangeEvent),
@@ -28,113 +35,131 @@ pub enum WindowEvents {
 	Restyle(RestyleEvent),
 }
 impl_event_conversions!(WindowEvents, Event::Window);
-event_wrapper_test_cases!(WindowEvents, MoveEvent);
+event_wrapper_test_cases!(WindowEvents, PropertyChangeEvent);
 
 impl HasMatchRule for WindowEvents {
-	const MATCH_RULE_STRING: &'static str = "type='signal',interface='org.a11y.atspi.Event.Window'";
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_MATCH_RULE;
 }
 
+/// The `org.a11y.atspi.Event.Window:PropertyChange` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct PropertyChangeEvent {
 	pub item: crate::events::Accessible,
 	pub property: String,
 }
 
+/// The `org.a11y.atspi.Event.Window:Minimize` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct MinimizeEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Maximize` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct MaximizeEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Restore` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct RestoreEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Close` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct CloseEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Create` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct CreateEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Reparent` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ReparentEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:DesktopCreate` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct DesktopCreateEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:DesktopDestroy` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct DesktopDestroyEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Destroy` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct DestroyEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Activate` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ActivateEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Deactivate` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct DeactivateEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Raise` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct RaiseEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Lower` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct LowerEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Move` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct MoveEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Resize` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ResizeEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Shade` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct ShadeEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:uUshade` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct UUshadeEvent {
 	pub item: crate::events::Accessible,
 }
 
+/// The `org.a11y.atspi.Event.Window:Restyle` event.
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default)]
 pub struct RestyleEvent {
 	pub item: crate::events::Accessible,
 }
 
 impl GenericEvent<'_> for PropertyChangeEvent {
-	const DBUS_MEMBER: &'static str = "PropertyChange";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='PropertyChange'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_PROPERTY_CHANGE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_PROPERTY_CHANGE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_PROPERTY_CHANGE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -155,10 +180,9 @@ impl GenericEvent<'_> for PropertyChangeEvent {
 }
 
 impl GenericEvent<'_> for MinimizeEvent {
-	const DBUS_MEMBER: &'static str = "Minimize";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Minimize'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_MINIMIZE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_MINIMIZE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_MINIMIZE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -179,10 +203,9 @@ impl GenericEvent<'_> for MinimizeEvent {
 }
 
 impl GenericEvent<'_> for MaximizeEvent {
-	const DBUS_MEMBER: &'static str = "Maximize";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Maximize'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_MAXIMIZE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_MAXIMIZE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_MAXIMIZE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -203,10 +226,9 @@ impl GenericEvent<'_> for MaximizeEvent {
 }
 
 impl GenericEvent<'_> for RestoreEvent {
-	const DBUS_MEMBER: &'static str = "Restore";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Restore'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_RESTORE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_RESTORE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_RESTORE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -227,10 +249,9 @@ impl GenericEvent<'_> for RestoreEvent {
 }
 
 impl GenericEvent<'_> for CloseEvent {
-	const DBUS_MEMBER: &'static str = "Close";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Close'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_CLOSE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_CLOSE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_CLOSE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -251,10 +272,9 @@ impl GenericEvent<'_> for CloseEvent {
 }
 
 impl GenericEvent<'_> for CreateEvent {
-	const DBUS_MEMBER: &'static str = "Create";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Create'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_CREATE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_CREATE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_CREATE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -275,10 +295,9 @@ impl GenericEvent<'_> for CreateEvent {
 }
 
 impl GenericEvent<'_> for ReparentEvent {
-	const DBUS_MEMBER: &'static str = "Reparent";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Reparent'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_REPARENT.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_REPARENT.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_REPARENT.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -299,10 +318,9 @@ impl GenericEvent<'_> for ReparentEvent {
 }
 
 impl GenericEvent<'_> for DesktopCreateEvent {
-	const DBUS_MEMBER: &'static str = "DesktopCreate";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='DesktopCreate'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_DESKTOP_CREATE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_DESKTOP_CREATE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_DESKTOP_CREATE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -323,10 +341,9 @@ impl GenericEvent<'_> for DesktopCreateEvent {
 }
 
 impl GenericEvent<'_> for DesktopDestroyEvent {
-	const DBUS_MEMBER: &'static str = "DesktopDestroy";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='DesktopDestroy'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_DESKTOP_DESTROY.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_DESKTOP_DESTROY.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_DESKTOP_DESTROY.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -347,10 +364,9 @@ impl GenericEvent<'_> for DesktopDestroyEvent {
 }
 
 impl GenericEvent<'_> for DestroyEvent {
-	const DBUS_MEMBER: &'static str = "Destroy";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Destroy'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_DESTROY.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_DESTROY.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_DESTROY.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -371,10 +387,9 @@ impl GenericEvent<'_> for DestroyEvent {
 }
 
 impl GenericEvent<'_> for ActivateEvent {
-	const DBUS_MEMBER: &'static str = "Activate";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Activate'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_ACTIVATE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_ACTIVATE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_ACTIVATE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -395,10 +410,9 @@ impl GenericEvent<'_> for ActivateEvent {
 }
 
 impl GenericEvent<'_> for DeactivateEvent {
-	const DBUS_MEMBER: &'static str = "Deactivate";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Deactivate'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_DEACTIVATE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_DEACTIVATE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_DEACTIVATE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -419,10 +433,9 @@ impl GenericEvent<'_> for DeactivateEvent {
 }
 
 impl GenericEvent<'_> for RaiseEvent {
-	const DBUS_MEMBER: &'static str = "Raise";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Raise'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_RAISE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_RAISE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_RAISE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -443,10 +456,9 @@ impl GenericEvent<'_> for RaiseEvent {
 }
 
 impl GenericEvent<'_> for LowerEvent {
-	const DBUS_MEMBER: &'static str = "Lower";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Lower'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_LOWER.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_LOWER.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_LOWER.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -467,10 +479,9 @@ impl GenericEvent<'_> for LowerEvent {
 }
 
 impl GenericEvent<'_> for MoveEvent {
-	const DBUS_MEMBER: &'static str = "Move";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Move'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_MOVE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_MOVE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_MOVE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -491,10 +502,9 @@ impl GenericEvent<'_> for MoveEvent {
 }
 
 impl GenericEvent<'_> for ResizeEvent {
-	const DBUS_MEMBER: &'static str = "Resize";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Resize'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_RESIZE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_RESIZE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_RESIZE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -515,10 +525,9 @@ impl GenericEvent<'_> for ResizeEvent {
 }
 
 impl GenericEvent<'_> for ShadeEvent {
-	const DBUS_MEMBER: &'static str = "Shade";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Shade'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_SHADE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_SHADE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_SHADE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -539,10 +548,9 @@ impl GenericEvent<'_> for ShadeEvent {
 }
 
 impl GenericEvent<'_> for UUshadeEvent {
-	const DBUS_MEMBER: &'static str = "uUshade";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='uUshade'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_U_USHADE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_U_USHADE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_U_USHADE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -563,10 +571,9 @@ impl GenericEvent<'_> for UUshadeEvent {
 }
 
 impl GenericEvent<'_> for RestyleEvent {
-	const DBUS_MEMBER: &'static str = "Restyle";
-	const DBUS_INTERFACE: &'static str = "org.a11y.atspi.Event.Window";
-	const MATCH_RULE_STRING: &'static str =
-		"type='signal',interface='org.a11y.atspi.Event.Window',member='Restyle'";
+	const DBUS_MEMBER: &'static str = signals::WINDOW_RESTYLE.member;
+	const DBUS_INTERFACE: &'static str = signals::WINDOW_RESTYLE.interface;
+	const MATCH_RULE_STRING: &'static str = signals::WINDOW_RESTYLE.match_rule;
 	const REGISTRY_EVENT_STRING: &'static str = "Window:";
 
 	type Body = EventBodyOwned;
@@ -10,6 +10,8 @@ pub mod accessible;
 pub use accessible::Accessible;
 pub mod interface;
 pub use interface::{Interface, InterfaceSet};
+pub mod object_attributes;
+pub use object_attributes::ObjectAttributes;
 pub mod state;
 pub use state::{State, StateSet};
 pub mod cache;
@@ -22,6 +24,8 @@ mod role;
 pub use role::Role;
 mod relation_type;
 pub use relation_type::RelationType;
+mod relation_set;
+pub use relation_set::RelationSet;
 
 use serde::{Deserialize, Serialize};
 use zvariant::Type;
@@ -105,6 +109,30 @@ pub enum Granularity {
 	Paragraph,
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[repr(u32)]
+/// Which boundaries delimit the text around an offset, for
+/// `atspi_proxies::text::TextProxy::get_text_at_offset` and its siblings.
+///
+/// A `*Start` boundary makes a unit run from the start of one word, sentence or line to the start
+/// of the next; an `*End` boundary runs from end to end.
+pub enum TextBoundaryType {
+	/// A single character.
+	Char,
+	/// From the start of a word to the start of the next one.
+	WordStart,
+	/// From the end of a word to the end of the next one.
+	WordEnd,
+	/// From the start of a sentence to the start of the next one.
+	SentenceStart,
+	/// From the end of a sentence to the end of the next one.
+	SentenceEnd,
+	/// From the start of a line to the start of the next one.
+	LineStart,
+	/// From the end of a line to the end of the next one.
+	LineEnd,
+}
+
 /// Indicates relative stacking order of a `atspi_proxies::component::ComponentProxy` with respect to the
 /// onscreen visual representation of the UI.
 ///
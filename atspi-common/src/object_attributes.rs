@@ -0,0 +1,442 @@
+//! Typed access to the object attributes web engines expose.
+//!
+//! Browsers pass ARIA information on through `GetAttributes`, mixed with other
+//! attributes in a map of strings. [`ObjectAttributes`] parses the well-known keys used by
+//! Gecko, `WebKit` and Chromium, and [`ObjectAttributes::aria`] combines them with the role and
+//! states of the object into the [`AriaView`] an ARIA author would recognise.
+
+use crate::{Role, State, StateSet};
+use std::collections::HashMap;
+
+/// How urgently changes to a live region should be announced; `aria-live`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Live {
+	/// Changes are not announced.
+	Off,
+	/// Changes are announced when the user is idle.
+	Polite,
+	/// Changes are announced immediately.
+	Assertive,
+}
+
+impl Live {
+	fn parse(value: &str) -> Option<Self> {
+		match value {
+			"off" => Some(Self::Off),
+			"polite" => Some(Self::Polite),
+			"assertive" => Some(Self::Assertive),
+			_ => None,
+		}
+	}
+}
+
+/// The kind of popup an object opens; `aria-haspopup`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HasPopup {
+	/// A menu, also used for `aria-haspopup="true"`.
+	Menu,
+	/// A list box.
+	Listbox,
+	/// A tree.
+	Tree,
+	/// A grid.
+	Grid,
+	/// A dialog.
+	Dialog,
+}
+
+impl HasPopup {
+	/// Returns `Ok(None)` for `false`, and `Err(())` for values that are not understood.
+	fn parse(value: &str) -> Result<Option<Self>, ()> {
+		Ok(Some(match value {
+			"false" => return Ok(None),
+			"true" | "menu" => Self::Menu,
+			"listbox" => Self::Listbox,
+			"tree" => Self::Tree,
+			"grid" => Self::Grid,
+			"dialog" => Self::Dialog,
+			_ => return Err(()),
+		}))
+	}
+}
+
+/// Which changes to a live region are announced; `aria-relevant`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Relevant {
+	/// Nodes added to the region.
+	pub additions: bool,
+	/// Nodes removed from the region.
+	pub removals: bool,
+	/// Text changed within the region.
+	pub text: bool,
+}
+
+impl Default for Relevant {
+	/// `additions text`, the ARIA default.
+	fn default() -> Self {
+		Self { additions: true, removals: false, text: true }
+	}
+}
+
+impl Relevant {
+	fn parse(value: &str) -> Option<Self> {
+		let mut relevant = Self { additions: false, removals: false, text: false };
+		for token in value.split_ascii_whitespace() {
+			match token {
+				"additions" => relevant.additions = true,
+				"removals" => relevant.removals = true,
+				"text" => relevant.text = true,
+				"all" => relevant = Self { additions: true, removals: true, text: true },
+				_ => return None,
+			}
+		}
+		Some(relevant)
+	}
+}
+
+/// The value of a tri-state ARIA property such as `aria-checked` or `aria-pressed`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Checked {
+	/// Not checked or pressed.
+	False,
+	/// Checked or pressed.
+	True,
+	/// Partially checked, such as a check box for a group of which some items are checked.
+	Mixed,
+}
+
+/// The object attributes of an object, parsed.
+///
+/// Keys that are not understood, and well-known keys whose value cannot be parsed,
+/// are kept as they are in [`ObjectAttributes::other`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjectAttributes {
+	/// The ARIA roles given by the author, most preferred first; `xml-roles`.
+	pub xml_roles: Vec<String>,
+	/// The name of the HTML element; `tag`.
+	pub tag: Option<String>,
+	/// The `id` of the HTML element; `id`.
+	pub id: Option<String>,
+	/// The `class` of the HTML element; `class`.
+	pub class: Option<String>,
+	/// The CSS `display` value; `display`.
+	pub display: Option<String>,
+	/// The heading, tree item or list nesting level, starting at 1; `level`.
+	pub level: Option<u32>,
+	/// The position in the set of siblings, starting at 1; `posinset`.
+	pub position_in_set: Option<u32>,
+	/// The number of items in the set of siblings; `setsize`.
+	pub set_size: Option<u32>,
+	/// The popup the object opens; `haspopup`.
+	pub has_popup: Option<HasPopup>,
+	/// The politeness of the object, if it is a live region; `live`.
+	pub live: Option<Live>,
+	/// The changes announced, if the object is a live region; `relevant`.
+	pub relevant: Option<Relevant>,
+	/// Whether the whole region is announced when part of it changes; `atomic`.
+	pub atomic: Option<bool>,
+	/// Whether the region is being updated; `busy`.
+	pub busy: Option<bool>,
+	/// The politeness of the live region containing the object; `container-live`.
+	pub container_live: Option<Live>,
+	/// The changes announced by the live region containing the object; `container-relevant`.
+	pub container_relevant: Option<Relevant>,
+	/// Whether the live region containing the object is atomic; `container-atomic`.
+	pub container_atomic: Option<bool>,
+	/// Whether the live region containing the object is busy; `container-busy`.
+	pub container_busy: Option<bool>,
+	/// The hint shown in an empty entry; `placeholder`, or `placeholder-text` in `WebKit`.
+	pub placeholder: Option<String>,
+	/// The author's description of the role; `roledescription`.
+	pub role_description: Option<String>,
+	/// Which item of a set is current, for example `page`; `current`.
+	pub current: Option<String>,
+	/// The sort order of a column header; `sort`.
+	pub sort: Option<String>,
+	/// The value of a range widget as text; `valuetext`.
+	pub value_text: Option<String>,
+	/// Keyboard shortcuts activating the object; `keyshortcuts`.
+	pub key_shortcuts: Option<String>,
+	/// The kind of completion an entry offers; `autocomplete`.
+	pub autocomplete: Option<String>,
+	/// The `type` of an HTML input element; `text-input-type`.
+	pub text_input_type: Option<String>,
+	/// The number of columns of a table, including hidden ones; `colcount`.
+	pub column_count: Option<i32>,
+	/// The number of rows of a table, including hidden ones; `rowcount`.
+	pub row_count: Option<i32>,
+	/// The column of a cell, starting at 1; `colindex`.
+	pub column_index: Option<i32>,
+	/// The row of a cell or row, starting at 1; `rowindex`.
+	pub row_index: Option<i32>,
+	/// All other attributes.
+	pub other: HashMap<String, String>,
+}
+
+impl From<HashMap<String, String>> for ObjectAttributes {
+	fn from(attributes: HashMap<String, String>) -> Self {
+		let mut parsed = Self::default();
+		for (key, value) in attributes {
+			if !parsed.parse(&key, &value) {
+				parsed.other.insert(key, value);
+			}
+		}
+		parsed
+	}
+}
+
+impl ObjectAttributes {
+	/// Stores a well-known attribute; returns `false` if `key` is unknown or `value` invalid.
+	fn parse(&mut self, key: &str, value: &str) -> bool {
+		fn set<T>(field: &mut Option<T>, value: Option<T>) -> bool {
+			*field = value;
+			field.is_some()
+		}
+		fn boolean(value: &str) -> Option<bool> {
+			match value {
+				"true" => Some(true),
+				"false" => Some(false),
+				_ => None,
+			}
+		}
+		match key {
+			"xml-roles" => {
+				self.xml_roles = value.split_ascii_whitespace().map(str::to_string).collect();
+				true
+			}
+			"tag" => set(&mut self.tag, Some(value.to_string())),
+			"id" => set(&mut self.id, Some(value.to_string())),
+			"class" => set(&mut self.class, Some(value.to_string())),
+			"display" => set(&mut self.display, Some(value.to_string())),
+			"level" => set(&mut self.level, value.parse().ok()),
+			"posinset" => set(&mut self.position_in_set, value.parse().ok()),
+			"setsize" => set(&mut self.set_size, value.parse().ok()),
+			"haspopup" => match HasPopup::parse(value) {
+				Ok(has_popup) => {
+					self.has_popup = has_popup;
+					true
+				}
+				Err(()) => false,
+			},
+			"live" => set(&mut self.live, Live::parse(value)),
+			"relevant" => set(&mut self.relevant, Relevant::parse(value)),
+			"atomic" => set(&mut self.atomic, boolean(value)),
+			"busy" => set(&mut self.busy, boolean(value)),
+			"container-live" => set(&mut self.container_live, Live::parse(value)),
+			"container-relevant" => set(&mut self.container_relevant, Relevant::parse(value)),
+			"container-atomic" => set(&mut self.container_atomic, boolean(value)),
+			"container-busy" => set(&mut self.container_busy, boolean(value)),
+			"placeholder" | "placeholder-text" => {
+				set(&mut self.placeholder, Some(value.to_string()))
+			}
+			"roledescription" => set(&mut self.role_description, Some(value.to_string())),
+			"current" => set(&mut self.current, Some(value.to_string())),
+			"sort" => set(&mut self.sort, Some(value.to_string())),
+			"valuetext" => set(&mut self.value_text, Some(value.to_string())),
+			"keyshortcuts" => set(&mut self.key_shortcuts, Some(value.to_string())),
+			"autocomplete" => set(&mut self.autocomplete, Some(value.to_string())),
+			"text-input-type" => set(&mut self.text_input_type, Some(value.to_string())),
+			"colcount" => set(&mut self.column_count, value.parse().ok()),
+			"rowcount" => set(&mut self.row_count, value.parse().ok()),
+			"colindex" => set(&mut self.column_index, value.parse().ok()),
+			"rowindex" => set(&mut self.row_index, value.parse().ok()),
+			_ => false,
+		}
+	}
+
+	/// The ARIA role of the object: the first of [`Self::xml_roles`] that is a known ARIA
+	/// role, or else the ARIA role `role` corresponds to if there is exactly one.
+	#[must_use]
+	pub fn aria_role(&self, role: Role) -> Option<String> {
+		if let Some(xml_role) = self.xml_roles.iter().find(|r| Role::from_aria(r).is_some()) {
+			return Some(xml_role.clone());
+		}
+		let mut aria = role.aria_roles();
+		match (aria.next(), aria.next()) {
+			(Some(only), None) => Some(only.to_string()),
+			_ => None,
+		}
+	}
+
+	/// Combines the attributes with the `role` and `states` of the object into the ARIA
+	/// properties they correspond to.
+	///
+	/// Live region properties fall back to those of the containing region, and then to the
+	/// implicit values of the role, so `alert`s are assertive and `log`s polite.
+	#[must_use]
+	pub fn aria(&self, role: Role, states: StateSet) -> AriaView {
+		let tristate = || {
+			if states.contains(State::Indeterminate) {
+				Checked::Mixed
+			} else if states.contains(State::Checked) {
+				Checked::True
+			} else {
+				Checked::False
+			}
+		};
+		let checkable = states.intersects(State::Checkable | State::Checked | State::Indeterminate);
+		// Switches are toggle buttons too, but they are checked rather than pressed.
+		let pressable = role == Role::ToggleButton && !self.xml_roles.iter().any(|r| r == "switch");
+		let (implicit_live, implicit_atomic) = match role {
+			Role::Notification => (Some(Live::Assertive), true),
+			Role::StatusBar => (Some(Live::Polite), true),
+			Role::Log => (Some(Live::Polite), false),
+			Role::Marquee | Role::Timer => (Some(Live::Off), false),
+			_ => (None, false),
+		};
+		AriaView {
+			role: self.aria_role(role),
+			level: self.level,
+			has_popup: self
+				.has_popup
+				.or_else(|| states.contains(State::HasPopup).then_some(HasPopup::Menu)),
+			checked: (checkable && !pressable).then(tristate),
+			pressed: pressable.then(tristate),
+			expanded: states
+				.contains(State::Expandable)
+				.then(|| states.contains(State::Expanded)),
+			selected: states
+				.contains(State::Selectable)
+				.then(|| states.contains(State::Selected)),
+			disabled: role.is_interactive() && !states.contains(State::Enabled),
+			required: states.contains(State::Required),
+			invalid: states.contains(State::InvalidEntry),
+			readonly: states.contains(State::ReadOnly),
+			multiselectable: states.contains(State::Multiselectable),
+			multiline: states.contains(State::MultiLine),
+			modal: states.contains(State::Modal),
+			live: self.live.or(self.container_live).or(implicit_live),
+			atomic: self.atomic.or(self.container_atomic).unwrap_or(implicit_atomic),
+			relevant: self.relevant.or(self.container_relevant).unwrap_or_default(),
+			busy: self.busy.or(self.container_busy).unwrap_or(false)
+				|| states.contains(State::Busy),
+			current: self.current.clone().filter(|current| current != "false"),
+		}
+	}
+}
+
+/// The ARIA properties of an object, as returned by [`ObjectAttributes::aria`].
+///
+/// Properties which do not apply to the object are `None`; for example [`Self::checked`]
+/// for an object that cannot be checked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct AriaView {
+	/// `role`.
+	pub role: Option<String>,
+	/// `aria-level`.
+	pub level: Option<u32>,
+	/// `aria-haspopup`.
+	pub has_popup: Option<HasPopup>,
+	/// `aria-checked`.
+	pub checked: Option<Checked>,
+	/// `aria-pressed`.
+	pub pressed: Option<Checked>,
+	/// `aria-expanded`.
+	pub expanded: Option<bool>,
+	/// `aria-selected`.
+	pub selected: Option<bool>,
+	/// `aria-disabled`.
+	pub disabled: bool,
+	/// `aria-required`.
+	pub required: bool,
+	/// `aria-invalid`.
+	pub invalid: bool,
+	/// `aria-readonly`.
+	pub readonly: bool,
+	/// `aria-multiselectable`.
+	pub multiselectable: bool,
+	/// `aria-multiline`.
+	pub multiline: bool,
+	/// `aria-modal`.
+	pub modal: bool,
+	/// `aria-live`, of the object or the region containing it.
+	pub live: Option<Live>,
+	/// `aria-atomic`, of the object or the region containing it.
+	pub atomic: bool,
+	/// `aria-relevant`, of the object or the region containing it.
+	pub relevant: Relevant,
+	/// `aria-busy`, of the object or the region containing it.
+	pub busy: bool,
+	/// `aria-current`, unless it is `false`.
+	pub current: Option<String>,
+}
+
+#[cfg(test)]
+fn attributes(pairs: &[(&str, &str)]) -> ObjectAttributes {
+	pairs
+		.iter()
+		.map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+		.collect::<HashMap<_, _>>()
+		.into()
+}
+
+#[test]
+fn test_parse_gecko_attributes() {
+	let parsed = attributes(&[
+		("xml-roles", "switch checkbox"),
+		("tag", "h2"),
+		("level", "2"),
+		("haspopup", "true"),
+		("container-live", "polite"),
+		("container-relevant", "additions removals"),
+		("placeholder", "Search"),
+		("margin-left", "0px"),
+		("posinset", "many"),
+	]);
+	assert_eq!(parsed.xml_roles, ["switch", "checkbox"]);
+	assert_eq!(parsed.tag.as_deref(), Some("h2"));
+	assert_eq!(parsed.level, Some(2));
+	assert_eq!(parsed.has_popup, Some(HasPopup::Menu));
+	assert_eq!(parsed.container_live, Some(Live::Polite));
+	assert_eq!(
+		parsed.container_relevant,
+		Some(Relevant { additions: true, removals: true, text: false })
+	);
+	assert_eq!(parsed.placeholder.as_deref(), Some("Search"));
+	assert_eq!(parsed.position_in_set, None);
+	assert_eq!(parsed.other.len(), 2);
+	assert_eq!(parsed.other["posinset"], "many");
+}
+
+#[test]
+fn test_parse_webkit_attributes() {
+	let parsed = attributes(&[("placeholder-text", "Name"), ("haspopup", "false")]);
+	assert_eq!(parsed.placeholder.as_deref(), Some("Name"));
+	assert_eq!(parsed.has_popup, None);
+	assert!(parsed.other.is_empty());
+}
+
+#[test]
+fn test_aria_view() {
+	let switch = attributes(&[("xml-roles", "switch")]);
+	let view = switch.aria(Role::ToggleButton, StateSet::new(State::Enabled | State::Checked));
+	assert_eq!(view.role.as_deref(), Some("switch"));
+	assert_eq!(view.checked, Some(Checked::True));
+	assert_eq!(view.pressed, None);
+	assert!(!view.disabled);
+
+	let button = ObjectAttributes::default();
+	let view = button.aria(Role::ToggleButton, StateSet::new(State::Checkable | State::Checked));
+	assert_eq!(view.pressed, Some(Checked::True));
+	assert_eq!(view.checked, None);
+
+	let none = ObjectAttributes::default();
+	let view = none.aria(Role::CheckBox, StateSet::new(State::Checkable | State::Indeterminate));
+	assert_eq!(view.role.as_deref(), Some("checkbox"));
+	assert_eq!(view.checked, Some(Checked::Mixed));
+	assert!(view.disabled);
+
+	let alert = none.aria(Role::Notification, StateSet::empty());
+	assert_eq!(alert.live, Some(Live::Assertive));
+	assert!(alert.atomic);
+	assert_eq!(alert.relevant, Relevant::default());
+
+	let inside = attributes(&[("container-live", "polite"), ("container-busy", "true")]);
+	let view = inside.aria(Role::Paragraph, StateSet::empty());
+	assert_eq!(view.live, Some(Live::Polite));
+	assert!(view.busy);
+	assert_eq!(view.role.as_deref(), Some("paragraph"));
+	assert_eq!(none.aria(Role::Landmark, StateSet::empty()).role, None);
+}
@@ -0,0 +1,121 @@
+use crate::{Accessible, RelationType};
+use serde::{Deserialize, Serialize};
+use zvariant::Type;
+
+/// The relations of one object to others, as returned by `GetRelationSet`.
+///
+/// Every entry pairs a [`RelationType`] with the objects it points to.
+/// An object may list the same relation type more than once; lookups treat those entries as one.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub struct RelationSet(Vec<(RelationType, Vec<Accessible>)>);
+
+impl RelationSet {
+	/// Creates an empty set.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Whether the set has no relations at all.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.0.iter().all(|(_, targets)| targets.is_empty())
+	}
+
+	/// Whether the set has a `relation` with at least one target.
+	#[must_use]
+	pub fn contains(&self, relation: RelationType) -> bool {
+		self.targets(relation).next().is_some()
+	}
+
+	/// The targets of `relation`, in the order the application listed them.
+	pub fn targets(&self, relation: RelationType) -> impl Iterator<Item = &Accessible> + '_ {
+		self.0
+			.iter()
+			.filter(move |(r, _)| *r == relation)
+			.flat_map(|(_, targets)| targets)
+	}
+
+	/// Whether `target` is one of the targets of `relation`.
+	#[must_use]
+	pub fn points_to(&self, relation: RelationType, target: &Accessible) -> bool {
+		self.targets(relation).any(|t| t == target)
+	}
+
+	/// Whether this set, belonging to a target of `source`'s `relation`, points back to `source`
+	/// with the [reciprocal](RelationType::reciprocal) relation.
+	///
+	/// Relations without a reciprocal are always considered reciprocated.
+	#[must_use]
+	pub fn reciprocates(&self, relation: RelationType, source: &Accessible) -> bool {
+		match relation.reciprocal() {
+			Some(expected) => self.points_to(expected, source),
+			None => true,
+		}
+	}
+
+	/// Iterates over the relations and their targets.
+	pub fn iter(&self) -> impl Iterator<Item = (RelationType, &[Accessible])> + '_ {
+		self.0
+			.iter()
+			.map(|(relation, targets)| (*relation, targets.as_slice()))
+	}
+}
+
+impl From<Vec<(RelationType, Vec<Accessible>)>> for RelationSet {
+	fn from(relations: Vec<(RelationType, Vec<Accessible>)>) -> Self {
+		Self(relations)
+	}
+}
+
+impl From<RelationSet> for Vec<(RelationType, Vec<Accessible>)> {
+	fn from(set: RelationSet) -> Self {
+		set.0
+	}
+}
+
+impl FromIterator<(RelationType, Vec<Accessible>)> for RelationSet {
+	fn from_iter<I: IntoIterator<Item = (RelationType, Vec<Accessible>)>>(iter: I) -> Self {
+		Self(iter.into_iter().collect())
+	}
+}
+
+#[cfg(test)]
+fn object(index: u32) -> Accessible {
+	Accessible {
+		name: ":1.1".into(),
+		path: format!("/org/a11y/atspi/accessible/{index}").try_into().unwrap(),
+	}
+}
+
+#[test]
+fn test_relation_set_lookup() {
+	let set: RelationSet = vec![
+		(RelationType::LabelledBy, vec![object(1)]),
+		(RelationType::MemberOf, vec![object(2), object(3)]),
+		(RelationType::LabelledBy, vec![object(4)]),
+		(RelationType::FlowsTo, vec![]),
+	]
+	.into();
+	assert!(!set.is_empty());
+	assert!(set.contains(RelationType::MemberOf));
+	assert!(!set.contains(RelationType::FlowsTo));
+	assert_eq!(set.targets(RelationType::LabelledBy).collect::<Vec<_>>(), [&object(1), &object(4)]);
+	assert!(set.points_to(RelationType::MemberOf, &object(3)));
+	assert!(!set.points_to(RelationType::MemberOf, &object(1)));
+	assert!(RelationSet::new().is_empty());
+}
+
+#[test]
+fn test_relation_set_reciprocates() {
+	let label: RelationSet = vec![(RelationType::LabelFor, vec![object(1)])].into();
+	assert!(label.reciprocates(RelationType::LabelledBy, &object(1)));
+	assert!(!label.reciprocates(RelationType::LabelledBy, &object(2)));
+	assert!(!label.reciprocates(RelationType::DescribedBy, &object(1)));
+	assert!(label.reciprocates(RelationType::MemberOf, &object(2)));
+}
+
+#[test]
+fn test_relation_set_signature() {
+	assert_eq!(RelationSet::signature(), "a(ua(so))");
+}
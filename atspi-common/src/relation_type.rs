@@ -118,3 +118,75 @@ pub enum RelationType {
 	/// Included in upstream [AT-SPI2-CORE](https://gitlab.gnome.org/GNOME/at-spi2-core) since 2.26.
 	ErrorFor,
 }
+
+impl RelationType {
+	/// The relation a target is expected to have back to its source, for relations that come in pairs.
+	///
+	/// For example, an object which is [`RelationType::LabelFor`] an entry should be found in the
+	/// [`RelationType::LabelledBy`] targets of that entry.
+	/// Returns `None` for relations without a counterpart, such as [`RelationType::MemberOf`].
+	#[must_use]
+	pub fn reciprocal(self) -> Option<Self> {
+		Some(match self {
+			Self::LabelFor => Self::LabelledBy,
+			Self::LabelledBy => Self::LabelFor,
+			Self::ControllerFor => Self::ControlledBy,
+			Self::ControlledBy => Self::ControllerFor,
+			Self::NodeChildOf => Self::NodeParentOf,
+			Self::NodeParentOf => Self::NodeChildOf,
+			Self::FlowsTo => Self::FlowsFrom,
+			Self::FlowsFrom => Self::FlowsTo,
+			Self::Embeds => Self::EmbeddedBy,
+			Self::EmbeddedBy => Self::Embeds,
+			Self::PopupFor => Self::ParentWindowOf,
+			Self::ParentWindowOf => Self::PopupFor,
+			Self::DescriptionFor => Self::DescribedBy,
+			Self::DescribedBy => Self::DescriptionFor,
+			Self::Details => Self::DetailsFor,
+			Self::DetailsFor => Self::Details,
+			Self::ErrorMessage => Self::ErrorFor,
+			Self::ErrorFor => Self::ErrorMessage,
+			Self::Null | Self::MemberOf | Self::TooltipFor | Self::Extended | Self::SubwindowOf => {
+				return None
+			}
+		})
+	}
+}
+
+#[test]
+fn test_reciprocal_is_symmetric() {
+	use RelationType as R;
+	let all = [
+		R::Null,
+		R::LabelFor,
+		R::LabelledBy,
+		R::ControllerFor,
+		R::ControlledBy,
+		R::MemberOf,
+		R::TooltipFor,
+		R::NodeChildOf,
+		R::NodeParentOf,
+		R::Extended,
+		R::FlowsTo,
+		R::FlowsFrom,
+		R::SubwindowOf,
+		R::Embeds,
+		R::EmbeddedBy,
+		R::PopupFor,
+		R::ParentWindowOf,
+		R::DescriptionFor,
+		R::DescribedBy,
+		R::Details,
+		R::DetailsFor,
+		R::ErrorMessage,
+		R::ErrorFor,
+	];
+	for relation in all {
+		if let Some(reciprocal) = relation.reciprocal() {
+			assert_ne!(relation, reciprocal);
+			assert_eq!(reciprocal.reciprocal(), Some(relation));
+		}
+	}
+	assert_eq!(RelationType::LabelFor.reciprocal(), Some(RelationType::LabelledBy));
+	assert_eq!(RelationType::MemberOf.reciprocal(), None);
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use zvariant::Type;
 
-use crate::AtspiError;
+use crate::{AtspiError, Interface, InterfaceSet};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type, Hash)]
 /// An accessible object role.
@@ -539,3 +539,343 @@ impl std::fmt::Display for Role {
 		write!(f, "{}", self.name())
 	}
 }
+
+/// WAI-ARIA roles and the role user agents map them to, following the AT-SPI column of the
+/// [Core Accessibility API Mappings](https://www.w3.org/TR/core-aam-1.2/).
+///
+/// Several ARIA roles share one role, for example all landmarks map to [`Role::Landmark`];
+/// the ARIA role of such objects is found in their `xml-roles` attribute.
+const ARIA_ROLES: &[(&str, Role)] = &[
+	("alert", Role::Notification),
+	("alertdialog", Role::Dialog),
+	("application", Role::Embedded),
+	("article", Role::Article),
+	("banner", Role::Landmark),
+	("blockquote", Role::BlockQuote),
+	("button", Role::PushButton),
+	("caption", Role::Caption),
+	("cell", Role::TableCell),
+	("checkbox", Role::CheckBox),
+	("code", Role::Static),
+	("columnheader", Role::ColumnHeader),
+	("combobox", Role::ComboBox),
+	("comment", Role::Comment),
+	("complementary", Role::Landmark),
+	("contentinfo", Role::Landmark),
+	("definition", Role::DescriptionValue),
+	("deletion", Role::ContentDeletion),
+	("dialog", Role::Dialog),
+	("document", Role::DocumentFrame),
+	("emphasis", Role::Static),
+	("feed", Role::Panel),
+	("figure", Role::Panel),
+	("form", Role::Form),
+	("generic", Role::Section),
+	("grid", Role::Table),
+	("gridcell", Role::TableCell),
+	("group", Role::Panel),
+	("heading", Role::Heading),
+	("img", Role::Image),
+	("insertion", Role::ContentInsertion),
+	("link", Role::Link),
+	("list", Role::List),
+	("listbox", Role::ListBox),
+	("listitem", Role::ListItem),
+	("log", Role::Log),
+	("main", Role::Landmark),
+	("mark", Role::Mark),
+	("marquee", Role::Marquee),
+	("math", Role::Math),
+	("menu", Role::Menu),
+	("menubar", Role::MenuBar),
+	("menuitem", Role::MenuItem),
+	("menuitemcheckbox", Role::CheckMenuItem),
+	("menuitemradio", Role::RadioMenuItem),
+	("meter", Role::LevelBar),
+	("navigation", Role::Landmark),
+	("note", Role::Comment),
+	("option", Role::ListItem),
+	("paragraph", Role::Paragraph),
+	("progressbar", Role::ProgressBar),
+	("radio", Role::RadioButton),
+	("radiogroup", Role::Panel),
+	("region", Role::Landmark),
+	("row", Role::TableRow),
+	("rowgroup", Role::Panel),
+	("rowheader", Role::RowHeader),
+	("scrollbar", Role::ScrollBar),
+	("search", Role::Landmark),
+	("searchbox", Role::Entry),
+	("separator", Role::Separator),
+	("slider", Role::Slider),
+	("spinbutton", Role::SpinButton),
+	("status", Role::StatusBar),
+	("strong", Role::Static),
+	("subscript", Role::Subscript),
+	("suggestion", Role::Suggestion),
+	("superscript", Role::Superscript),
+	("switch", Role::ToggleButton),
+	("tab", Role::PageTab),
+	("table", Role::Table),
+	("tablist", Role::PageTabList),
+	("tabpanel", Role::ScrollPane),
+	("term", Role::DescriptionTerm),
+	("textbox", Role::Entry),
+	("time", Role::Static),
+	("timer", Role::Timer),
+	("toolbar", Role::ToolBar),
+	("tooltip", Role::ToolTip),
+	("tree", Role::Tree),
+	("treegrid", Role::TreeTable),
+	("treeitem", Role::TreeItem),
+];
+
+/// Metadata about roles, for structural navigation, auditing and speech output.
+///
+/// The human readable name of a role is [`Role::name`]; the name in the user's language is
+/// provided by the application through `GetLocalizedRoleName`.
+impl Role {
+	/// Whether objects with this role mark a region of a page that users may want to jump to,
+	/// like the ARIA landmark roles.
+	#[must_use]
+	pub fn is_landmark(self) -> bool {
+		matches!(self, Self::Landmark | Self::Form)
+	}
+
+	/// Whether objects with this role mainly exist to group their children.
+	#[must_use]
+	pub fn is_container(self) -> bool {
+		matches!(
+			self,
+			Self::Application
+				| Self::Article
+				| Self::BlockQuote
+				| Self::DescriptionList
+				| Self::Dialog
+				| Self::DocumentEmail
+				| Self::DocumentFrame
+				| Self::DocumentPresentation
+				| Self::DocumentSpreadsheet
+				| Self::DocumentText
+				| Self::DocumentWeb
+				| Self::Filler
+				| Self::Footer
+				| Self::Form | Self::Frame
+				| Self::GlassPane
+				| Self::Grouping
+				| Self::Header
+				| Self::HTMLContainer
+				| Self::InfoBar
+				| Self::InternalFrame
+				| Self::Landmark
+				| Self::LayeredPane
+				| Self::List | Self::ListBox
+				| Self::Log | Self::Menu
+				| Self::MenuBar
+				| Self::OptionPane
+				| Self::Page | Self::PageTabList
+				| Self::Panel
+				| Self::PopupMenu
+				| Self::RootPane
+				| Self::ScrollPane
+				| Self::Section
+				| Self::SplitPane
+				| Self::StatusBar
+				| Self::Table
+				| Self::TableRow
+				| Self::ToolBar
+				| Self::Tree | Self::TreeTable
+				| Self::Viewport
+				| Self::Window
+		)
+	}
+
+	/// Whether objects with this role are widgets the user operates, rather than content or
+	/// containers.
+	#[must_use]
+	pub fn is_interactive(self) -> bool {
+		matches!(
+			self,
+			Self::Autocomplete
+				| Self::Calendar
+				| Self::CheckBox
+				| Self::CheckMenuItem
+				| Self::ColorChooser
+				| Self::ComboBox
+				| Self::DateEditor
+				| Self::Dial | Self::Editbar
+				| Self::Entry
+				| Self::FileChooser
+				| Self::FontChooser
+				| Self::Link | Self::ListItem
+				| Self::MenuItem
+				| Self::PageTab
+				| Self::PasswordText
+				| Self::PushButton
+				| Self::PushButtonMenu
+				| Self::RadioButton
+				| Self::RadioMenuItem
+				| Self::Rating
+				| Self::ScrollBar
+				| Self::Slider
+				| Self::SpinButton
+				| Self::TearoffMenuItem
+				| Self::Terminal
+				| Self::ToggleButton
+				| Self::TreeItem
+		)
+	}
+
+	/// Whether objects with this role head other content: headings, and the headers of
+	/// tables and their columns and rows.
+	#[must_use]
+	pub fn is_heading_like(self) -> bool {
+		matches!(
+			self,
+			Self::Heading
+				| Self::ColumnHeader
+				| Self::RowHeader
+				| Self::TableColumnHeader
+				| Self::TableRowHeader
+				| Self::Caption
+		)
+	}
+
+	/// Whether objects with this role are usually named after the text they contain,
+	/// when their name is empty.
+	#[must_use]
+	pub fn names_from_content(self) -> bool {
+		matches!(
+			self,
+			Self::Caption
+				| Self::CheckBox
+				| Self::CheckMenuItem
+				| Self::ColumnHeader
+				| Self::Heading
+				| Self::Label
+				| Self::Link | Self::ListItem
+				| Self::MenuItem
+				| Self::PageTab
+				| Self::PushButton
+				| Self::RadioButton
+				| Self::RadioMenuItem
+				| Self::RowHeader
+				| Self::Static
+				| Self::TableCell
+				| Self::TableColumnHeader
+				| Self::TableRowHeader
+				| Self::ToggleButton
+				| Self::ToolTip
+				| Self::TreeItem
+		)
+	}
+
+	/// The interfaces an object with this role is expected to implement, besides
+	/// [`Interface::Accessible`], which is always included.
+	///
+	/// These are the interfaces without which the object cannot be used as its role promises,
+	/// for example [`Interface::Table`] for a [`Role::Table`]. Objects may implement more.
+	#[must_use]
+	pub fn expected_interfaces(self) -> InterfaceSet {
+		let mut interfaces = InterfaceSet::new(Interface::Accessible);
+		match self {
+			Self::Table | Self::TreeTable => interfaces.insert(Interface::Table),
+			Self::TableCell => interfaces.insert(Interface::TableCell),
+			Self::Entry | Self::PasswordText => {
+				interfaces.insert(Interface::Text | Interface::EditableText);
+			}
+			Self::Text | Self::Terminal | Self::Paragraph => interfaces.insert(Interface::Text),
+			Self::Slider
+			| Self::SpinButton
+			| Self::ScrollBar
+			| Self::ProgressBar
+			| Self::Dial
+			| Self::LevelBar => interfaces.insert(Interface::Value),
+			Self::PushButton
+			| Self::PushButtonMenu
+			| Self::ToggleButton
+			| Self::CheckBox
+			| Self::RadioButton
+			| Self::MenuItem
+			| Self::CheckMenuItem
+			| Self::RadioMenuItem
+			| Self::PageTab => interfaces.insert(Interface::Action),
+			Self::Link => interfaces.insert(Interface::Action | Interface::Hyperlink),
+			Self::Image | Self::Icon => interfaces.insert(Interface::Image),
+			Self::List | Self::ListBox | Self::Menu | Self::MenuBar | Self::PageTabList => {
+				interfaces.insert(Interface::Selection);
+			}
+			Self::DocumentEmail
+			| Self::DocumentFrame
+			| Self::DocumentPresentation
+			| Self::DocumentSpreadsheet
+			| Self::DocumentText
+			| Self::DocumentWeb => interfaces.insert(Interface::Document),
+			Self::Application => interfaces.insert(Interface::Application),
+			_ => {}
+		}
+		interfaces
+	}
+
+	/// The WAI-ARIA roles user agents map to this role.
+	///
+	/// Empty for roles no ARIA role maps to, for example [`Role::Frame`].
+	pub fn aria_roles(self) -> impl Iterator<Item = &'static str> {
+		ARIA_ROLES
+			.iter()
+			.filter(move |(_, role)| *role == self)
+			.map(|(aria, _)| *aria)
+	}
+
+	/// The role user agents map the WAI-ARIA role `aria` to.
+	///
+	/// Matching ignores ASCII case and surrounding whitespace. Returns `None` for unknown roles
+	/// and for `none` and `presentation`, which remove an element from the accessibility tree.
+	#[must_use]
+	pub fn from_aria(aria: &str) -> Option<Self> {
+		let aria = aria.trim();
+		let role = match aria.to_ascii_lowercase().as_str() {
+			"image" => Self::Image,
+			"directory" => Self::List,
+			_ => ARIA_ROLES
+				.iter()
+				.find(|(name, _)| name.eq_ignore_ascii_case(aria))
+				.map(|(_, role)| *role)?,
+		};
+		Some(role)
+	}
+}
+
+#[test]
+fn test_role_metadata() {
+	assert!(Role::Landmark.is_landmark());
+	assert!(!Role::Panel.is_landmark());
+	assert!(Role::Panel.is_container());
+	assert!(!Role::PushButton.is_container());
+	assert!(Role::PushButton.is_interactive());
+	assert!(!Role::Label.is_interactive());
+	assert!(Role::Heading.is_heading_like());
+	assert!(Role::Heading.names_from_content());
+	assert!(!Role::Entry.names_from_content());
+
+	assert!(Role::Table.expected_interfaces().contains(Interface::Table));
+	assert!(Role::Entry.expected_interfaces().contains(Interface::EditableText));
+	assert_eq!(Role::Panel.expected_interfaces(), InterfaceSet::new(Interface::Accessible));
+}
+
+#[test]
+fn test_aria_mapping() {
+	assert_eq!(Role::from_aria("button"), Some(Role::PushButton));
+	assert_eq!(Role::from_aria(" TreeGrid "), Some(Role::TreeTable));
+	assert_eq!(Role::from_aria("image"), Some(Role::Image));
+	assert_eq!(Role::from_aria("presentation"), None);
+	assert_eq!(Role::from_aria("spaceship"), None);
+
+	assert_eq!(Role::PushButton.aria_roles().collect::<Vec<_>>(), ["button"]);
+	assert!(Role::Landmark.aria_roles().any(|aria| aria == "navigation"));
+	assert_eq!(Role::Frame.aria_roles().count(), 0);
+	for (aria, role) in ARIA_ROLES {
+		assert_eq!(Role::from_aria(aria), Some(*role));
+		assert!(role.aria_roles().any(|name| name == *aria));
+	}
+}
@@ -0,0 +1,296 @@
+//! Enumerate and watch the accessible applications on the bus.
+//!
+//! Every application registers its root object as a child of the desktop root,
+//! which lives at [`ROOT_PATH`] on the registry daemon.
+
+use crate::{AccessibilityConnection, AtspiResult};
+use atspi_common::{
+	accessible::ROOT_PATH,
+	events::{
+		object::{ChildrenChangedEvent, ObjectEvents},
+		Event,
+	},
+	Accessible,
+};
+use atspi_proxies::{
+	accessible::AccessibleProxy, application::ApplicationProxy, proxy_for, timeout::CallTimeoutExt,
+};
+use futures_lite::stream::{Stream, StreamExt};
+use std::collections::HashSet;
+use zbus::names::BusName;
+
+/// The well-known bus name of the registry daemon, which owns the desktop root.
+pub const REGISTRY_BUS_NAME: &str = "org.a11y.atspi.Registry";
+
+/// The desktop root, whose children are the root objects of all applications.
+#[must_use]
+pub fn desktop() -> Accessible {
+	root_of(REGISTRY_BUS_NAME)
+}
+
+/// The root object of the application owning `bus_name`.
+#[must_use]
+pub fn root_of(bus_name: &str) -> Accessible {
+	Accessible {
+		name: bus_name.to_string(),
+		path: ROOT_PATH.try_into().expect("ROOT_PATH is a valid object path"),
+	}
+}
+
+/// Information on a running accessible application.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ApplicationInfo {
+	/// The root object of the application.
+	pub accessible: Accessible,
+	/// The accessible name of the application.
+	pub name: String,
+	/// The name of the toolkit the application uses, for example "GTK" or "Qt".
+	pub toolkit_name: String,
+	/// The version of the toolkit.
+	pub toolkit_version: String,
+	/// The version of the AT-SPI specification the application implements.
+	pub atspi_version: String,
+	/// The identifier the registry assigned to the application.
+	pub id: i32,
+}
+
+impl ApplicationInfo {
+	/// The unique bus name of the application.
+	#[must_use]
+	pub fn bus_name(&self) -> &str {
+		&self.accessible.name
+	}
+}
+
+/// An application appearing on, or leaving, the accessibility bus.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplicationEvent {
+	/// The application registered itself with the registry.
+	Added(Accessible),
+	/// The application deregistered, or its connection to the bus went away.
+	Removed(Accessible),
+}
+
+impl AccessibilityConnection {
+	/// Lists all accessible applications registered on the bus.
+	///
+	/// Applications which fail to respond, or do not respond within the timeout of
+	/// [`Self::call_guard`], are left out.
+	///
+	/// # Errors
+	///
+	/// When the children of the desktop root can not be retrieved.
+	pub async fn applications(&self) -> AtspiResult<Vec<ApplicationInfo>> {
+		let desktop = desktop();
+		let proxy = proxy_for::<AccessibleProxy>(self.connection(), &desktop).await?;
+		let mut applications = Vec::new();
+		for root in proxy.get_children().guarded(self.call_guard(), &desktop.name).await? {
+			let bus_name = root.name.clone();
+			if let Ok(info) = self
+				.application_info(root)
+				.guarded(self.call_guard(), &bus_name)
+				.await
+			{
+				applications.push(info);
+			}
+		}
+		Ok(applications)
+	}
+
+	/// The unique bus name of the registry daemon, which sends the events of the desktop root.
+	///
+	/// # Errors
+	///
+	/// When the registry daemon does not own [`REGISTRY_BUS_NAME`].
+	pub async fn registry_owner(&self) -> AtspiResult<String> {
+		let name = BusName::from_static_str(REGISTRY_BUS_NAME)?;
+		Ok(self.dbus_proxy.get_name_owner(name).await?.to_string())
+	}
+
+	/// Retrieves the [`ApplicationInfo`] of the application with root object `root`.
+	///
+	/// # Errors
+	///
+	/// When any of the properties of the `Application` interface can not be read.
+	pub async fn application_info(&self, root: Accessible) -> AtspiResult<ApplicationInfo> {
+		let conn = self.connection();
+		let application = proxy_for::<ApplicationProxy>(conn, &root).await?;
+		let accessible = proxy_for::<AccessibleProxy>(conn, &root).await?;
+		Ok(ApplicationInfo {
+			name: accessible.name().await?,
+			toolkit_name: application.toolkit_name().await?,
+			toolkit_version: application.version().await?,
+			atspi_version: application.atspi_version().await?,
+			id: application.id().await?,
+			accessible: root,
+		})
+	}
+
+	/// Stream of applications being added to and removed from the bus.
+	///
+	/// Additions and removals are taken from `ChildrenChanged` events on the desktop root.
+	/// Removals are also taken from `NameOwnerChanged` signals, so applications that exit
+	/// without deregistering are noticed as well. Only applications which are children of the
+	/// desktop root, when the stream is created or by a later addition, are reported removed;
+	/// and a removal reported by both is reported once.
+	///
+	/// # Errors
+	///
+	/// When registering for `ChildrenChanged` events, subscribing to `NameOwnerChanged`,
+	/// looking up the [`Self::registry_owner`] or the children of the desktop root fails.
+	pub async fn application_events(&self) -> AtspiResult<impl Stream<Item = ApplicationEvent>> {
+		let registry = self.registry_owner().await?;
+		self.register_event::<ChildrenChangedEvent>().await?;
+		let owners = self
+			.dbus_proxy
+			.receive_name_owner_changed()
+			.await?
+			.filter_map(|signal| {
+				let args = signal.args().ok()?;
+				vanished(args.name().as_str(), args.new_owner().is_some())
+			});
+		let children = self
+			.event_stream()
+			.filter_map(move |event| application_event(&event.ok()?, &registry));
+
+		// Seeded after subscribing, so no application can be added in between unnoticed.
+		let desktop = desktop();
+		let proxy = proxy_for::<AccessibleProxy>(self.connection(), &desktop).await?;
+		let mut known: HashSet<String> = proxy
+			.get_children()
+			.guarded(self.call_guard(), &desktop.name)
+			.await?
+			.into_iter()
+			.map(|root| root.name)
+			.collect();
+		Ok(children
+			.or(owners)
+			.filter_map(move |event| known_only(&mut known, event)))
+	}
+}
+
+/// Whether `item` is the desktop root, given the unique bus name of the registry daemon.
+///
+/// Every application has an object at [`ROOT_PATH`]; only the one of the registry is the desktop.
+pub(crate) fn is_desktop(item: &Accessible, registry: &str) -> bool {
+	item.path.as_str() == ROOT_PATH && item.name == registry
+}
+
+/// The application event described by a `ChildrenChanged` event on the desktop root,
+/// given the unique bus name of the registry daemon.
+fn application_event(event: &Event, registry: &str) -> Option<ApplicationEvent> {
+	let Event::Object(ObjectEvents::ChildrenChanged(ev)) = event else {
+		return None;
+	};
+	if !is_desktop(&ev.item, registry) || ev.child.is_null() {
+		return None;
+	}
+	if ev.operation.starts_with("add") {
+		Some(ApplicationEvent::Added(ev.child.clone()))
+	} else if ev.operation.starts_with("remove") {
+		Some(ApplicationEvent::Removed(ev.child.clone()))
+	} else {
+		None
+	}
+}
+
+/// The removal of an application, if a unique name lost its owner.
+fn vanished(name: &str, has_new_owner: bool) -> Option<ApplicationEvent> {
+	(name.starts_with(':') && !has_new_owner).then(|| ApplicationEvent::Removed(root_of(name)))
+}
+
+/// Keeps track of the bus names of the applications in `known`, and passes on `event` unless it
+/// removes an application which is not known: one which was never added, or already removed.
+fn known_only(known: &mut HashSet<String>, event: ApplicationEvent) -> Option<ApplicationEvent> {
+	match &event {
+		ApplicationEvent::Added(app) => {
+			known.insert(app.name.clone());
+			Some(event)
+		}
+		ApplicationEvent::Removed(app) => known.remove(&app.name).then_some(event),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{application_event, known_only, root_of, vanished, ApplicationEvent};
+	use atspi_common::{
+		events::{object::ChildrenChangedEvent, Event},
+		Accessible,
+	};
+	use std::collections::HashSet;
+
+	/// The unique name of the registry daemon in these tests.
+	const REGISTRY: &str = ":1.0";
+
+	fn children_changed(operation: &str, child: Accessible) -> Event {
+		ChildrenChangedEvent {
+			item: root_of(REGISTRY),
+			operation: operation.into(),
+			index_in_parent: 0,
+			child,
+		}
+		.into()
+	}
+
+	#[test]
+	fn desktop_children_are_applications() {
+		let app = root_of(":1.5");
+		assert_eq!(
+			application_event(&children_changed("add", app.clone()), REGISTRY),
+			Some(ApplicationEvent::Added(app.clone()))
+		);
+		assert_eq!(
+			application_event(&children_changed("remove/system", app.clone()), REGISTRY),
+			Some(ApplicationEvent::Removed(app))
+		);
+	}
+
+	#[test]
+	fn other_children_are_ignored() {
+		let mut event = ChildrenChangedEvent {
+			item: root_of(":1.5"),
+			operation: "add".into(),
+			index_in_parent: 0,
+			child: root_of(":1.5"),
+		};
+		event.item.path = "/org/a11y/atspi/accessible/12".try_into().unwrap();
+		assert_eq!(application_event(&event.into(), REGISTRY), None);
+	}
+
+	#[test]
+	fn roots_of_applications_are_not_the_desktop() {
+		let event = ChildrenChangedEvent {
+			item: root_of(":1.5"),
+			operation: "add".into(),
+			index_in_parent: 0,
+			child: Accessible {
+				name: ":1.5".into(),
+				path: "/org/a11y/atspi/accessible/3".try_into().unwrap(),
+			},
+		};
+		assert_eq!(application_event(&event.into(), REGISTRY), None);
+	}
+
+	#[test]
+	fn lost_unique_names_are_removals() {
+		assert_eq!(vanished(":1.5", false), Some(ApplicationEvent::Removed(root_of(":1.5"))));
+		assert_eq!(vanished(":1.5", true), None);
+		assert_eq!(vanished("org.a11y.atspi.Registry", false), None);
+	}
+
+	#[test]
+	fn only_known_applications_are_removed() {
+		let mut known = HashSet::from([":1.5".to_string()]);
+		let removed = |name: &str| ApplicationEvent::Removed(root_of(name));
+		// Some other client, an AT for example, leaving the bus.
+		assert_eq!(known_only(&mut known, removed(":1.7")), None);
+		assert_eq!(known_only(&mut known, removed(":1.5")), Some(removed(":1.5")));
+		// Reported by both the registry and the bus.
+		assert_eq!(known_only(&mut known, removed(":1.5")), None);
+
+		let added = ApplicationEvent::Added(root_of(":1.7"));
+		assert_eq!(known_only(&mut known, added.clone()), Some(added));
+		assert_eq!(known_only(&mut known, removed(":1.7")), Some(removed(":1.7")));
+	}
+}
@@ -0,0 +1,534 @@
+//! Finds common accessibility defects in a live application.
+//!
+//! [`audit`] walks a subtree and checks every object against a set of [`Rule`]s.
+//! The [`AuditReport`] it returns can be serialized, and [`AuditConfig`] deserialized,
+//! so a CI job can keep its configuration in a file and archive the report:
+//!
+//! ```rust,no_run
+//! use atspi_connection::{
+//!     application::root_of,
+//!     audit::{audit, AuditConfig, Rule},
+//!     AccessibilityConnection,
+//! };
+//!
+//! # tokio_test::block_on(async {
+//! let connection = AccessibilityConnection::open().await.unwrap();
+//! let config = AuditConfig::default().without(Rule::WrongIndexInParent);
+//! let report =
+//!     audit(connection.connection(), connection.call_guard(), &root_of(":1.42"), &config).await;
+//! println!("{}", serde_json::to_string_pretty(&report).unwrap());
+//! assert!(report.passed());
+//! # })
+//! ```
+
+use crate::{relations::relation_set, snapshot::Snapshot, tree::walk, AtspiResult};
+use atspi_common::{Accessible, Interface, InterfaceSet, RelationSet, Role, State};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	component::ComponentProxy,
+	proxy_for,
+	table::TableProxy,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+use futures_util::{future::join_all, join};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// At most this many column and row headers are looked up per table.
+const HEADER_PROBE_LIMIT: i32 = 32;
+
+/// A defect [`audit`] can look for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Rule {
+	/// A focusable object without a name.
+	UnnamedFocusable,
+	/// An image with an empty `ImageDescription`.
+	ImageWithoutDescription,
+	/// A table with neither column nor row headers.
+	TableWithoutHeaders,
+	/// A focusable object that cannot receive focus: it does not implement `Component`,
+	/// or, with [`AuditConfig::grab_focus`], `GrabFocus` fails.
+	UnfocusableFocusable,
+	/// A relation whose target does not point back with the reciprocal relation,
+	/// such as `LabelFor` without a matching `LabelledBy`.
+	/// See [`RelationType::reciprocal`](atspi_common::RelationType::reciprocal).
+	UnreciprocatedRelation,
+	/// A child whose `GetIndexInParent` disagrees with its position in its parent's children.
+	WrongIndexInParent,
+}
+
+impl Rule {
+	/// Every rule.
+	pub const ALL: [Rule; 6] = [
+		Rule::UnnamedFocusable,
+		Rule::ImageWithoutDescription,
+		Rule::TableWithoutHeaders,
+		Rule::UnfocusableFocusable,
+		Rule::UnreciprocatedRelation,
+		Rule::WrongIndexInParent,
+	];
+
+	/// The severity of the rule's findings in [`AuditConfig::default`].
+	#[must_use]
+	pub fn default_severity(self) -> Severity {
+		match self {
+			Rule::UnnamedFocusable | Rule::UnfocusableFocusable | Rule::WrongIndexInParent => {
+				Severity::Error
+			}
+			Rule::ImageWithoutDescription
+			| Rule::TableWithoutHeaders
+			| Rule::UnreciprocatedRelation => Severity::Warning,
+		}
+	}
+}
+
+/// How bad a finding is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+	/// Worth fixing, but does not fail [`AuditReport::passed`].
+	Warning,
+	/// Fails [`AuditReport::passed`].
+	Error,
+}
+
+/// Which rules to check, and how.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct AuditConfig {
+	/// The rules to check, with the severity of their findings. Rules not listed are not checked.
+	pub rules: BTreeMap<Rule, Severity>,
+	/// Whether to call `GrabFocus` on every focusable, showing and enabled object for
+	/// [`Rule::UnfocusableFocusable`]. This moves focus around the application, so it is off by
+	/// default, and only objects lacking the `Component` interface are reported. The objects get
+	/// focus one at a time, after all other checks; then the object which had focus, if it is
+	/// among those checked, gets it back.
+	pub grab_focus: bool,
+	/// How many levels below the root to check; all of them if `None`.
+	pub max_depth: Option<usize>,
+}
+
+impl Default for AuditConfig {
+	fn default() -> Self {
+		Self {
+			rules: Rule::ALL
+				.iter()
+				.map(|&rule| (rule, rule.default_severity()))
+				.collect(),
+			grab_focus: false,
+			max_depth: None,
+		}
+	}
+}
+
+impl AuditConfig {
+	/// Stops checking `rule`.
+	#[must_use]
+	pub fn without(mut self, rule: Rule) -> Self {
+		self.rules.remove(&rule);
+		self
+	}
+
+	/// Checks `rule`, reporting its findings with `severity`.
+	#[must_use]
+	pub fn with(mut self, rule: Rule, severity: Severity) -> Self {
+		self.rules.insert(rule, severity);
+		self
+	}
+
+	fn checks(&self, rule: Rule) -> bool {
+		self.rules.contains_key(&rule)
+	}
+}
+
+/// One defect found by [`audit`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+	pub rule: Rule,
+	pub severity: Severity,
+	/// The offending object.
+	pub accessible: Accessible,
+	/// The position of the object below the audited root, as child indices.
+	pub path: Vec<usize>,
+	pub role: Role,
+	pub name: String,
+	/// What is wrong, for people.
+	pub message: String,
+}
+
+/// The outcome of [`audit`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditReport {
+	/// How many objects were checked.
+	pub checked: usize,
+	/// The defects found, in document order.
+	pub findings: Vec<Finding>,
+}
+
+impl AuditReport {
+	/// Whether there are no findings of [`Severity::Error`].
+	#[must_use]
+	pub fn passed(&self) -> bool {
+		self.count(Severity::Error) == 0
+	}
+
+	/// The number of findings of `severity`.
+	#[must_use]
+	pub fn count(&self, severity: Severity) -> usize {
+		self.findings
+			.iter()
+			.filter(|finding| finding.severity == severity)
+			.count()
+	}
+}
+
+/// What is read of every object.
+struct Facts {
+	snapshot: Snapshot,
+	relations: RelationSet,
+}
+
+async fn read(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+	descend: bool,
+) -> (Facts, Vec<Accessible>) {
+	let extra = async {
+		let proxy = proxy_for::<AccessibleProxy>(conn, accessible).await?;
+		let children = async {
+			if descend {
+				proxy.get_children().guarded(guard, &accessible.name).await
+			} else {
+				Ok(Vec::new())
+			}
+		};
+		let relations = proxy.get_relation_set().guarded(guard, &accessible.name);
+		let (children, relations) = join!(children, relations);
+		let relations = relations.map(RelationSet::from).unwrap_or_default();
+		Ok::<_, zbus::Error>((children.unwrap_or_default(), relations))
+	};
+	let (snapshot, extra) = join!(Snapshot::fetch(conn, guard, accessible), extra);
+	let (children, relations) = extra.unwrap_or_default();
+	(Facts { snapshot, relations }, children)
+}
+
+/// Checks `root` and its descendants; see the [module documentation](self).
+///
+/// Objects that cannot be read are skipped rather than reported. All calls are subject to
+/// `guard`.
+pub async fn audit(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	root: &Accessible,
+	config: &AuditConfig,
+) -> AuditReport {
+	let max_depth = config.max_depth.unwrap_or(usize::MAX);
+	let visited = walk(root, max_depth, |accessible, descend| async move {
+		read(conn, guard, &accessible, descend).await
+	})
+	.await;
+
+	let mut paths: Vec<Vec<usize>> = Vec::with_capacity(visited.len());
+	for visit in &visited {
+		let mut path = visit
+			.parent
+			.map_or_else(Vec::new, |(parent, _)| paths[parent].clone());
+		path.extend(visit.parent.map(|(_, position)| position));
+		paths.push(path);
+	}
+	let relations: HashMap<&Accessible, &RelationSet> = visited
+		.iter()
+		.map(|visit| (&visit.accessible, &visit.value.relations))
+		.collect();
+
+	let checks = visited.iter().map(|visit| async {
+		let mut found: Vec<(Rule, String)> = Vec::new();
+		let snapshot = &visit.value.snapshot;
+		let states = snapshot.states.clone().unwrap_or_default();
+		let interfaces = snapshot
+			.interfaces
+			.as_ref()
+			.map_or_else(|_| InterfaceSet::empty(), |i| *i);
+		let focusable = states.contains(State::Focusable);
+
+		if config.checks(Rule::UnnamedFocusable)
+			&& focusable
+			&& matches!(&snapshot.name, Ok(name) if name.trim().is_empty())
+		{
+			found.push((Rule::UnnamedFocusable, "focusable object has no name".to_string()));
+		}
+		if config.checks(Rule::ImageWithoutDescription) {
+			if let Some(Ok(image)) = &snapshot.image {
+				if matches!(&image.description, Ok(description) if description.trim().is_empty()) {
+					found.push((
+						Rule::ImageWithoutDescription,
+						"image has no description".to_string(),
+					));
+				}
+			}
+		}
+		if config.checks(Rule::TableWithoutHeaders) && interfaces.contains(Interface::Table) {
+			if let Some(message) = missing_headers(conn, guard, &visit.accessible).await {
+				found.push((Rule::TableWithoutHeaders, message));
+			}
+		}
+		if config.checks(Rule::UnfocusableFocusable)
+			&& focusable
+			&& !interfaces.contains(Interface::Component)
+		{
+			let message = "focusable object does not implement Component".to_string();
+			found.push((Rule::UnfocusableFocusable, message));
+		}
+		if config.checks(Rule::UnreciprocatedRelation) {
+			for (relation, targets) in visit.value.relations.iter() {
+				let Some(expected) = relation.reciprocal() else { continue };
+				for target in targets {
+					let points_back = match relations.get(target) {
+						Some(back) => back.reciprocates(relation, &visit.accessible),
+						None => relation_set(conn, guard, target)
+							.await
+							.unwrap_or_default()
+							.reciprocates(relation, &visit.accessible),
+					};
+					if !points_back {
+						let message = format!(
+							"{relation:?} {} {} has no {expected:?} back",
+							target.name,
+							target.path.as_str()
+						);
+						found.push((Rule::UnreciprocatedRelation, message));
+					}
+				}
+			}
+		}
+		if config.checks(Rule::WrongIndexInParent) {
+			if let (Some((_, position)), Ok(index)) = (visit.parent, &snapshot.index_in_parent) {
+				if usize::try_from(*index).ok() != Some(position) {
+					let message = format!(
+						"index in parent is {index}, but it is child {position} of its parent"
+					);
+					found.push((Rule::WrongIndexInParent, message));
+				}
+			}
+		}
+		found
+	});
+	let mut found = join_all(checks).await;
+
+	// Focus probes run one at a time, so focus moves through the application in document order
+	// and no probe races with another, and focus goes back where it was afterwards.
+	if config.grab_focus && config.checks(Rule::UnfocusableFocusable) {
+		let mut probes: Vec<usize> = (0..visited.len())
+			.filter(|&index| {
+				let snapshot = &visited[index].value.snapshot;
+				let probed = State::Focusable | State::Showing | State::Enabled;
+				matches!(&snapshot.states, Ok(states) if states.contains(probed))
+					&& matches!(&snapshot.interfaces, Ok(i) if i.contains(Interface::Component))
+			})
+			.collect();
+		probes.sort_by(|&a, &b| paths[a].cmp(&paths[b]));
+		for &index in &probes {
+			if !grab_focus(conn, guard, &visited[index].accessible).await {
+				let message = "focusable object refused GrabFocus".to_string();
+				found[index].push((Rule::UnfocusableFocusable, message));
+				found[index].sort_by_key(|(rule, _)| *rule);
+			}
+		}
+		let focused = visited.iter().find(
+			|visit| matches!(&visit.value.snapshot.states, Ok(states) if states.contains(State::Focused)),
+		);
+		if let (Some(focused), false) = (focused, probes.is_empty()) {
+			grab_focus(conn, guard, &focused.accessible).await;
+		}
+	}
+
+	let mut findings = Vec::new();
+	for ((visit, path), found) in visited.iter().zip(&paths).zip(found) {
+		let snapshot = &visit.value.snapshot;
+		for (rule, message) in found {
+			findings.push(Finding {
+				rule,
+				severity: config.rules[&rule],
+				accessible: visit.accessible.clone(),
+				path: path.clone(),
+				role: snapshot.role.clone().unwrap_or(Role::Invalid),
+				name: snapshot.name.clone().unwrap_or_default(),
+				message,
+			});
+		}
+	}
+	// Breadth first order to document order; the sort is stable, so rules stay in order.
+	findings.sort_by(|a, b| a.path.cmp(&b.path));
+	AuditReport { checked: visited.len(), findings }
+}
+
+/// Describes what is missing if a table has neither column nor row headers.
+async fn missing_headers(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> Option<String> {
+	let table = proxy_for::<TableProxy>(conn, accessible).await.ok()?;
+	let bus_name = accessible.name.as_str();
+	let (rows, columns) =
+		join!(table.nrows().guarded(guard, bus_name), table.ncolumns().guarded(guard, bus_name));
+	let (rows, columns) = (rows.ok()?, columns.ok()?);
+	if rows == 0 || columns == 0 {
+		return None;
+	}
+	let column_headers = (0..columns.min(HEADER_PROBE_LIMIT))
+		.map(|c| table.get_column_header(c).guarded(guard, bus_name));
+	let row_headers =
+		(0..rows.min(HEADER_PROBE_LIMIT)).map(|r| table.get_row_header(r).guarded(guard, bus_name));
+	let (column_headers, row_headers) = join!(join_all(column_headers), join_all(row_headers));
+	let has_header = |header: AtspiResult<Accessible>| matches!(header, Ok(h) if !h.is_null());
+	if column_headers.into_iter().chain(row_headers).any(has_header) {
+		return None;
+	}
+	Some(format!("table of {rows} rows and {columns} columns has no headers"))
+}
+
+async fn grab_focus(conn: &zbus::Connection, guard: &CallGuard, accessible: &Accessible) -> bool {
+	match proxy_for::<ComponentProxy>(conn, accessible).await {
+		Ok(component) => component
+			.grab_focus()
+			.guarded(guard, &accessible.name)
+			.await
+			.unwrap_or(false),
+		Err(_) => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+	use atspi_common::RelationType;
+
+	/// A dialog with one defect for each rule, except grabbing focus.
+	fn tree() -> Tree {
+		let mut tree = Tree::default();
+		tree.add(Node::new(Role::Dialog, "Settings").children(&[1, 2, 3, 4, 5, 6]));
+		tree.add(
+			Node::new(Role::PushButton, "")
+				.states(State::Focusable | State::Enabled)
+				.extents((0, 0, 10, 10)),
+		);
+		tree.add(Node::new(Role::Image, "Logo").image(""));
+		tree.add(Node::new(Role::Table, "Results").table(3, 2));
+		tree.add(Node::new(Role::CheckBox, "Remember").states(State::Focusable));
+		tree.add(Node::new(Role::Label, "Name").relation(RelationType::LabelFor, &[6]));
+		tree.add(
+			Node::new(Role::Entry, "Name")
+				.index_in_parent(2)
+				.extents((0, 0, 1, 1)),
+		);
+		tree
+	}
+
+	#[test]
+	fn reports_every_defect_in_document_order() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let report =
+				audit(&conn, &CallGuard::default(), &mock::accessible(0), &AuditConfig::default())
+					.await;
+
+			assert_eq!(report.checked, 7);
+			let found: Vec<(Rule, Vec<usize>)> =
+				report.findings.iter().map(|f| (f.rule, f.path.clone())).collect();
+			assert_eq!(
+				found,
+				[
+					(Rule::UnnamedFocusable, vec![0]),
+					(Rule::ImageWithoutDescription, vec![1]),
+					(Rule::TableWithoutHeaders, vec![2]),
+					(Rule::UnfocusableFocusable, vec![3]),
+					(Rule::UnreciprocatedRelation, vec![4]),
+					(Rule::WrongIndexInParent, vec![5]),
+				]
+			);
+			assert_eq!(report.findings[5].accessible, mock::accessible(6));
+			assert!(!report.passed());
+			assert_eq!(report.count(Severity::Warning), 3);
+		});
+	}
+
+	#[test]
+	fn disabled_rules_are_not_checked() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let config = AuditConfig::default()
+				.without(Rule::UnnamedFocusable)
+				.without(Rule::UnfocusableFocusable)
+				.with(Rule::WrongIndexInParent, Severity::Warning);
+			let report = audit(&conn, &CallGuard::default(), &mock::accessible(0), &config).await;
+
+			assert_eq!(report.findings.len(), 4);
+			assert!(report.passed());
+		});
+	}
+
+	#[test]
+	fn reciprocated_relations_pass() {
+		tokio_test::block_on(async {
+			let mut tree = Tree::default();
+			tree.add(Node::new(Role::Panel, "").children(&[1, 2]));
+			tree.add(Node::new(Role::Label, "Name").relation(RelationType::LabelFor, &[2]));
+			tree.add(Node::new(Role::Entry, "Name").relation(RelationType::LabelledBy, &[1]));
+			let conn = tree.serve().await;
+
+			let report =
+				audit(&conn, &CallGuard::default(), &mock::accessible(0), &AuditConfig::default())
+					.await;
+			assert_eq!(report.findings, []);
+		});
+	}
+
+	#[test]
+	fn grabs_focus_one_object_at_a_time_and_restores_it() {
+		tokio_test::block_on(async {
+			let focusable = State::Focusable | State::Showing | State::Enabled;
+			let mut tree = Tree::default();
+			tree.add(Node::new(Role::Panel, "").children(&[1, 3, 4]));
+			tree.add(Node::new(Role::Panel, "").children(&[2]));
+			tree.add(
+				Node::new(Role::PushButton, "Deep")
+					.states(focusable | State::Focused)
+					.extents((0, 0, 1, 1)),
+			);
+			tree.add(
+				Node::new(Role::PushButton, "Broken")
+					.states(focusable)
+					.extents((0, 0, 1, 1))
+					.failing("GrabFocus"),
+			);
+			tree.add(
+				Node::new(Role::PushButton, "Shallow")
+					.states(focusable)
+					.extents((0, 0, 1, 1)),
+			);
+			let grabs = tree.grabs();
+			let conn = tree.serve().await;
+			let config = AuditConfig { grab_focus: true, ..AuditConfig::default() };
+
+			let report = audit(&conn, &CallGuard::default(), &mock::accessible(0), &config).await;
+			let found: Vec<(Rule, Vec<usize>)> =
+				report.findings.iter().map(|f| (f.rule, f.path.clone())).collect();
+			assert_eq!(found, [(Rule::UnfocusableFocusable, vec![1])]);
+			// In document order, then back to the button which had focus.
+			assert_eq!(*grabs.lock().unwrap(), [2, 4, 2]);
+		});
+	}
+
+	#[test]
+	fn configuration_reads_from_json() {
+		let json = r#"{"rules": {"unnamed-focusable": "warning"}, "grab-focus": true}"#;
+		let config: AuditConfig = serde_json::from_str(json).unwrap();
+		assert_eq!(config.rules.len(), 1);
+		assert_eq!(config.rules[&Rule::UnnamedFocusable], Severity::Warning);
+		assert!(config.grab_focus);
+	}
+}
@@ -0,0 +1,209 @@
+//! A blocking counterpart to [`AccessibilityConnection`](crate::AccessibilityConnection).
+//!
+//! Every call blocks the current thread until it completes, so no executor is needed.
+//! This suits small scripts, and consumers calling in from other languages.
+
+use crate::{AccessibilityConnection, AtspiResult};
+use atspi_common::error::AtspiError;
+use atspi_common::events::{Event, GenericEvent, HasMatchRule, HasRegistryEventString};
+use atspi_proxies::registry::RegistryProxyBlocking;
+use futures_lite::StreamExt;
+use std::ops::Deref;
+use zbus::{blocking::Connection, Address};
+
+/// Runs `future` to completion on the runtime zbus drives its blocking API with.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+	zbus::block_on(future)
+}
+
+/// A blocking connection to the at-spi bus
+///
+/// Every call is made through an [`AccessibilityConnection`], which [`Self::inner`] gives access to.
+pub struct AccessibilityConnectionBlocking {
+	inner: AccessibilityConnection,
+	registry: RegistryProxyBlocking<'static>,
+}
+
+impl AccessibilityConnectionBlocking {
+	/// Open a new connection to the bus
+	///
+	/// # Errors
+	///
+	/// When the session bus can not be reached, does not know the address of the
+	/// accessibility bus, or the accessibility bus can not be connected to.
+	pub fn open() -> zbus::Result<Self> {
+		block_on(AccessibilityConnection::open()).map(Self::from)
+	}
+
+	/// Returns an [`AccessibilityConnectionBlocking`], a wrapper for the [`RegistryProxyBlocking`];
+	/// a handle for the registry provider on the accessibility bus.
+	///
+	/// Without address, you will want to call `open`, which tries to obtain the accessibility bus' address
+	/// on your behalf.
+	///
+	/// # Errors
+	///
+	/// `RegistryProxyBlocking` is configured with invalid path, interface or destination
+	pub fn connect(bus_addr: Address) -> zbus::Result<Self> {
+		block_on(AccessibilityConnection::connect(bus_addr)).map(Self::from)
+	}
+
+	/// The asynchronous connection the calls are made through.
+	#[must_use]
+	pub fn inner(&self) -> &AccessibilityConnection {
+		&self.inner
+	}
+
+	/// Iterator yielding all `Event` types.
+	///
+	/// Each call to `next` blocks until a signal arrives on the a11y bus.
+	///
+	/// # Example
+	///
+	/// ```rust,no_run
+	/// use atspi_connection::AccessibilityConnectionBlocking;
+	/// use atspi_common::events::object::{ObjectEvents, StateChangedEvent};
+	///
+	/// let atspi = AccessibilityConnectionBlocking::open().unwrap();
+	/// atspi.register_event::<ObjectEvents>().unwrap();
+	///
+	/// for event in atspi.event_iter() {
+	///     if let Ok(event) = StateChangedEvent::try_from(event.unwrap()) {
+	///         println!("{} changed state", event.item.path.as_str());
+	///     }
+	/// }
+	/// ```
+	pub fn event_iter(&self) -> impl Iterator<Item = AtspiResult<Event>> {
+		let mut events = Box::pin(self.inner.event_stream());
+		std::iter::from_fn(move || block_on(events.next()))
+	}
+
+	/// Adds the match rule of an event as defined in [`atspi_common::events`].
+	///
+	/// # Errors
+	///
+	/// See [`AccessibilityConnection::add_match_rule`].
+	pub fn add_match_rule<T: HasMatchRule>(&self) -> Result<(), AtspiError> {
+		block_on(self.inner.add_match_rule::<T>())
+	}
+
+	/// Removes the match rule of an event as defined in [`atspi_common::events`].
+	///
+	/// # Errors
+	///
+	/// See [`AccessibilityConnection::remove_match_rule`].
+	pub fn remove_match_rule<T: HasMatchRule>(&self) -> Result<(), AtspiError> {
+		block_on(self.inner.remove_match_rule::<T>())
+	}
+
+	/// Add a registry event.
+	/// This tells accessible applications which events should be forwarded to the accessibility bus.
+	/// This is called by [`Self::register_event`].
+	///
+	/// # Errors
+	///
+	/// May cause an error if the `DBus` method [`RegistryProxyBlocking::register_event`] fails.
+	pub fn add_registry_event<T: HasRegistryEventString>(&self) -> Result<(), AtspiError> {
+		block_on(self.inner.add_registry_event::<T>())
+	}
+
+	/// Remove a registry event.
+	/// This is called by [`Self::deregister_event`].
+	///
+	/// # Errors
+	///
+	/// May cause an error if the `DBus` method [`RegistryProxyBlocking::deregister_event`] fails.
+	pub fn remove_registry_event<T: HasRegistryEventString>(&self) -> Result<(), AtspiError> {
+		block_on(self.inner.remove_registry_event::<T>())
+	}
+
+	/// This calls [`Self::add_registry_event`] and [`Self::add_match_rule`], two components necessary to receive accessibility events.
+	/// # Errors
+	/// This will only fail if [`Self::add_registry_event`] or [`Self::add_match_rule`] fails.
+	pub fn register_event<T: HasRegistryEventString + HasMatchRule>(
+		&self,
+	) -> Result<(), AtspiError> {
+		block_on(self.inner.register_event::<T>())
+	}
+
+	/// This calls [`Self::remove_registry_event`] and [`Self::remove_match_rule`], two components necessary to receive accessibility events.
+	/// # Errors
+	/// This will only fail if [`Self::remove_registry_event`] or [`Self::remove_match_rule`] fails.
+	pub fn deregister_event<T: HasRegistryEventString + HasMatchRule>(
+		&self,
+	) -> Result<(), AtspiError> {
+		block_on(self.inner.deregister_event::<T>())
+	}
+
+	/// Shorthand for a reference to the underlying [`zbus::blocking::Connection`]
+	#[must_use = "The reference to the underlying zbus::blocking::Connection must be used"]
+	pub fn connection(&self) -> &Connection {
+		self.registry.connection()
+	}
+
+	/// Send an event over the accessibility bus.
+	/// This converts the event into a [`zbus::Message`] using the [`GenericEvent`] trait.
+	///
+	/// # Errors
+	///
+	/// This will only fail if:
+	/// 1. [`zbus::MessageBuilder`] fails at any point, or
+	/// 2. sending the event fails for some reason.
+	pub fn send_event<T>(&self, event: T) -> Result<u32, AtspiError>
+	where
+		T: for<'a> GenericEvent<'a>,
+	{
+		block_on(self.inner.send_event(event))
+	}
+}
+
+impl From<AccessibilityConnection> for AccessibilityConnectionBlocking {
+	fn from(inner: AccessibilityConnection) -> Self {
+		let registry = RegistryProxyBlocking::from(inner.registry.inner().clone());
+		Self { inner, registry }
+	}
+}
+
+impl Deref for AccessibilityConnectionBlocking {
+	type Target = RegistryProxyBlocking<'static>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.registry
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{block_on, AccessibilityConnectionBlocking};
+	use crate::{mock, AccessibilityConnection};
+	use atspi_common::events::{
+		object::{ObjectEvents, StateChangedEvent},
+		Event, GenericEvent,
+	};
+
+	#[test]
+	fn iterates_over_events() {
+		let (application, screen_reader) = block_on(mock::peers());
+		let atspi = block_on(AccessibilityConnection::with_bus(screen_reader)).unwrap();
+		let atspi = AccessibilityConnectionBlocking::from(atspi);
+		let mut events = atspi.event_iter();
+
+		let event =
+			StateChangedEvent { item: mock::accessible(3), state: "focused".into(), enabled: 1 };
+		// Peer-to-peer messages only have a sender if it is set explicitly.
+		let message = zbus::MessageBuilder::signal(
+			event.path(),
+			StateChangedEvent::DBUS_INTERFACE,
+			StateChangedEvent::DBUS_MEMBER,
+		)
+		.unwrap()
+		.sender(mock::BUS_NAME)
+		.unwrap()
+		.build(&event.body())
+		.unwrap();
+		block_on(application.send_message(message)).unwrap();
+
+		let received = events.next().unwrap().unwrap();
+		assert_eq!(received, Event::Object(ObjectEvents::StateChanged(event)));
+	}
+}
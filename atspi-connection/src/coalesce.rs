@@ -0,0 +1,504 @@
+//! Thins out storms of events.
+//!
+//! Toolkits can emit hundreds of `ChildrenChanged`, `BoundsChanged` or `VisibleDataChanged`
+//! events when a list reloads. A [`Coalescer`] sits between [`event_stream`] and its consumer
+//! and applies a [`Policy`] to every kind of event, per object:
+//!
+//! * [`Policy::Pass`] lets every event through;
+//! * [`Policy::Collapse`] lets the first event through, then holds back further events of the
+//!   same kind on the same object for the window, and lets only the last of those through when
+//!   the window ends;
+//! * [`Policy::KeepLast`] holds back events for the window and only lets the last one through.
+//!
+//! Events about objects which have become defunct, or have been removed from the cache, are
+//! dropped. How many events were dropped can be read from a [`DropCounter`] at any time.
+//!
+//! Held back events are delivered when their window ends, so they may arrive after events
+//! which were received later.
+//!
+//! [`event_stream`]: crate::AccessibilityConnection::event_stream
+
+use crate::debounce::{debounce, Debounce};
+use atspi_common::{
+	events::{
+		object::{
+			BoundsChangedEvent, ChildrenChangedEvent, ModelChangedEvent, ObjectEvents,
+			TextBoundsChangedEvent, TextCaretMovedEvent, VisibleDataChangedEvent,
+		},
+		CacheEvents, Event, GenericEvent,
+	},
+	Accessible, AtspiError, State,
+};
+use futures_lite::{future, Stream};
+use futures_util::{future::BoxFuture, FutureExt};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+/// The window used by [`CoalesceConfig::default`].
+pub const DEFAULT_WINDOW: Duration = Duration::from_millis(100);
+
+/// The number of defunct objects remembered before they are forgotten.
+const DEFUNCT_LIMIT: usize = 4096;
+
+/// What a [`Coalescer`] does with one kind of event; see the [module documentation](self).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Policy {
+	/// Let every event through.
+	Pass,
+	/// Let the first event through at once and the last of the following ones when the window ends.
+	Collapse,
+	/// Let only the last event through when the window ends.
+	KeepLast,
+}
+
+/// Which [`Policy`] applies to which kind of event, and for how long events are held back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoalesceConfig {
+	window: Duration,
+	drop_defunct: bool,
+	fallback: Policy,
+	/// Policies by D-Bus interface and member of the event.
+	policies: Vec<(&'static str, &'static str, Policy)>,
+}
+
+impl Default for CoalesceConfig {
+	/// Collapses `ChildrenChanged`, `BoundsChanged`, `VisibleDataChanged`, `ModelChanged` and
+	/// `TextBoundsChanged`, keeps the last `TextCaretMoved`, lets all other events pass and drops
+	/// events about defunct objects, with a window of [`DEFAULT_WINDOW`].
+	fn default() -> Self {
+		Self {
+			window: DEFAULT_WINDOW,
+			drop_defunct: true,
+			fallback: Policy::Pass,
+			policies: Vec::new(),
+		}
+		.with::<ChildrenChangedEvent>(Policy::Collapse)
+		.with::<BoundsChangedEvent>(Policy::Collapse)
+		.with::<VisibleDataChangedEvent>(Policy::Collapse)
+		.with::<ModelChangedEvent>(Policy::Collapse)
+		.with::<TextBoundsChangedEvent>(Policy::Collapse)
+		.with::<TextCaretMovedEvent>(Policy::KeepLast)
+	}
+}
+
+impl CoalesceConfig {
+	/// A configuration which lets every event pass, except those about defunct objects.
+	#[must_use]
+	pub fn pass_all() -> Self {
+		Self {
+			window: DEFAULT_WINDOW,
+			drop_defunct: true,
+			fallback: Policy::Pass,
+			policies: Vec::new(),
+		}
+	}
+
+	/// Applies `policy` to events of type `T`.
+	#[must_use]
+	pub fn with<'a, T: GenericEvent<'a>>(mut self, policy: Policy) -> Self {
+		let (interface, member) = (T::DBUS_INTERFACE, T::DBUS_MEMBER);
+		self.policies.retain(|(i, m, _)| (*i, *m) != (interface, member));
+		self.policies.push((interface, member, policy));
+		self
+	}
+
+	/// Applies `policy` to all events without a policy of their own.
+	#[must_use]
+	pub fn fallback(mut self, policy: Policy) -> Self {
+		self.fallback = policy;
+		self
+	}
+
+	/// Holds events back for `window`.
+	#[must_use]
+	pub fn window(mut self, window: Duration) -> Self {
+		self.window = window;
+		self
+	}
+
+	/// Whether to drop events about defunct objects.
+	#[must_use]
+	pub fn drop_defunct(mut self, drop_defunct: bool) -> Self {
+		self.drop_defunct = drop_defunct;
+		self
+	}
+
+	fn policy(&self, event: &Event) -> Policy {
+		let (interface, member) = (event.interface(), event.member());
+		self.policies
+			.iter()
+			.find(|(i, m, _)| *i == interface && *m == member)
+			.map_or(self.fallback, |(_, _, policy)| *policy)
+	}
+}
+
+/// Counts the events a [`Coalescer`] dropped.
+///
+/// Clones share their counts, so a counter obtained before [`Coalescer::apply`] keeps
+/// reporting while the stream runs.
+#[derive(Clone, Debug, Default)]
+pub struct DropCounter {
+	duplicates: Arc<AtomicU64>,
+	defunct: Arc<AtomicU64>,
+}
+
+impl DropCounter {
+	/// Events dropped because a later event of the same kind on the same object replaced them.
+	#[must_use]
+	pub fn duplicates(&self) -> u64 {
+		self.duplicates.load(Ordering::Relaxed)
+	}
+
+	/// Events dropped because their object was defunct.
+	#[must_use]
+	pub fn defunct(&self) -> u64 {
+		self.defunct.load(Ordering::Relaxed)
+	}
+
+	/// All dropped events.
+	#[must_use]
+	pub fn total(&self) -> u64 {
+		self.duplicates() + self.defunct()
+	}
+}
+
+/// The window of one kind of event on one object.
+#[derive(Debug)]
+struct Slot {
+	policy: Policy,
+	until: Instant,
+	held: Option<Event>,
+}
+
+/// An object, and the D-Bus interface and member of a kind of event.
+type Key = (Accessible, String, String);
+
+/// Applies a [`CoalesceConfig`] to events; see the [module documentation](self).
+///
+/// Feed it events with [`Coalescer::process`] and collect held back events with
+/// [`Coalescer::settle`], or let [`Coalescer::apply`] do both on an event stream.
+#[derive(Debug)]
+pub struct Coalescer {
+	config: CoalesceConfig,
+	slots: HashMap<Key, Slot>,
+	defunct: HashSet<Accessible>,
+	dropped: DropCounter,
+}
+
+impl Default for Coalescer {
+	fn default() -> Self {
+		Self::new(CoalesceConfig::default())
+	}
+}
+
+impl Coalescer {
+	/// Creates a coalescer applying `config`.
+	#[must_use]
+	pub fn new(config: CoalesceConfig) -> Self {
+		Self {
+			config,
+			slots: HashMap::new(),
+			defunct: HashSet::new(),
+			dropped: DropCounter::default(),
+		}
+	}
+
+	/// A counter of the events this coalescer drops.
+	#[must_use]
+	pub fn dropped(&self) -> DropCounter {
+		self.dropped.clone()
+	}
+
+	/// Takes an event received at `now`, and returns it if it should be delivered at once.
+	pub fn process(&mut self, event: Event, now: Instant) -> Option<Event> {
+		if self.config.drop_defunct && self.track_defunct(&event) {
+			return Some(event);
+		}
+		if self.defunct.contains(event.item()) {
+			self.dropped.defunct.fetch_add(1, Ordering::Relaxed);
+			return None;
+		}
+		let policy = self.config.policy(&event);
+		if policy == Policy::Pass {
+			return Some(event);
+		}
+		let key = (event.item().clone(), event.interface().to_string(), event.member().to_string());
+		let window = self.config.window;
+		if matches!(self.slots.get(&key), Some(slot) if slot.until <= now && slot.held.is_none()) {
+			self.slots.remove(&key);
+		}
+		match self.slots.get_mut(&key) {
+			Some(slot) => {
+				if slot.held.replace(event).is_some() {
+					self.dropped.duplicates.fetch_add(1, Ordering::Relaxed);
+				}
+				if slot.until <= now {
+					slot.until = now + window;
+				}
+				None
+			}
+			None if policy == Policy::Collapse => {
+				self.slots
+					.insert(key, Slot { policy, until: now + window, held: None });
+				Some(event)
+			}
+			None => {
+				self.slots
+					.insert(key, Slot { policy, until: now + window, held: Some(event) });
+				None
+			}
+		}
+	}
+
+	/// The moment the first held back event is due, if there is one.
+	#[must_use]
+	pub fn deadline(&self) -> Option<Instant> {
+		self.slots
+			.values()
+			.filter(|slot| slot.held.is_some())
+			.map(|slot| slot.until)
+			.min()
+	}
+
+	/// Returns the held back events whose window has ended at `now`, in the order their
+	/// windows ended.
+	pub fn settle(&mut self, now: Instant) -> Vec<Event> {
+		let window = self.config.window;
+		let mut due = Vec::new();
+		self.slots.retain(|_, slot| {
+			if slot.until > now {
+				return true;
+			}
+			match slot.held.take() {
+				Some(event) => {
+					due.push((slot.until, event));
+					// A collapsed kind stays throttled after letting its last event through.
+					slot.until = now + window;
+					slot.policy == Policy::Collapse
+				}
+				None => false,
+			}
+		});
+		due.sort_by_key(|(until, _)| *until);
+		due.into_iter().map(|(_, event)| event).collect()
+	}
+
+	/// Returns all held back events, regardless of their window.
+	pub fn flush(&mut self) -> Vec<Event> {
+		let mut due: Vec<(Instant, Event)> = self
+			.slots
+			.drain()
+			.filter_map(|(_, slot)| Some((slot.until, slot.held?)))
+			.collect();
+		due.sort_by_key(|(until, _)| *until);
+		due.into_iter().map(|(_, event)| event).collect()
+	}
+
+	/// Applies the coalescer to a stream of events.
+	///
+	/// Errors are passed on at once. Held back events are delivered when `events` ends.
+	///
+	/// # Example
+	///
+	/// ```rust,no_run
+	/// use atspi_connection::{coalesce::Coalescer, AccessibilityConnection};
+	/// use futures_lite::StreamExt;
+	///
+	/// # tokio_test::block_on(async {
+	/// let connection = AccessibilityConnection::open().await.unwrap();
+	/// let coalescer = Coalescer::default();
+	/// let dropped = coalescer.dropped();
+	/// let events = coalescer.apply(connection.event_stream());
+	/// futures_lite::pin!(events);
+	/// while let Some(event) = events.next().await {
+	///     println!("{event:?}, {} dropped so far", dropped.total());
+	/// }
+	/// # })
+	/// ```
+	pub fn apply<S>(self, events: S) -> impl Stream<Item = Result<Event, AtspiError>>
+	where
+		S: Stream<Item = Result<Event, AtspiError>>,
+	{
+		debounce(self, events)
+	}
+
+	/// Records objects becoming defunct or being removed; returns whether `event` announced it.
+	fn track_defunct(&mut self, event: &Event) -> bool {
+		let gone = match event {
+			Event::Object(ObjectEvents::StateChanged(ev)) if ev.state == State::Defunct => {
+				if ev.enabled != 1 {
+					self.defunct.remove(&ev.item);
+					return true;
+				}
+				&ev.item
+			}
+			Event::Cache(CacheEvents::Remove(ev)) => &ev.node_removed,
+			_ => return false,
+		};
+		if self.defunct.len() >= DEFUNCT_LIMIT {
+			self.defunct.clear();
+		}
+		self.defunct.insert(gone.clone());
+		let before = self.slots.len();
+		self.slots
+			.retain(|(item, _, _), slot| item != gone || slot.held.is_none());
+		let removed = (before - self.slots.len()) as u64;
+		self.dropped.defunct.fetch_add(removed, Ordering::Relaxed);
+		true
+	}
+}
+
+impl Debounce for Coalescer {
+	type Item = Result<Event, AtspiError>;
+
+	fn deadline(&self) -> Option<Instant> {
+		self.deadline()
+	}
+
+	fn process(
+		&mut self,
+		event: Result<Event, AtspiError>,
+		now: Instant,
+	) -> BoxFuture<'_, Vec<Self::Item>> {
+		let ready = match event {
+			Ok(event) => self.process(event, now).map(Ok).into_iter().collect(),
+			Err(e) => vec![Err(e)],
+		};
+		future::ready(ready).boxed()
+	}
+
+	fn settle(&mut self, now: Instant) -> BoxFuture<'_, Vec<Self::Item>> {
+		future::ready(self.settle(now).into_iter().map(Ok).collect()).boxed()
+	}
+
+	fn flush(&mut self) -> BoxFuture<'_, Vec<Self::Item>> {
+		future::ready(self.flush().into_iter().map(Ok).collect()).boxed()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use atspi_common::events::object::{StateChangedEvent, TextChangedEvent};
+	use futures_lite::{stream, StreamExt};
+
+	fn object(index: u32) -> Accessible {
+		Accessible {
+			name: ":1.42".into(),
+			path: format!("/org/a11y/atspi/accessible/{index}").try_into().unwrap(),
+		}
+	}
+
+	fn children_changed(index: u32, child: u32) -> Event {
+		ChildrenChangedEvent {
+			item: object(index),
+			operation: "add".into(),
+			index_in_parent: 0,
+			child: object(child),
+		}
+		.into()
+	}
+
+	fn caret(index: u32, position: i32) -> Event {
+		TextCaretMovedEvent { item: object(index), position }.into()
+	}
+
+	#[test]
+	fn collapses_storms() {
+		let mut coalescer = Coalescer::default();
+		let dropped = coalescer.dropped();
+		let start = Instant::now();
+		assert!(coalescer.process(children_changed(1, 10), start).is_some());
+		for child in 11..20 {
+			assert!(coalescer.process(children_changed(1, child), start).is_none());
+		}
+		// Other objects are not affected.
+		assert!(coalescer.process(children_changed(2, 10), start).is_some());
+		// Events without a policy pass.
+		let text = TextChangedEvent { item: object(1), ..Default::default() };
+		assert!(coalescer.process(text.into(), start).is_some());
+
+		assert_eq!(coalescer.deadline(), Some(start + DEFAULT_WINDOW));
+		assert_eq!(coalescer.settle(start), []);
+		assert_eq!(coalescer.settle(start + DEFAULT_WINDOW), [children_changed(1, 19)]);
+		assert_eq!(dropped.duplicates(), 8);
+
+		// The kind stays throttled for another window after its last event was let through.
+		let later = start + DEFAULT_WINDOW;
+		assert!(coalescer.process(children_changed(1, 20), later).is_none());
+		assert_eq!(coalescer.flush(), [children_changed(1, 20)]);
+	}
+
+	#[test]
+	fn keeps_last_caret_movement() {
+		let mut coalescer = Coalescer::default();
+		let start = Instant::now();
+		for position in 0..5 {
+			assert!(coalescer.process(caret(1, position), start).is_none());
+		}
+		assert_eq!(coalescer.settle(start + DEFAULT_WINDOW), [caret(1, 4)]);
+		assert_eq!(coalescer.deadline(), None);
+		assert_eq!(coalescer.dropped().duplicates(), 4);
+	}
+
+	#[test]
+	fn drops_events_of_defunct_objects() {
+		let mut coalescer = Coalescer::default();
+		let start = Instant::now();
+		assert!(coalescer.process(caret(1, 0), start).is_none());
+		let defunct = StateChangedEvent { item: object(1), state: State::Defunct, enabled: 1 };
+		assert!(coalescer.process(defunct.clone().into(), start).is_some());
+		assert!(coalescer.process(children_changed(1, 2), start).is_none());
+		assert_eq!(coalescer.flush(), []);
+		assert_eq!(coalescer.dropped().defunct(), 2);
+
+		let alive = StateChangedEvent { enabled: 0, ..defunct };
+		assert!(coalescer.process(alive.into(), start).is_some());
+		assert!(coalescer.process(children_changed(1, 2), start).is_some());
+	}
+
+	#[test]
+	fn configurable_policies() {
+		let config = CoalesceConfig::pass_all()
+			.with::<TextCaretMovedEvent>(Policy::Collapse)
+			.window(Duration::from_secs(1))
+			.drop_defunct(false);
+		let mut coalescer = Coalescer::new(config);
+		let start = Instant::now();
+		assert!(coalescer.process(children_changed(1, 2), start).is_some());
+		assert!(coalescer.process(children_changed(1, 2), start).is_some());
+		assert!(coalescer.process(caret(1, 0), start).is_some());
+		assert!(coalescer.process(caret(1, 1), start).is_none());
+		assert_eq!(coalescer.deadline(), Some(start + Duration::from_secs(1)));
+	}
+
+	#[test]
+	fn applies_to_streams() {
+		tokio_test::block_on(async {
+			let events = stream::iter([
+				Ok(caret(1, 0)),
+				Ok(children_changed(1, 2)),
+				Err(AtspiError::MissingName),
+				Ok(caret(1, 1)),
+				Ok(children_changed(1, 3)),
+			]);
+			let out: Vec<_> = Coalescer::default().apply(events).collect().await;
+			let out: Vec<_> = out.into_iter().map(Result::ok).collect();
+			assert_eq!(
+				out,
+				[
+					Some(children_changed(1, 2)),
+					None,
+					Some(caret(1, 1)),
+					Some(children_changed(1, 3)),
+				]
+			);
+		});
+	}
+}
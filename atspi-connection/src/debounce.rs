@@ -0,0 +1,80 @@
+//! The event loop shared by the types that hold events back for a while.
+//!
+//! [`FocusTracker`](crate::focus::FocusTracker), [`LiveRegionMonitor`](crate::live::LiveRegionMonitor)
+//! and [`Coalescer`](crate::coalesce::Coalescer) all collect events until some deadline passes.
+//! [`debounce`] feeds them a stream of events, wakes them when their deadline passes and
+//! drains them once the stream ends.
+
+use async_io::Timer;
+use atspi_common::{error::AtspiError, events::Event};
+use futures_lite::{future, stream, Stream, StreamExt};
+use futures_util::future::BoxFuture;
+use std::time::Instant;
+
+/// A type which turns events into items, some of them only once a deadline has passed.
+pub(crate) trait Debounce {
+	type Item;
+
+	/// The moment the earliest held back item is due, if there is one.
+	fn deadline(&self) -> Option<Instant>;
+
+	/// Handles one item of the event stream; returns what is ready at once.
+	fn process(
+		&mut self,
+		event: Result<Event, AtspiError>,
+		now: Instant,
+	) -> BoxFuture<'_, Vec<Self::Item>>;
+
+	/// Returns the items which are due at `now`.
+	fn settle(&mut self, now: Instant) -> BoxFuture<'_, Vec<Self::Item>>;
+
+	/// Returns all held back items.
+	fn flush(&mut self) -> BoxFuture<'_, Vec<Self::Item>>;
+}
+
+/// Runs `events` through `debouncer`.
+///
+/// The returned stream ends once `events` has ended and the held back items have been flushed.
+pub(crate) fn debounce<D, S>(debouncer: D, events: S) -> impl Stream<Item = D::Item>
+where
+	D: Debounce,
+	S: Stream<Item = Result<Event, AtspiError>>,
+{
+	let state = (debouncer, Box::pin(events), Vec::new().into_iter(), false);
+	stream::unfold(state, |(mut debouncer, mut events, mut ready, mut ended)| async move {
+		loop {
+			if let Some(item) = ready.next() {
+				return Some((item, (debouncer, events, ready, ended)));
+			}
+			if ended {
+				return None;
+			}
+			let settled = debouncer.settle(Instant::now()).await;
+			if !settled.is_empty() {
+				ready = settled.into_iter();
+				continue;
+			}
+			let next = match debouncer.deadline() {
+				Some(deadline) => {
+					future::or(async { Some(events.next().await) }, async {
+						Timer::at(deadline).await;
+						None
+					})
+					.await
+				}
+				None => Some(events.next().await),
+			};
+			match next {
+				Some(Some(event)) => {
+					ready = debouncer.process(event, Instant::now()).await.into_iter();
+				}
+				Some(None) => {
+					ended = true;
+					ready = debouncer.flush().await.into_iter();
+				}
+				// The deadline passed.
+				None => {}
+			}
+		}
+	})
+}
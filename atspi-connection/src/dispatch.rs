@@ -0,0 +1,457 @@
+//! Routes events to the handlers interested in them.
+//!
+//! An [`EventDispatcher`] replaces the single loop matching on every [`Event`]. Handlers are
+//! registered for a concrete event type such as `StateChangedEvent`, for a wrapper enum such as
+//! `ObjectEvents`, or for the events passing a [`Filter`]. Every handler has a priority:
+//! handlers with a higher priority run first, and any of them can end the processing of an
+//! event by returning [`Flow::Stop`].
+//!
+//! A handler which panics is counted in the [`Dispatched`] summary and does not affect the
+//! other handlers.
+//!
+//! ```rust,no_run
+//! use atspi_common::events::object::StateChangedEvent;
+//! use atspi_connection::{
+//!     dispatch::{EventDispatcher, Flow},
+//!     AccessibilityConnection,
+//! };
+//!
+//! # tokio_test::block_on(async {
+//! let connection = AccessibilityConnection::open().await.unwrap();
+//! connection.register_event::<StateChangedEvent>().await.unwrap();
+//!
+//! let mut dispatcher = EventDispatcher::new();
+//! dispatcher.on(0, |event: StateChangedEvent| async move {
+//!     println!("{:?} of {} is now {}", event.state, event.item.path.as_str(), event.enabled);
+//!     Flow::Continue
+//! });
+//! dispatcher.run(connection.event_stream()).await;
+//! # })
+//! ```
+
+use atspi_common::{events::Event, Accessible, AtspiError, Role};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	proxy_for,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+use futures_lite::{Stream, StreamExt};
+use futures_util::{
+	future::{join_all, BoxFuture},
+	FutureExt,
+};
+use std::{
+	future::Future,
+	panic::{catch_unwind, AssertUnwindSafe},
+	sync::Arc,
+};
+
+/// Whether lower priority handlers get to see an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Flow {
+	/// Pass the event on to the next handlers.
+	Continue,
+	/// Handlers with a lower priority do not see the event.
+	Stop,
+}
+
+/// How the handlers of one priority run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+	/// One after the other, in the order they were registered.
+	#[default]
+	Sequential,
+	/// All at once. The next priority runs when all of them have finished.
+	Concurrent,
+}
+
+/// Selects events by their origin.
+#[derive(Clone)]
+pub enum Filter {
+	/// Events sent by the application with this unique bus name.
+	Sender(String),
+	/// Events about objects whose path starts with this prefix.
+	PathPrefix(String),
+	/// Events about objects with this role.
+	///
+	/// Looking up the role requires [`EventDispatcher::with_connection`];
+	/// without a connection, no event matches.
+	Role(Role),
+	/// Events passing every filter.
+	All(Vec<Filter>),
+	/// Events for which the function returns `true`.
+	Predicate(Arc<dyn Fn(&Event) -> bool + Send + Sync>),
+}
+
+impl std::fmt::Debug for Filter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Sender(sender) => f.debug_tuple("Sender").field(sender).finish(),
+			Self::PathPrefix(prefix) => f.debug_tuple("PathPrefix").field(prefix).finish(),
+			Self::Role(role) => f.debug_tuple("Role").field(role).finish(),
+			Self::All(filters) => f.debug_tuple("All").field(filters).finish(),
+			Self::Predicate(_) => f.write_str("Predicate(..)"),
+		}
+	}
+}
+
+impl Filter {
+	/// Whether `event` passes the filter, given the role of the object if it was looked up.
+	///
+	/// `None` if that depends on the role, and it was not looked up yet.
+	fn matches(&self, event: &Event, role: Option<Option<Role>>) -> Option<bool> {
+		match self {
+			Self::Sender(sender) => Some(event.item().name == *sender),
+			Self::PathPrefix(prefix) => {
+				Some(event.item().path.as_str().starts_with(prefix.as_str()))
+			}
+			Self::Role(expected) => role.map(|role| role == Some(*expected)),
+			Self::All(filters) => {
+				let mut all = Some(true);
+				for filter in filters {
+					match filter.matches(event, role) {
+						Some(false) => return Some(false),
+						None => all = None,
+						Some(true) => {}
+					}
+				}
+				all
+			}
+			Self::Predicate(predicate) => Some(predicate(event)),
+		}
+	}
+}
+
+/// Identifies a registered handler, to remove it with [`EventDispatcher::remove`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HandlerId(u64);
+
+/// What happened to one event.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dispatched {
+	/// The number of handlers which ran, including those which panicked.
+	pub handled: usize,
+	/// The number of handlers which panicked.
+	pub panicked: usize,
+	/// Whether a handler returned [`Flow::Stop`].
+	pub stopped: bool,
+}
+
+type Call = dyn Fn(Event) -> Option<BoxFuture<'static, Flow>> + Send + Sync;
+
+struct Entry {
+	id: HandlerId,
+	priority: i32,
+	filter: Option<Filter>,
+	call: Arc<Call>,
+}
+
+/// Calls handlers for events; see the [module documentation](self).
+pub struct EventDispatcher {
+	mode: Mode,
+	conn: Option<(zbus::Connection, CallGuard)>,
+	entries: Vec<Entry>,
+	next_id: u64,
+}
+
+impl std::fmt::Debug for EventDispatcher {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("EventDispatcher")
+			.field("mode", &self.mode)
+			.field("handlers", &self.entries.len())
+			.finish_non_exhaustive()
+	}
+}
+
+impl Default for EventDispatcher {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl EventDispatcher {
+	/// Creates a dispatcher without handlers, running them [sequentially](Mode::Sequential).
+	#[must_use]
+	pub fn new() -> Self {
+		Self { mode: Mode::Sequential, conn: None, entries: Vec::new(), next_id: 0 }
+	}
+
+	/// Runs handlers of the same priority in `mode`.
+	#[must_use]
+	pub fn mode(mut self, mode: Mode) -> Self {
+		self.mode = mode;
+		self
+	}
+
+	/// Uses `conn` to look up the roles [`Filter::Role`] asks for, subject to `guard`.
+	#[must_use]
+	pub fn with_connection(mut self, conn: zbus::Connection, guard: CallGuard) -> Self {
+		self.conn = Some((conn, guard));
+		self
+	}
+
+	/// Registers `handler` for the events which convert to `T`.
+	///
+	/// `T` is a concrete event type such as `StateChangedEvent`, or a wrapper enum such as
+	/// `ObjectEvents`. Handlers with a higher `priority` run first.
+	pub fn on<T, F, Fut>(&mut self, priority: i32, handler: F) -> HandlerId
+	where
+		T: TryFrom<Event> + Send + 'static,
+		F: Fn(T) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Flow> + Send + 'static,
+	{
+		let call = move |event: Event| T::try_from(event).ok().map(|event| handler(event).boxed());
+		self.insert(priority, None, Arc::new(call))
+	}
+
+	/// Registers `handler` for the events passing `filter`.
+	pub fn on_filter<F, Fut>(&mut self, filter: Filter, priority: i32, handler: F) -> HandlerId
+	where
+		F: Fn(Event) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Flow> + Send + 'static,
+	{
+		let call = move |event: Event| Some(handler(event).boxed());
+		self.insert(priority, Some(filter), Arc::new(call))
+	}
+
+	/// Removes a handler; returns whether it was registered.
+	pub fn remove(&mut self, id: HandlerId) -> bool {
+		let before = self.entries.len();
+		self.entries.retain(|entry| entry.id != id);
+		self.entries.len() != before
+	}
+
+	/// Passes `event` to the handlers interested in it.
+	///
+	/// The role of the object is looked up once a [`Filter::Role`] has to decide, if at all.
+	pub async fn dispatch(&self, event: &Event) -> Dispatched {
+		let mut role = None;
+		let mut dispatched = Dispatched::default();
+		let mut entries = self.entries.iter().peekable();
+		while let Some(first) = entries.peek() {
+			let priority = first.priority;
+			let mut calls = Vec::new();
+			while let Some(entry) = entries.next_if(|entry| entry.priority == priority) {
+				let matches = match &entry.filter {
+					Some(filter) => match filter.matches(event, role) {
+						Some(matches) => matches,
+						None => {
+							let looked_up = self.role_of(event.item()).await;
+							role = Some(looked_up);
+							filter.matches(event, role) == Some(true)
+						}
+					},
+					None => true,
+				};
+				if matches {
+					calls.push(Arc::clone(&entry.call));
+				}
+			}
+			let flows = match self.mode {
+				Mode::Sequential => {
+					let mut flows = Vec::with_capacity(calls.len());
+					for call in calls {
+						let flow = run(call.as_ref(), event).await;
+						let stop = flow == Some(Ok(Flow::Stop));
+						flows.push(flow);
+						if stop {
+							break;
+						}
+					}
+					flows
+				}
+				Mode::Concurrent => {
+					join_all(calls.iter().map(|call| run(call.as_ref(), event))).await
+				}
+			};
+			for flow in flows.into_iter().flatten() {
+				dispatched.handled += 1;
+				match flow {
+					Ok(Flow::Stop) => dispatched.stopped = true,
+					Ok(Flow::Continue) => {}
+					Err(()) => dispatched.panicked += 1,
+				}
+			}
+			if dispatched.stopped {
+				break;
+			}
+		}
+		dispatched
+	}
+
+	/// Dispatches every event of `events`, one at a time, until the stream ends.
+	///
+	/// Errors in the event stream are skipped.
+	pub async fn run<S>(&self, events: S)
+	where
+		S: Stream<Item = Result<Event, AtspiError>>,
+	{
+		let mut events = Box::pin(events);
+		while let Some(event) = events.next().await {
+			if let Ok(event) = event {
+				self.dispatch(&event).await;
+			}
+		}
+	}
+
+	fn insert(&mut self, priority: i32, filter: Option<Filter>, call: Arc<Call>) -> HandlerId {
+		let id = HandlerId(self.next_id);
+		self.next_id += 1;
+		let index = self.entries.partition_point(|entry| entry.priority >= priority);
+		self.entries.insert(index, Entry { id, priority, filter, call });
+		id
+	}
+
+	async fn role_of(&self, item: &Accessible) -> Option<Role> {
+		let (conn, guard) = self.conn.as_ref()?;
+		let proxy = proxy_for::<AccessibleProxy>(conn, item).await.ok()?;
+		proxy.get_role().guarded(guard, &item.name).await.ok()
+	}
+}
+
+/// Calls a handler; `None` if it does not take the event, `Err` if it panicked.
+async fn run(call: &Call, event: &Event) -> Option<Result<Flow, ()>> {
+	let future = match catch_unwind(AssertUnwindSafe(|| call(event.clone()))) {
+		Ok(future) => future?,
+		Err(_) => return Some(Err(())),
+	};
+	Some(AssertUnwindSafe(future).catch_unwind().await.map_err(|_| ()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+	use atspi_common::events::object::{ObjectEvents, StateChangedEvent, TextCaretMovedEvent};
+	use std::{
+		sync::Mutex,
+		time::{Duration, Instant},
+	};
+
+	fn state_changed(item: Accessible) -> Event {
+		StateChangedEvent { item, ..Default::default() }.into()
+	}
+
+	fn object(name: &str, path: &str) -> Accessible {
+		Accessible { name: name.into(), path: path.try_into().unwrap() }
+	}
+
+	type Log = Arc<Mutex<Vec<&'static str>>>;
+
+	fn record(log: &Log, entry: &'static str, flow: Flow) -> impl Future<Output = Flow> {
+		log.lock().unwrap().push(entry);
+		async move { flow }
+	}
+
+	#[test]
+	fn runs_handlers_by_priority_until_stopped() {
+		tokio_test::block_on(async {
+			let log: Log = Arc::default();
+			let mut dispatcher = EventDispatcher::new();
+			let l = log.clone();
+			dispatcher.on(0, move |_: StateChangedEvent| record(&l, "low", Flow::Continue));
+			let l = log.clone();
+			dispatcher.on(10, move |_: ObjectEvents| record(&l, "object", Flow::Continue));
+			let l = log.clone();
+			let stopper =
+				dispatcher.on(5, move |_: StateChangedEvent| record(&l, "stop", Flow::Stop));
+			let l = log.clone();
+			dispatcher.on(10, move |_: TextCaretMovedEvent| record(&l, "caret", Flow::Continue));
+
+			let item = object(":1.1", "/org/a11y/atspi/accessible/1");
+			let dispatched = dispatcher.dispatch(&state_changed(item.clone())).await;
+			assert_eq!(*log.lock().unwrap(), ["object", "stop"]);
+			assert_eq!(dispatched, Dispatched { handled: 2, panicked: 0, stopped: true });
+
+			assert!(dispatcher.remove(stopper));
+			assert!(!dispatcher.remove(stopper));
+			log.lock().unwrap().clear();
+			dispatcher.dispatch(&state_changed(item)).await;
+			assert_eq!(*log.lock().unwrap(), ["object", "low"]);
+		});
+	}
+
+	#[test]
+	fn isolates_panics() {
+		tokio_test::block_on(async {
+			for mode in [Mode::Sequential, Mode::Concurrent] {
+				let log: Log = Arc::default();
+				let mut dispatcher = EventDispatcher::new().mode(mode);
+				dispatcher.on(2, |_: StateChangedEvent| -> BoxFuture<'static, Flow> {
+					panic!("handler failed before returning a future")
+				});
+				dispatcher.on(2, |_: StateChangedEvent| async { panic!("handler failed") });
+				let l = log.clone();
+				dispatcher.on(1, move |_: StateChangedEvent| record(&l, "after", Flow::Continue));
+
+				let event = state_changed(object(":1.1", "/org/a11y/atspi/accessible/1"));
+				let dispatched = dispatcher.dispatch(&event).await;
+				assert_eq!(dispatched.panicked, 2);
+				assert_eq!(dispatched.handled, 3);
+				assert_eq!(*log.lock().unwrap(), ["after"]);
+			}
+		});
+	}
+
+	#[test]
+	fn filters_by_sender_path_and_role() {
+		tokio_test::block_on(async {
+			let mut tree = Tree::default();
+			tree.add(Node::new(Role::Frame, "").children(&[1]));
+			tree.add(Node::new(Role::PushButton, "OK"));
+			let log: Log = Arc::default();
+			let mut dispatcher =
+				EventDispatcher::new().with_connection(tree.serve().await, CallGuard::default());
+			let l = log.clone();
+			let sender = Filter::Sender(mock::BUS_NAME.into());
+			dispatcher.on_filter(sender, 0, move |_| record(&l, "sender", Flow::Continue));
+			let l = log.clone();
+			let prefix = Filter::PathPrefix("/org/a11y/atspi/accessible/".into());
+			dispatcher.on_filter(prefix, 0, move |_| record(&l, "path", Flow::Continue));
+			let l = log.clone();
+			let button = Filter::All(vec![
+				Filter::Role(Role::PushButton),
+				Filter::Predicate(Arc::new(|event| matches!(event, Event::Object(_)))),
+			]);
+			dispatcher.on_filter(button, 0, move |_| record(&l, "button", Flow::Continue));
+
+			dispatcher.dispatch(&state_changed(mock::accessible(1))).await;
+			assert_eq!(*log.lock().unwrap(), ["sender", "path", "button"]);
+
+			log.lock().unwrap().clear();
+			dispatcher.dispatch(&state_changed(mock::accessible(0))).await;
+			dispatcher
+				.dispatch(&state_changed(object(":1.7", "/org/example")))
+				.await;
+			assert_eq!(*log.lock().unwrap(), ["sender", "path"]);
+		});
+	}
+
+	#[test]
+	fn looks_roles_up_only_when_a_filter_needs_them() {
+		tokio_test::block_on(async {
+			// Every role lookup would take the whole timeout.
+			let (conn, _peer) = mock::unresponsive().await;
+			let timeout = Duration::from_secs(2);
+			let log: Log = Arc::default();
+			let mut dispatcher =
+				EventDispatcher::new().with_connection(conn, CallGuard::new(timeout));
+			let l = log.clone();
+			let elsewhere =
+				Filter::All(vec![Filter::Role(Role::PushButton), Filter::Sender(":1.9".into())]);
+			dispatcher.on_filter(elsewhere, 10, move |_| record(&l, "elsewhere", Flow::Continue));
+			let l = log.clone();
+			dispatcher.on(5, move |_: StateChangedEvent| record(&l, "stop", Flow::Stop));
+			let l = log.clone();
+			let button = Filter::Role(Role::PushButton);
+			dispatcher.on_filter(button, 0, move |_| record(&l, "button", Flow::Continue));
+
+			let start = Instant::now();
+			dispatcher
+				.dispatch(&state_changed(object(":1.1", "/org/a11y/atspi/accessible/1")))
+				.await;
+			assert!(start.elapsed() < timeout);
+			assert_eq!(*log.lock().unwrap(), ["stop"]);
+		});
+	}
+}
@@ -0,0 +1,500 @@
+//! Filters events by the application sending them.
+//!
+//! Some applications, like terminals or chat clients, flood the bus with events. An
+//! [`AppFilter`] lists applications, either by bus name or by name, together with an
+//! [`EventMask`] selecting the kinds of events to let through from them; all other applications
+//! get the mask of the filter itself. [`AppFilter::allow_all`] starts a deny list,
+//! [`AppFilter::deny_all`] starts an allow list.
+//!
+//! Where possible, [`AccessibilityConnection::register_event_filtered`] leaves out events at the
+//! bus, by adding match rules with a `sender=` clause. D-Bus has no way to express a deny list in
+//! match rules, so [`AppFilter::apply`] filters the event stream itself as well. The returned
+//! [`FilteredRegistration`] removes those match rules again, through
+//! [`AccessibilityConnection::deregister_event_filtered`].
+//!
+//! ```rust,no_run
+//! use atspi_common::events::object::{ObjectEvents, StateChangedEvent};
+//! use atspi_connection::{
+//!     filter::{App, AppFilter, EventMask},
+//!     AccessibilityConnection,
+//! };
+//! # use futures_lite::StreamExt;
+//!
+//! # tokio_test::block_on(async {
+//! let connection = AccessibilityConnection::open().await.unwrap();
+//! let filter = AppFilter::allow_all()
+//!     .deny(App::Named("gnome-terminal-server".into()))
+//!     .mask(App::Named("Slack".into()), EventMask::none().with::<StateChangedEvent>());
+//! let registration = connection.register_event_filtered::<ObjectEvents>(&filter).await.unwrap();
+//!
+//! let events = filter.apply(
+//!     connection.connection().clone(),
+//!     connection.call_guard().clone(),
+//!     connection.event_stream(),
+//! );
+//! futures_lite::pin!(events);
+//! while let Some(event) = events.next().await {
+//!     println!("{event:?}");
+//! }
+//! connection.deregister_event_filtered::<ObjectEvents>(registration).await.unwrap();
+//! # })
+//! ```
+
+use crate::{application::root_of, AccessibilityConnection, AtspiResult};
+use atspi_common::{
+	events::{Event, GenericEvent, HasMatchRule, HasRegistryEventString},
+	AtspiError,
+};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	proxy_for,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+use futures_lite::{stream, Stream, StreamExt};
+use std::{
+	collections::HashMap,
+	time::{Duration, Instant},
+};
+use zbus::MatchRule;
+
+/// How many application names are remembered before the cache starts over.
+const CACHE_LIMIT: usize = 1024;
+
+/// How long a failed lookup of an application name is remembered before it is tried again.
+const FAILURE_RETRY: Duration = Duration::from_secs(2);
+
+/// An application an [`AppFilter`] applies to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum App {
+	/// The application owning this unique bus name, for example `:1.42`.
+	BusName(String),
+	/// The applications whose root object has this accessible name, as listed by
+	/// [`AccessibilityConnection::applications`].
+	Named(String),
+}
+
+/// The kinds of events to let through.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventMask {
+	all: bool,
+	/// Kinds by D-Bus interface and, unless the whole interface is included, member.
+	kinds: Vec<(&'static str, Option<&'static str>)>,
+}
+
+impl EventMask {
+	/// A mask letting every event through.
+	#[must_use]
+	pub fn all() -> Self {
+		Self { all: true, kinds: Vec::new() }
+	}
+
+	/// A mask letting no event through; add kinds with [`Self::with`] and [`Self::with_interface`].
+	#[must_use]
+	pub fn none() -> Self {
+		Self { all: false, kinds: Vec::new() }
+	}
+
+	/// Also lets events of type `T` through.
+	#[must_use]
+	pub fn with<'a, T: GenericEvent<'a>>(mut self) -> Self {
+		self.kinds.push((T::DBUS_INTERFACE, Some(T::DBUS_MEMBER)));
+		self
+	}
+
+	/// Also lets all events of a D-Bus interface, such as `org.a11y.atspi.Event.Object`, through.
+	#[must_use]
+	pub fn with_interface(mut self, interface: &'static str) -> Self {
+		self.kinds.push((interface, None));
+		self
+	}
+
+	/// Whether `event` gets through.
+	#[must_use]
+	pub fn allows(&self, event: &Event) -> bool {
+		self.all
+			|| self.kinds.iter().any(|(interface, member)| {
+				*interface == event.interface()
+					&& match member {
+						Some(member) => *member == event.member(),
+						None => true,
+					}
+			})
+	}
+
+	/// Whether any event matching `rule` could get through.
+	fn overlaps(&self, rule: &MatchRule<'_>) -> bool {
+		self.all
+			|| self.kinds.iter().any(|(interface, member)| {
+				let interface_matches = match rule.interface() {
+					Some(rule_interface) => rule_interface.as_str() == *interface,
+					None => true,
+				};
+				let member_matches = match (rule.member(), member) {
+					(Some(rule_member), Some(member)) => rule_member.as_str() == *member,
+					_ => true,
+				};
+				interface_matches && member_matches
+			})
+	}
+}
+
+/// Which events to let through from which application; see the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppFilter {
+	unlisted: EventMask,
+	apps: Vec<(App, EventMask)>,
+}
+
+impl AppFilter {
+	/// A filter letting through all events, except from the applications denied or masked later.
+	#[must_use]
+	pub fn allow_all() -> Self {
+		Self { unlisted: EventMask::all(), apps: Vec::new() }
+	}
+
+	/// A filter letting through no event, except from the applications allowed or masked later.
+	#[must_use]
+	pub fn deny_all() -> Self {
+		Self { unlisted: EventMask::none(), apps: Vec::new() }
+	}
+
+	/// Lets every event from `app` through.
+	#[must_use]
+	pub fn allow(self, app: App) -> Self {
+		self.mask(app, EventMask::all())
+	}
+
+	/// Lets no event from `app` through.
+	#[must_use]
+	pub fn deny(self, app: App) -> Self {
+		self.mask(app, EventMask::none())
+	}
+
+	/// Lets the events in `mask` from `app` through, replacing an earlier mask for `app`.
+	#[must_use]
+	pub fn mask(mut self, app: App, mask: EventMask) -> Self {
+		self.apps.retain(|(a, _)| *a != app);
+		self.apps.push((app, mask));
+		self
+	}
+
+	/// Whether `event` gets through, given the name of the application sending it.
+	///
+	/// A mask for the bus name of the sender takes precedence over a mask for its name.
+	#[must_use]
+	pub fn allows(&self, event: &Event, application_name: Option<&str>) -> bool {
+		self.mask_for(&event.item().name, application_name).allows(event)
+	}
+
+	fn mask_for(&self, sender: &str, application_name: Option<&str>) -> &EventMask {
+		let by_bus_name = self
+			.apps
+			.iter()
+			.find(|(app, _)| matches!(app, App::BusName(name) if name == sender));
+		let by_name = || {
+			self.apps.iter().find(
+				|(app, _)| matches!((app, application_name), (App::Named(name), Some(n)) if name == n),
+			)
+		};
+		by_bus_name.or_else(by_name).map_or(&self.unlisted, |(_, mask)| mask)
+	}
+
+	fn needs_names(&self) -> bool {
+		self.apps.iter().any(|(app, _)| matches!(app, App::Named(_)))
+	}
+
+	/// Leaves out the events of `events` which do not get through.
+	///
+	/// The name of an application is looked up on its root object over `conn`, subject to
+	/// `guard`, the first time it sends an event. When the lookup fails, its events are treated as
+	/// coming from an application without a name, and the lookup is tried again with the first
+	/// event after a short while. Errors in the event stream are passed on.
+	pub fn apply<S>(
+		self,
+		conn: zbus::Connection,
+		guard: CallGuard,
+		events: S,
+	) -> impl Stream<Item = Result<Event, AtspiError>>
+	where
+		S: Stream<Item = Result<Event, AtspiError>>,
+	{
+		// The names of senders, or when the lookup last failed.
+		let names: HashMap<String, Result<String, Instant>> = HashMap::new();
+		let state = (self, (conn, guard), names, Box::pin(events));
+		stream::unfold(state, |(filter, lookup, mut names, mut events)| async move {
+			loop {
+				let event = match events.next().await? {
+					Ok(event) => event,
+					Err(e) => return Some((Err(e), (filter, lookup, names, events))),
+				};
+				let name = if filter.needs_names() {
+					let sender = &event.item().name;
+					match names.get(sender) {
+						Some(Ok(name)) => Some(name.clone()),
+						Some(Err(failed)) if failed.elapsed() < FAILURE_RETRY => None,
+						_ => {
+							let (conn, guard) = &lookup;
+							let name = application_name(conn, guard, sender).await;
+							if names.len() >= CACHE_LIMIT {
+								names.clear();
+							}
+							let name = name.map_err(|_| Instant::now());
+							names.insert(sender.clone(), name.clone());
+							name.ok()
+						}
+					}
+				} else {
+					None
+				};
+				if filter.allows(&event, name.as_deref()) {
+					return Some((Ok(event), (filter, lookup, names, events)));
+				}
+			}
+		})
+	}
+}
+
+/// The name of the application owning `sender`, from its root object.
+///
+/// Asking the object which sent an event for its application fails once it is defunct, which
+/// many objects are by the time their events arrive; the root stays.
+async fn application_name(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	sender: &str,
+) -> AtspiResult<String> {
+	proxy_for::<AccessibleProxy>(conn, &root_of(sender))
+		.await?
+		.name()
+		.guarded(guard, sender)
+		.await
+}
+
+/// The match rules added by [`AccessibilityConnection::register_event_filtered`]; pass it to
+/// [`AccessibilityConnection::deregister_event_filtered`] to remove them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct FilteredRegistration {
+	/// The bus names match rules were added for, or `None` when all events were registered for.
+	senders: Option<Vec<String>>,
+}
+
+impl FilteredRegistration {
+	/// The bus names of the applications match rules were added for, or `None` when the events
+	/// were registered for from all applications.
+	#[must_use]
+	pub fn senders(&self) -> Option<&[String]> {
+		self.senders.as_deref()
+	}
+}
+
+impl AccessibilityConnection {
+	/// Like [`Self::add_match_rule`], but only for events sent by `sender`.
+	///
+	/// # Errors
+	///
+	/// When `sender` is not a valid bus name, or adding the match rule fails.
+	pub async fn add_match_rule_for<T: HasMatchRule>(&self, sender: &str) -> AtspiResult<()> {
+		let rule = format!("{},sender='{sender}'", T::MATCH_RULE_STRING);
+		self.dbus_proxy
+			.add_match_rule(MatchRule::try_from(rule.as_str())?)
+			.await?;
+		Ok(())
+	}
+
+	/// Removes a match rule added by [`Self::add_match_rule_for`].
+	///
+	/// # Errors
+	///
+	/// When `sender` is not a valid bus name, or removing the match rule fails.
+	pub async fn remove_match_rule_for<T: HasMatchRule>(&self, sender: &str) -> AtspiResult<()> {
+		let rule = format!("{},sender='{sender}'", T::MATCH_RULE_STRING);
+		self.dbus_proxy
+			.remove_match_rule(MatchRule::try_from(rule.as_str())?)
+			.await?;
+		Ok(())
+	}
+
+	/// Registers for events of type `T`, leaving out at the bus what `filter` would not let
+	/// through, where possible.
+	///
+	/// If `filter` lets events of type `T` from unlisted applications through, this is the same
+	/// as [`Self::register_event`]. Otherwise, match rules are only added for the listed
+	/// applications which may send events of type `T`; applications listed by name are looked up
+	/// among the running applications. Applications starting later are not covered, call this
+	/// again when they appear, for example in response to [`Self::application_events`].
+	///
+	/// The events still need to go through [`AppFilter::apply`], which applies the masks exactly.
+	/// When the filter changes, or applications exit, pass the returned registration to
+	/// [`Self::deregister_event_filtered`] before registering again.
+	///
+	/// # Errors
+	///
+	/// When registering the event, adding a match rule or listing the applications fails.
+	pub async fn register_event_filtered<T: HasRegistryEventString + HasMatchRule>(
+		&self,
+		filter: &AppFilter,
+	) -> AtspiResult<FilteredRegistration> {
+		let rule = MatchRule::try_from(T::MATCH_RULE_STRING)?;
+		if filter.unlisted.overlaps(&rule) {
+			self.register_event::<T>().await?;
+			return Ok(FilteredRegistration { senders: None });
+		}
+		self.add_registry_event::<T>().await?;
+		let mut senders = Vec::new();
+		for (app, mask) in &filter.apps {
+			if let (App::BusName(name), true) = (app, mask.overlaps(&rule)) {
+				senders.push(name.clone());
+			}
+		}
+		if filter.needs_names() {
+			for application in self.applications().await? {
+				let sender = application.bus_name();
+				if filter.mask_for(sender, Some(&application.name)).overlaps(&rule) {
+					senders.push(sender.to_string());
+				}
+			}
+		}
+		senders.sort_unstable();
+		senders.dedup();
+		for sender in &senders {
+			self.add_match_rule_for::<T>(sender).await?;
+		}
+		Ok(FilteredRegistration { senders: Some(senders) })
+	}
+
+	/// Undoes [`Self::register_event_filtered`] for events of type `T`, removing the match rules
+	/// of `registration`.
+	///
+	/// # Errors
+	///
+	/// When deregistering the event or removing a match rule fails; the other match rules are
+	/// removed nevertheless.
+	pub async fn deregister_event_filtered<T: HasRegistryEventString + HasMatchRule>(
+		&self,
+		registration: FilteredRegistration,
+	) -> AtspiResult<()> {
+		let Some(senders) = registration.senders else {
+			return self.deregister_event::<T>().await;
+		};
+		let mut result = self.remove_registry_event::<T>().await;
+		for sender in &senders {
+			let removed = self.remove_match_rule_for::<T>(sender).await;
+			result = result.and(removed);
+		}
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+	use atspi_common::{
+		events::object::{ObjectEvents, StateChangedEvent, TextChangedEvent},
+		Accessible, Role,
+	};
+
+	fn item(sender: &str) -> Accessible {
+		Accessible { name: sender.into(), path: "/org/a11y/atspi/accessible/1".try_into().unwrap() }
+	}
+
+	fn state_changed(sender: &str) -> Event {
+		StateChangedEvent { item: item(sender), ..Default::default() }.into()
+	}
+
+	fn text_changed(sender: &str) -> Event {
+		TextChangedEvent { item: item(sender), ..Default::default() }.into()
+	}
+
+	#[test]
+	fn masks_by_bus_name_before_name() {
+		let filter = AppFilter::allow_all()
+			.deny(App::Named("Terminal".into()))
+			.mask(App::BusName(":1.2".into()), EventMask::none().with::<StateChangedEvent>());
+		assert!(filter.allows(&text_changed(":1.1"), None));
+		assert!(!filter.allows(&text_changed(":1.1"), Some("Terminal")));
+		assert!(filter.allows(&state_changed(":1.2"), Some("Terminal")));
+		assert!(!filter.allows(&text_changed(":1.2"), Some("Terminal")));
+
+		let filter = AppFilter::deny_all().allow(App::Named("Editor".into())).mask(
+			App::Named("Chat".into()),
+			EventMask::none().with_interface("org.a11y.atspi.Event.Object"),
+		);
+		assert!(!filter.allows(&text_changed(":1.1"), None));
+		assert!(filter.allows(&text_changed(":1.1"), Some("Editor")));
+		assert!(filter.allows(&text_changed(":1.1"), Some("Chat")));
+	}
+
+	#[test]
+	fn mask_overlaps_match_rules() {
+		let object = MatchRule::try_from(ObjectEvents::MATCH_RULE_STRING).unwrap();
+		let state =
+			MatchRule::try_from(<StateChangedEvent as HasMatchRule>::MATCH_RULE_STRING).unwrap();
+		let mask = EventMask::none().with::<TextChangedEvent>();
+		assert!(mask.overlaps(&object));
+		assert!(!mask.overlaps(&state));
+		assert!(!EventMask::none().overlaps(&object));
+		assert!(EventMask::all().overlaps(&state));
+	}
+
+	#[test]
+	fn applies_filter_to_stream() {
+		tokio_test::block_on(async {
+			let mut tree = Tree::default();
+			tree.add(Node::new(Role::Application, "Terminal").children(&[1]));
+			tree.add(Node::new(Role::Terminal, ""));
+			let filter = AppFilter::allow_all()
+				.mask(App::Named("Terminal".into()), EventMask::none().with::<StateChangedEvent>());
+			let events = [
+				Ok(text_changed(mock::BUS_NAME)),
+				Ok(state_changed(mock::BUS_NAME)),
+				Err(AtspiError::MissingName),
+			];
+			let filtered =
+				filter.apply(tree.serve().await, CallGuard::default(), stream::iter(events));
+			let filtered: Vec<_> = filtered.collect().await;
+			assert!(matches!(
+				filtered.as_slice(),
+				[Ok(Event::Object(ObjectEvents::StateChanged(_))), Err(AtspiError::MissingName),]
+			));
+		});
+	}
+
+	#[test]
+	fn names_defunct_objects_by_their_root() {
+		tokio_test::block_on(async {
+			let mut tree = Tree::default();
+			tree.add(Node::new(Role::Application, "Terminal").children(&[1]));
+			tree.add(Node::new(Role::Terminal, ""));
+			let filter = AppFilter::allow_all().deny(App::Named("Terminal".into()));
+			let defunct = TextChangedEvent {
+				item: Accessible {
+					name: mock::BUS_NAME.into(),
+					path: "/org/example".try_into().unwrap(),
+				},
+				..Default::default()
+			};
+			let events = [Ok(defunct.into()), Ok(text_changed(mock::BUS_NAME))];
+			let filtered =
+				filter.apply(tree.serve().await, CallGuard::default(), stream::iter(events));
+			assert_eq!(filtered.count().await, 0);
+		});
+	}
+
+	#[test]
+	fn remembers_failed_name_lookups() {
+		tokio_test::block_on(async {
+			let (conn, _peer) = mock::unresponsive().await;
+			let timeout = Duration::from_millis(200);
+			// Never quarantined, so only the filter saves the later lookups.
+			let guard = CallGuard::new(timeout).quarantine(0, Duration::ZERO);
+			let filter = AppFilter::allow_all().deny(App::Named("Terminal".into()));
+			let events = (0..4).map(|_| Ok(text_changed(":1.7")));
+			let start = Instant::now();
+			let filtered = filter.apply(conn, guard, stream::iter(events));
+			assert_eq!(filtered.count().await, 4);
+			assert!(start.elapsed() < 2 * timeout);
+		});
+	}
+}
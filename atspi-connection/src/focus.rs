@@ -0,0 +1,349 @@
+//! Keeps track of which object has focus.
+//!
+//! No single AT-SPI signal tells what currently has focus.
+//! [`FocusTracker`] combines `Focus:` events, `StateChanged` events for [`State::Focused`],
+//! `ActiveDescendantChanged` events and window activation into one stream of [`FocusChanged`]
+//! notifications.
+//!
+//! Toolkits tend to emit bursts of focus related signals, sometimes moving focus back and forth
+//! between objects in a few milliseconds. The tracker only reports a change once the focus
+//! candidate has been stable for the debounce period, and never reports the same object twice in a row.
+
+use crate::{
+	debounce::{debounce, Debounce},
+	AccessibilityConnection,
+};
+use atspi_common::{
+	accessible::ROOT_PATH,
+	events::{
+		focus::FocusEvents,
+		object::{ActiveDescendantChangedEvent, ObjectEvents, StateChangedEvent},
+		window::{ActivateEvent, DeactivateEvent, WindowEvents},
+		Event,
+	},
+	Accessible, AtspiError, State,
+};
+use futures_lite::{future, Stream};
+use futures_util::{future::BoxFuture, FutureExt};
+use std::time::{Duration, Instant};
+
+/// The debounce period used by [`FocusTracker::default`].
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// A notification that the focused object changed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FocusChanged {
+	/// The object that had focus before, if any.
+	pub previous: Option<Accessible>,
+	/// The object that has focus now.
+	pub focused: Accessible,
+	/// The active window, if it belongs to the same application as [`Self::focused`].
+	pub window: Option<Accessible>,
+	/// The root object of the application [`Self::focused`] belongs to.
+	pub application: Accessible,
+}
+
+/// Tracks the focused object, the active window and the active application.
+///
+/// Feed it events with [`FocusTracker::process`] and collect the settled changes with
+/// [`FocusTracker::settle`], or let [`FocusTracker::changes`] do both on an event stream.
+#[derive(Clone, Debug)]
+pub struct FocusTracker {
+	debounce: Duration,
+	focused: Option<Accessible>,
+	active_window: Option<Accessible>,
+	pending: Option<(Accessible, Instant)>,
+}
+
+impl Default for FocusTracker {
+	fn default() -> Self {
+		Self::new(DEFAULT_DEBOUNCE)
+	}
+}
+
+impl FocusTracker {
+	/// Creates a tracker which reports a change once the focus has been stable for `debounce`.
+	#[must_use]
+	pub fn new(debounce: Duration) -> Self {
+		Self { debounce, focused: None, active_window: None, pending: None }
+	}
+
+	/// Registers all events the tracker consumes on `connection`.
+	///
+	/// # Errors
+	///
+	/// When any of the calls to [`AccessibilityConnection::register_event`] fail.
+	pub async fn register(connection: &AccessibilityConnection) -> Result<(), AtspiError> {
+		connection.register_event::<FocusEvents>().await?;
+		connection.register_event::<StateChangedEvent>().await?;
+		connection.register_event::<ActiveDescendantChangedEvent>().await?;
+		connection.register_event::<ActivateEvent>().await?;
+		connection.register_event::<DeactivateEvent>().await?;
+		Ok(())
+	}
+
+	/// The object which has focus, as last reported.
+	#[must_use]
+	pub fn focused(&self) -> Option<&Accessible> {
+		self.focused.as_ref()
+	}
+
+	/// The window which was activated last, unless it has been deactivated since.
+	#[must_use]
+	pub fn active_window(&self) -> Option<&Accessible> {
+		self.active_window.as_ref()
+	}
+
+	/// The root object of the application which has focus.
+	///
+	/// This is the application of the focused object or, when nothing has focus, of the active window.
+	#[must_use]
+	pub fn application(&self) -> Option<Accessible> {
+		self.focused
+			.as_ref()
+			.or(self.active_window.as_ref())
+			.map(application_of)
+	}
+
+	/// Updates the tracker with an event received at `now`.
+	///
+	/// Events unrelated to focus are ignored.
+	pub fn process(&mut self, event: &Event, now: Instant) {
+		match event {
+			Event::Focus(FocusEvents::Focus(ev)) => self.propose(ev.item.clone(), now),
+			Event::Object(ObjectEvents::StateChanged(ev)) if ev.state == State::Focused => {
+				if ev.enabled == 1 {
+					self.propose(ev.item.clone(), now);
+				} else if self.pending.as_ref().map(|(item, _)| item) == Some(&ev.item) {
+					self.pending = None;
+				}
+			}
+			Event::Object(ObjectEvents::ActiveDescendantChanged(ev)) => {
+				let container =
+					self.pending.as_ref().map(|(item, _)| item).or(self.focused.as_ref());
+				if container == Some(&ev.item) && !ev.child.is_null() {
+					self.propose(ev.child.clone(), now);
+				}
+			}
+			Event::Window(WindowEvents::Activate(ev)) => {
+				self.active_window = Some(ev.item.clone());
+			}
+			Event::Window(WindowEvents::Deactivate(ev))
+				if self.active_window.as_ref() == Some(&ev.item) =>
+			{
+				self.active_window = None;
+			}
+			_ => {}
+		}
+	}
+
+	/// The moment the pending focus candidate settles, if there is one.
+	#[must_use]
+	pub fn deadline(&self) -> Option<Instant> {
+		self.pending.as_ref().map(|(_, since)| *since + self.debounce)
+	}
+
+	/// Reports the pending focus change if it has been stable for the debounce period at `now`.
+	pub fn settle(&mut self, now: Instant) -> Option<FocusChanged> {
+		match self.deadline() {
+			Some(deadline) if deadline <= now => self.flush(),
+			_ => None,
+		}
+	}
+
+	/// Reports the pending focus change, regardless of how long it has been pending.
+	pub fn flush(&mut self) -> Option<FocusChanged> {
+		let (focused, _) = self.pending.take()?;
+		if self.focused.as_ref() == Some(&focused) {
+			return None;
+		}
+		let application = application_of(&focused);
+		let window = self
+			.active_window
+			.clone()
+			.filter(|window| window.name == focused.name);
+		let previous = self.focused.replace(focused.clone());
+		Some(FocusChanged { previous, focused, window, application })
+	}
+
+	/// Turns a stream of events into a stream of settled focus changes.
+	///
+	/// The returned stream ends when `events` does.
+	/// Errors in the event stream are skipped.
+	///
+	/// # Example
+	///
+	/// ```rust,no_run
+	/// use atspi_connection::{focus::FocusTracker, AccessibilityConnection};
+	/// use futures_lite::StreamExt;
+	///
+	/// # tokio_test::block_on(async {
+	/// let connection = AccessibilityConnection::open().await.unwrap();
+	/// FocusTracker::register(&connection).await.unwrap();
+	///
+	/// let changes = FocusTracker::default().changes(connection.event_stream());
+	/// futures_lite::pin!(changes);
+	/// while let Some(change) = changes.next().await {
+	///     println!("{} has focus", change.focused.path.as_str());
+	/// }
+	/// # })
+	/// ```
+	pub fn changes<S>(self, events: S) -> impl Stream<Item = FocusChanged>
+	where
+		S: Stream<Item = Result<Event, AtspiError>>,
+	{
+		debounce(self, events)
+	}
+
+	fn propose(&mut self, item: Accessible, now: Instant) {
+		if item.is_null() {
+			return;
+		}
+		match &self.pending {
+			Some((pending, _)) if *pending == item => {}
+			_ => self.pending = Some((item, now)),
+		}
+	}
+}
+
+impl Debounce for FocusTracker {
+	type Item = FocusChanged;
+
+	fn deadline(&self) -> Option<Instant> {
+		self.deadline()
+	}
+
+	fn process(
+		&mut self,
+		event: Result<Event, AtspiError>,
+		now: Instant,
+	) -> BoxFuture<'_, Vec<FocusChanged>> {
+		if let Ok(event) = event {
+			self.process(&event, now);
+		}
+		future::ready(Vec::new()).boxed()
+	}
+
+	fn settle(&mut self, now: Instant) -> BoxFuture<'_, Vec<FocusChanged>> {
+		future::ready(self.settle(now).into_iter().collect()).boxed()
+	}
+
+	fn flush(&mut self) -> BoxFuture<'_, Vec<FocusChanged>> {
+		future::ready(self.flush().into_iter().collect()).boxed()
+	}
+}
+
+fn application_of(item: &Accessible) -> Accessible {
+	Accessible {
+		name: item.name.clone(),
+		path: ROOT_PATH.try_into().expect("ROOT_PATH is a valid object path"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::FocusTracker;
+	use atspi_common::{
+		events::{
+			focus::FocusEvent,
+			object::{ActiveDescendantChangedEvent, StateChangedEvent},
+			window::ActivateEvent,
+			Event,
+		},
+		Accessible, State,
+	};
+	use std::time::{Duration, Instant};
+
+	fn object(name: &str, path: &str) -> Accessible {
+		Accessible { name: name.into(), path: path.try_into().unwrap() }
+	}
+
+	fn focus(item: &Accessible) -> Event {
+		FocusEvent { item: item.clone() }.into()
+	}
+
+	#[test]
+	fn reports_after_debounce() {
+		let mut tracker = FocusTracker::new(Duration::from_millis(50));
+		let start = Instant::now();
+		let button = object(":1.1", "/org/a11y/atspi/accessible/1");
+		tracker.process(&focus(&button), start);
+		assert_eq!(tracker.settle(start + Duration::from_millis(10)), None);
+		let change = tracker.settle(start + Duration::from_millis(50)).unwrap();
+		assert_eq!(change.focused, button);
+		assert_eq!(change.previous, None);
+		assert_eq!(change.application.path.as_str(), "/org/a11y/atspi/accessible/root");
+		assert_eq!(tracker.focused(), Some(&button));
+	}
+
+	#[test]
+	fn storms_collapse_into_one_change() {
+		let mut tracker = FocusTracker::new(Duration::from_millis(50));
+		let start = Instant::now();
+		let first = object(":1.1", "/org/a11y/atspi/accessible/1");
+		let second = object(":1.1", "/org/a11y/atspi/accessible/2");
+		tracker.process(&focus(&first), start);
+		tracker.process(&focus(&second), start + Duration::from_millis(5));
+		tracker.process(&focus(&first), start + Duration::from_millis(10));
+		assert_eq!(tracker.settle(start + Duration::from_millis(55)), None);
+		let change = tracker.settle(start + Duration::from_millis(60)).unwrap();
+		assert_eq!(change.focused, first);
+	}
+
+	#[test]
+	fn same_object_is_reported_once() {
+		let mut tracker = FocusTracker::new(Duration::ZERO);
+		let now = Instant::now();
+		let button = object(":1.1", "/org/a11y/atspi/accessible/1");
+		tracker.process(&focus(&button), now);
+		assert!(tracker.settle(now).is_some());
+		let state: Event =
+			StateChangedEvent { item: button.clone(), state: State::Focused, enabled: 1 }.into();
+		tracker.process(&state, now);
+		assert_eq!(tracker.settle(now), None);
+	}
+
+	#[test]
+	fn losing_focus_cancels_candidate() {
+		let mut tracker = FocusTracker::new(Duration::ZERO);
+		let now = Instant::now();
+		let button = object(":1.1", "/org/a11y/atspi/accessible/1");
+		let gained: Event =
+			StateChangedEvent { item: button.clone(), state: State::Focused, enabled: 1 }.into();
+		let lost: Event =
+			StateChangedEvent { item: button, state: State::Focused, enabled: 0 }.into();
+		tracker.process(&gained, now);
+		tracker.process(&lost, now);
+		assert_eq!(tracker.settle(now), None);
+	}
+
+	#[test]
+	fn active_descendant_of_focused_container() {
+		let mut tracker = FocusTracker::new(Duration::ZERO);
+		let now = Instant::now();
+		let window = object(":1.1", "/org/a11y/atspi/accessible/window");
+		let list = object(":1.1", "/org/a11y/atspi/accessible/list");
+		let row = object(":1.1", "/org/a11y/atspi/accessible/row");
+		tracker.process(&ActivateEvent { item: window.clone() }.into(), now);
+		tracker.process(&focus(&list), now);
+		tracker.settle(now).unwrap();
+
+		let descendant: Event =
+			ActiveDescendantChangedEvent { item: list.clone(), child: row.clone() }.into();
+		tracker.process(&descendant, now);
+		let change = tracker.settle(now).unwrap();
+		assert_eq!(change.focused, row);
+		assert_eq!(change.previous, Some(list));
+		assert_eq!(change.window, Some(window));
+	}
+
+	#[test]
+	fn active_descendant_of_unfocused_container_is_ignored() {
+		let mut tracker = FocusTracker::new(Duration::ZERO);
+		let now = Instant::now();
+		let list = object(":1.1", "/org/a11y/atspi/accessible/list");
+		let row = object(":1.1", "/org/a11y/atspi/accessible/row");
+		tracker.process(&ActiveDescendantChangedEvent { item: list, child: row }.into(), now);
+		assert_eq!(tracker.settle(now), None);
+	}
+}
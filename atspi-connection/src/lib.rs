@@ -1,14 +1,29 @@
-#[cfg(all(feature = "async-std", feature = "tokio"))]
-compile_error!("You may not mix the async-std and tokio features.");
+pub mod application;
+pub mod audit;
+pub mod blocking;
+pub mod coalesce;
+mod debounce;
+pub mod dispatch;
+pub mod filter;
+pub mod focus;
+pub mod live;
+#[cfg(test)]
+mod mock;
+pub mod name;
+pub mod relations;
+pub mod snapshot;
+pub mod socket;
+pub mod tree;
+pub mod window;
 
-#[cfg(all(not(feature = "async-std"), not(feature = "tokio")))]
-compile_error!("You must specify either the async-std or tokio feature.");
+pub use blocking::AccessibilityConnectionBlocking;
 
 use atspi_common::error::AtspiError;
 use atspi_common::events::{Event, GenericEvent, HasMatchRule, HasRegistryEventString};
 use atspi_proxies::{
 	bus::{BusProxy, StatusProxy},
 	registry::RegistryProxy,
+	timeout::CallGuard,
 };
 use futures_lite::stream::{Stream, StreamExt};
 use std::ops::Deref;
@@ -20,6 +35,7 @@ pub type AtspiResult<T> = std::result::Result<T, AtspiError>;
 pub struct AccessibilityConnection {
 	registry: RegistryProxy<'static>,
 	dbus_proxy: DBusProxy<'static>,
+	call_guard: CallGuard,
 }
 
 impl AccessibilityConnection {
@@ -65,18 +81,26 @@ impl AccessibilityConnection {
 		let bus = Box::pin(zbus::ConnectionBuilder::address(bus_addr)?.build()).await?;
 		#[cfg(feature = "tracing")]
 		tracing::debug!(name = bus.unique_name().map(|n| n.as_str()), "Connected to a11y bus");
+		Self::with_bus(bus).await
+	}
 
+	/// Wraps `bus`, an established connection to the accessibility bus.
+	pub(crate) async fn with_bus(bus: zbus::Connection) -> zbus::Result<Self> {
 		// The Proxy holds a strong reference to a Connection, so we only need to store the proxy
 		let registry = RegistryProxy::new(&bus).await?;
 		let dbus_proxy = DBusProxy::new(registry.connection()).await?;
 
-		Ok(Self { registry, dbus_proxy })
+		Ok(Self { registry, dbus_proxy, call_guard: CallGuard::default() })
 	}
 
 	/// Stream yielding all `Event` types.
 	///
 	/// Monitor this stream to be notified and receive events on the a11y bus.
 	///
+	/// Signals without a typed event, such as custom events of a toolkit, come through as
+	/// [`Event::Unknown`] rather than as errors. Signals of the bus itself, such as
+	/// `NameOwnerChanged`, are left out.
+	///
 	/// # Example
 	/// Basic use:
 	///
@@ -149,10 +173,7 @@ impl AccessibilityConnection {
 				Ok(m) => m,
 				Err(e) => return Some(Err(e.into())),
 			};
-			match msg.message_type() {
-				MessageType::Signal => Some(Event::try_from(&*msg)),
-				_ => None,
-			}
+			event_from(&msg)
 		})
 	}
 
@@ -189,7 +210,7 @@ impl AccessibilityConnection {
 	/// This function may return an error if a [`zbus::Error`] is caused by all the various calls to [`zbus::fdo::DBusProxy`] and [`zbus::MatchRule::try_from`].
 	pub async fn remove_match_rule<T: HasMatchRule>(&self) -> Result<(), AtspiError> {
 		let match_rule = MatchRule::try_from(<T as HasMatchRule>::MATCH_RULE_STRING)?;
-		self.dbus_proxy.add_match_rule(match_rule).await?;
+		self.dbus_proxy.remove_match_rule(match_rule).await?;
 		Ok(())
 	}
 
@@ -262,6 +283,24 @@ impl AccessibilityConnection {
 		Ok(())
 	}
 
+	/// The timeout and quarantine applied to the calls this connection makes into applications.
+	///
+	/// The helpers of this crate which do not go through the connection, such as
+	/// [`Snapshot::fetch`](crate::snapshot::Snapshot::fetch) or
+	/// [`audit`](crate::audit::audit), take a [`CallGuard`]; pass them this one. Use it to guard
+	/// your own calls with [`CallTimeoutExt::guarded`] as well, so they all share the quarantine.
+	///
+	/// [`CallTimeoutExt::guarded`]: atspi_proxies::timeout::CallTimeoutExt::guarded
+	#[must_use]
+	pub fn call_guard(&self) -> &CallGuard {
+		&self.call_guard
+	}
+
+	/// Replaces the timeout and quarantine applied to calls into applications.
+	pub fn set_call_guard(&mut self, call_guard: CallGuard) {
+		self.call_guard = call_guard;
+	}
+
 	/// Shorthand for a reference to the underlying [`zbus::Connection`]
 	#[must_use = "The reference to the underlying zbus::Connection must be used"]
 	pub fn connection(&self) -> &zbus::Connection {
@@ -303,6 +342,16 @@ impl Deref for AccessibilityConnection {
 	}
 }
 
+/// Decodes a signal to an [`Event`], skipping other messages and the signals of the bus itself.
+fn event_from(msg: &zbus::Message) -> Option<Result<Event, AtspiError>> {
+	if msg.message_type() != MessageType::Signal
+		|| matches!(msg.interface(), Some(interface) if interface == "org.freedesktop.DBus")
+	{
+		return None;
+	}
+	Some(Event::try_from(msg))
+}
+
 /// Set the `IsEnabled` property in the session bus.
 ///
 /// Assistive Technology provider applications (ATs) should set the accessibility
@@ -362,3 +411,37 @@ pub async fn read_session_accessibility() -> AtspiResult<bool> {
 	// Read the `IsEnabled` property.
 	status_proxy.is_enabled().await.map_err(Into::into)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::event_from;
+	use atspi_common::{events::Event, Accessible};
+	use zbus::MessageBuilder;
+
+	#[test]
+	fn skips_signals_of_the_bus() {
+		let message =
+			MessageBuilder::signal("/org/freedesktop/DBus", "org.freedesktop.DBus", "NameAcquired")
+				.unwrap()
+				.sender("org.freedesktop.DBus")
+				.unwrap()
+				.build(&":1.42")
+				.unwrap();
+		assert!(event_from(&message).is_none());
+	}
+
+	#[test]
+	fn decodes_other_signals() {
+		let message = MessageBuilder::signal(
+			"/org/a11y/atspi/accessible/null",
+			"org.a11y.atspi.Socket",
+			"Available",
+		)
+		.unwrap()
+		.sender(":0.0")
+		.unwrap()
+		.build(&Accessible::default())
+		.unwrap();
+		assert!(matches!(event_from(&message), Some(Ok(Event::Available(_)))));
+	}
+}
@@ -0,0 +1,637 @@
+//! Turns live region updates into announcements.
+//!
+//! Web content announces changes to live regions only implicitly: a `TextChanged` or
+//! `ChildrenChanged` event is emitted on an object whose object attributes mark it as part of
+//! a live region. [`LiveRegionMonitor`] looks those attributes up, decides what should be
+//! spoken according to the `live`, `relevant`, `atomic` and `busy` properties of the region,
+//! and reports it as a [`LiveAnnouncement`]. Explicit `Announcement` events are passed on as well.
+//!
+//! A single update of a page often causes several events for the same text, for example
+//! `ChildrenChanged` on a list and `TextChanged` on the new item. Updates of a region are
+//! therefore collected until the region has been quiet for the coalescing period, and text
+//! that is already part of the pending announcement is dropped.
+
+use crate::{
+	debounce::{debounce, Debounce},
+	AccessibilityConnection, AtspiResult,
+};
+use atspi_common::{
+	events::{
+		object::{
+			AnnouncementEvent, AttributesChangedEvent, ChildrenChangedEvent, ObjectEvents,
+			StateChangedEvent, TextChangedEvent,
+		},
+		Event,
+	},
+	object_attributes::{Live, Relevant},
+	Accessible, AtspiError, ObjectAttributes, State,
+};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	proxy_for,
+	text::TextProxy,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+use futures_lite::Stream;
+use futures_util::{future::BoxFuture, join, FutureExt};
+use std::{
+	collections::HashMap,
+	time::{Duration, Instant},
+};
+
+/// The coalescing period used by [`LiveRegionMonitor::new`].
+pub const DEFAULT_COALESCE: Duration = Duration::from_millis(100);
+
+/// At most this many ancestors are visited when looking for the root of a region.
+const MAX_REGION_DEPTH: usize = 32;
+
+/// At most this many levels of embedded objects are read for the text of a region.
+const MAX_TEXT_DEPTH: usize = 8;
+
+/// The number of objects whose region is remembered before the cache is cleared.
+const CACHE_LIMIT: usize = 1024;
+
+/// The politeness of an assertive `Announcement` event; polite ones carry 1.
+const ASSERTIVE: i32 = 2;
+
+/// The character standing in for an embedded object in the text of its parent.
+const EMBEDDED_OBJECT: char = '\u{fffc}';
+
+/// Text to announce.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LiveAnnouncement {
+	/// The text.
+	pub text: String,
+	/// How urgently to announce it; never [`Live::Off`].
+	pub priority: Live,
+	/// The root of the live region that changed, or the object that emitted an
+	/// `Announcement` event.
+	pub source: Accessible,
+}
+
+/// What is known about the live region an object belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Region {
+	root: Accessible,
+	live: Live,
+	relevant: Relevant,
+	atomic: bool,
+	busy: bool,
+}
+
+/// An announcement being collected.
+#[derive(Clone, Debug)]
+struct Pending {
+	root: Accessible,
+	priority: Live,
+	atomic: bool,
+	texts: Vec<String>,
+	last: Instant,
+}
+
+impl Pending {
+	/// Adds `text`, unless it is part of text already pending; drops pending text it contains.
+	fn add(&mut self, text: String) {
+		if text.is_empty() || self.texts.iter().any(|pending| pending.contains(&text)) {
+			return;
+		}
+		self.texts.retain(|pending| !text.contains(pending.as_str()));
+		self.texts.push(text);
+	}
+}
+
+/// Watches live regions and reports what should be announced; see the [module documentation](self).
+///
+/// Feed it events with [`LiveRegionMonitor::process`] and collect announcements with
+/// [`LiveRegionMonitor::settle`], or let [`LiveRegionMonitor::announcements`] do both on an
+/// event stream.
+#[derive(Debug)]
+pub struct LiveRegionMonitor {
+	conn: zbus::Connection,
+	guard: CallGuard,
+	coalesce: Duration,
+	regions: HashMap<Accessible, Option<Region>>,
+	pending: Vec<Pending>,
+}
+
+impl LiveRegionMonitor {
+	/// Creates a monitor reading object attributes over `conn`, subject to `guard`, with the
+	/// [`DEFAULT_COALESCE`] period.
+	#[must_use]
+	pub fn new(conn: zbus::Connection, guard: CallGuard) -> Self {
+		Self {
+			conn,
+			guard,
+			coalesce: DEFAULT_COALESCE,
+			regions: HashMap::new(),
+			pending: Vec::new(),
+		}
+	}
+
+	/// Announces the updates of a region once it has been quiet for `coalesce`.
+	#[must_use]
+	pub fn with_coalesce(mut self, coalesce: Duration) -> Self {
+		self.coalesce = coalesce;
+		self
+	}
+
+	/// Registers all events the monitor consumes on `connection`.
+	///
+	/// # Errors
+	///
+	/// When any of the calls to [`AccessibilityConnection::register_event`] fail.
+	pub async fn register(connection: &AccessibilityConnection) -> Result<(), AtspiError> {
+		connection.register_event::<TextChangedEvent>().await?;
+		connection.register_event::<ChildrenChangedEvent>().await?;
+		connection.register_event::<AnnouncementEvent>().await?;
+		connection.register_event::<AttributesChangedEvent>().await?;
+		connection.register_event::<StateChangedEvent>().await?;
+		Ok(())
+	}
+
+	/// Updates the monitor with an event received at `now`.
+	///
+	/// Events unrelated to live regions, and events on objects that cannot be read, are ignored.
+	pub async fn process(&mut self, event: &Event, now: Instant) {
+		let Event::Object(event) = event else { return };
+		match event {
+			ObjectEvents::Announcement(ev) => {
+				let region = Region {
+					root: ev.item.clone(),
+					live: if ev.politeness == ASSERTIVE { Live::Assertive } else { Live::Polite },
+					relevant: Relevant::default(),
+					atomic: false,
+					busy: false,
+				};
+				self.queue(&region, ev.text.trim().to_string(), now);
+			}
+			ObjectEvents::TextChanged(ev) => {
+				let Some(region) = self.region(&ev.item).await else { return };
+				let relevant = if ev.operation.starts_with("insert") {
+					region.relevant.text || region.relevant.additions
+				} else {
+					region.relevant.removals
+				};
+				if relevant {
+					self.queue(&region, ev.text.trim().to_string(), now);
+				}
+			}
+			ObjectEvents::ChildrenChanged(ev) if ev.operation.starts_with("add") => {
+				let Some(region) = self.region(&ev.item).await else { return };
+				if region.relevant.additions {
+					let text = if region.atomic {
+						String::new()
+					} else {
+						text_of(&self.conn, &self.guard, ev.child.clone(), 0).await
+					};
+					self.queue(&region, text, now);
+				}
+			}
+			// The attributes of any object may put its descendants into, or out of, a live region.
+			ObjectEvents::AttributesChanged(_) => self.regions.clear(),
+			ObjectEvents::StateChanged(ev) if ev.state == State::Busy => {
+				self.regions.retain(|item, region| {
+					*item != ev.item && !matches!(region, Some(region) if region.root == ev.item)
+				});
+			}
+			_ => {}
+		}
+	}
+
+	/// The moment the earliest pending announcement is due, if there is one.
+	#[must_use]
+	pub fn deadline(&self) -> Option<Instant> {
+		self.pending.iter().map(|pending| pending.last + self.coalesce).min()
+	}
+
+	/// Reports the announcements of regions which have been quiet for the coalescing period at `now`.
+	///
+	/// Assertive announcements come first.
+	pub async fn settle(&mut self, now: Instant) -> Vec<LiveAnnouncement> {
+		let coalesce = self.coalesce;
+		let (due, pending) = std::mem::take(&mut self.pending)
+			.into_iter()
+			.partition(|pending| pending.last + coalesce <= now);
+		self.pending = pending;
+		self.announce(due).await
+	}
+
+	/// Reports all pending announcements, regardless of how long they have been pending.
+	pub async fn flush(&mut self) -> Vec<LiveAnnouncement> {
+		let due = std::mem::take(&mut self.pending);
+		self.announce(due).await
+	}
+
+	/// Turns a stream of events into a stream of announcements.
+	///
+	/// The returned stream ends when `events` does.
+	/// Errors in the event stream are skipped.
+	///
+	/// # Example
+	///
+	/// ```rust,no_run
+	/// use atspi_connection::{live::LiveRegionMonitor, AccessibilityConnection};
+	/// use futures_lite::StreamExt;
+	///
+	/// # tokio_test::block_on(async {
+	/// let connection = AccessibilityConnection::open().await.unwrap();
+	/// LiveRegionMonitor::register(&connection).await.unwrap();
+	///
+	/// let monitor = LiveRegionMonitor::new(
+	///     connection.connection().clone(),
+	///     connection.call_guard().clone(),
+	/// );
+	/// let announcements = monitor.announcements(connection.event_stream());
+	/// futures_lite::pin!(announcements);
+	/// while let Some(announcement) = announcements.next().await {
+	///     println!("{:?}: {}", announcement.priority, announcement.text);
+	/// }
+	/// # })
+	/// ```
+	pub fn announcements<S>(self, events: S) -> impl Stream<Item = LiveAnnouncement>
+	where
+		S: Stream<Item = Result<Event, AtspiError>>,
+	{
+		debounce(self, events)
+	}
+
+	/// Adds an update of `region` to its pending announcement.
+	fn queue(&mut self, region: &Region, text: String, now: Instant) {
+		if region.live == Live::Off || region.busy || (text.is_empty() && !region.atomic) {
+			return;
+		}
+		let index = match self.pending.iter().position(|pending| pending.root == region.root) {
+			Some(index) => index,
+			None => {
+				self.pending.push(Pending {
+					root: region.root.clone(),
+					priority: region.live,
+					atomic: region.atomic,
+					texts: Vec::new(),
+					last: now,
+				});
+				self.pending.len() - 1
+			}
+		};
+		let pending = &mut self.pending[index];
+		pending.last = now;
+		pending.priority = pending.priority.max(region.live);
+		if !region.atomic {
+			pending.add(text);
+		}
+	}
+
+	async fn announce(&self, mut due: Vec<Pending>) -> Vec<LiveAnnouncement> {
+		due.sort_by_key(|pending| std::cmp::Reverse(pending.priority));
+		let mut announcements = Vec::with_capacity(due.len());
+		for pending in due {
+			let text = if pending.atomic {
+				text_of(&self.conn, &self.guard, pending.root.clone(), 0).await
+			} else {
+				pending.texts.join(" ")
+			};
+			if !text.is_empty() {
+				announcements.push(LiveAnnouncement {
+					text,
+					priority: pending.priority,
+					source: pending.root,
+				});
+			}
+		}
+		announcements
+	}
+
+	/// The live region `item` belongs to, if any.
+	///
+	/// Objects which cannot be read are taken to be outside of live regions, but are not
+	/// remembered as such.
+	async fn region(&mut self, item: &Accessible) -> Option<Region> {
+		if let Some(region) = self.regions.get(item) {
+			return region.clone();
+		}
+		let region = find_region(&self.conn, &self.guard, item).await.ok()?;
+		if self.regions.len() >= CACHE_LIMIT {
+			self.regions.clear();
+		}
+		self.regions.insert(item.clone(), region.clone());
+		region
+	}
+}
+
+impl Debounce for LiveRegionMonitor {
+	type Item = LiveAnnouncement;
+
+	fn deadline(&self) -> Option<Instant> {
+		self.deadline()
+	}
+
+	fn process(
+		&mut self,
+		event: Result<Event, AtspiError>,
+		now: Instant,
+	) -> BoxFuture<'_, Vec<LiveAnnouncement>> {
+		async move {
+			if let Ok(event) = event {
+				self.process(&event, now).await;
+			}
+			Vec::new()
+		}
+		.boxed()
+	}
+
+	fn settle(&mut self, now: Instant) -> BoxFuture<'_, Vec<LiveAnnouncement>> {
+		self.settle(now).boxed()
+	}
+
+	fn flush(&mut self) -> BoxFuture<'_, Vec<LiveAnnouncement>> {
+		self.flush().boxed()
+	}
+}
+
+/// What [`find_region`] reads of an object: the properties of its live region, and its parent.
+async fn read(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	item: &Accessible,
+) -> AtspiResult<(Option<Live>, Relevant, bool, bool, Accessible)> {
+	let proxy = proxy_for::<AccessibleProxy>(conn, item).await?;
+	let bus_name = item.name.as_str();
+	let (attributes, role, states, parent) = join!(
+		proxy.get_attributes().guarded(guard, bus_name),
+		proxy.get_role().guarded(guard, bus_name),
+		proxy.get_state().guarded(guard, bus_name),
+		proxy.parent().guarded(guard, bus_name),
+	);
+	let aria = ObjectAttributes::from(attributes?).aria(role?, states.unwrap_or_default());
+	Ok((aria.live, aria.relevant, aria.atomic, aria.busy, parent.unwrap_or_default()))
+}
+
+/// Finds the live region of `item`: its properties are those `item` reports, and its root is
+/// the topmost ancestor which is still part of a live region.
+///
+/// Fails only if `item` itself cannot be read.
+async fn find_region(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	item: &Accessible,
+) -> AtspiResult<Option<Region>> {
+	let (live, relevant, atomic, busy, mut parent) = read(conn, guard, item).await?;
+	let Some(live) = live else { return Ok(None) };
+	let mut root = item.clone();
+	for _ in 0..MAX_REGION_DEPTH {
+		if parent.is_null() || parent == root {
+			break;
+		}
+		match read(conn, guard, &parent).await {
+			Ok((Some(_), _, _, _, grandparent)) => {
+				root = std::mem::replace(&mut parent, grandparent);
+			}
+			_ => break,
+		}
+	}
+	Ok(Some(Region { root, live, relevant, atomic, busy }))
+}
+
+/// The text of `accessible`, with embedded objects replaced by their own text.
+///
+/// Objects without text content contribute their name or, if they have none, the text of
+/// their children.
+fn text_of<'a>(
+	conn: &'a zbus::Connection,
+	guard: &'a CallGuard,
+	accessible: Accessible,
+	depth: usize,
+) -> BoxFuture<'a, String> {
+	async move {
+		let bus_name = accessible.name.as_str();
+		let Ok(proxy) = proxy_for::<AccessibleProxy>(conn, &accessible).await else {
+			return String::new();
+		};
+		let content = match proxy_for::<TextProxy>(conn, &accessible).await {
+			Ok(text) => text.get_text(0, -1).guarded(guard, bus_name).await.ok(),
+			Err(_) => None,
+		};
+		let nested = depth < MAX_TEXT_DEPTH;
+		let text = match content {
+			Some(content) if content.contains(EMBEDDED_OBJECT) => {
+				let children = if nested {
+					proxy
+						.get_children()
+						.guarded(guard, bus_name)
+						.await
+						.unwrap_or_default()
+				} else {
+					Vec::new()
+				};
+				let mut children = children.into_iter();
+				let mut text = String::new();
+				for part in content.split_inclusive(EMBEDDED_OBJECT) {
+					match part.strip_suffix(EMBEDDED_OBJECT) {
+						Some(before) => {
+							text.push_str(before);
+							if let Some(child) = children.next() {
+								text.push_str(&text_of(conn, guard, child, depth + 1).await);
+							}
+						}
+						None => text.push_str(part),
+					}
+				}
+				text
+			}
+			Some(content) if !content.trim().is_empty() => content,
+			_ => {
+				let name = proxy.name().guarded(guard, bus_name).await.unwrap_or_default();
+				if !name.trim().is_empty() || !nested {
+					name
+				} else {
+					let children = proxy.get_children().guarded(guard, bus_name);
+					let children = children.await.unwrap_or_default();
+					let mut texts = Vec::with_capacity(children.len());
+					for child in children {
+						let text = text_of(conn, guard, child, depth + 1).await;
+						if !text.is_empty() {
+							texts.push(text);
+						}
+					}
+					texts.join(" ")
+				}
+			}
+		};
+		text.split_whitespace().collect::<Vec<_>>().join(" ")
+	}
+	.boxed()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+	use atspi_common::Role;
+	use futures_lite::{stream, StreamExt};
+
+	fn tree() -> Tree {
+		let mut tree = Tree::default();
+		tree.add(Node::new(Role::DocumentWeb, "").children(&[1, 3, 5, 7]));
+		// 1: a polite log with a message 2.
+		tree.add(
+			Node::new(Role::Log, "")
+				.attribute("container-live", "polite")
+				.children(&[2]),
+		);
+		tree.add(
+			Node::new(Role::Paragraph, "")
+				.attribute("container-live", "polite")
+				.text("Saved"),
+		);
+		// 3: an atomic status with an embedded count 4.
+		tree.add(
+			Node::new(Role::StatusBar, "")
+				.attribute("container-live", "polite")
+				.attribute("container-atomic", "true")
+				.text("Result \u{fffc} of 10")
+				.children(&[4]),
+		);
+		tree.add(
+			Node::new(Role::Static, "")
+				.attribute("container-live", "polite")
+				.attribute("container-atomic", "true")
+				.text("3"),
+		);
+		// 5: an alert.
+		tree.add(Node::new(Role::Notification, "").children(&[6]));
+		tree.add(Node::new(Role::Label, "Connection lost"));
+		// 7: not live at all.
+		tree.add(Node::new(Role::Paragraph, "").text("Quiet"));
+		tree
+	}
+
+	fn text_changed(item: usize, operation: &str, text: &str) -> Event {
+		TextChangedEvent {
+			item: mock::accessible(item),
+			operation: operation.into(),
+			start_pos: 0,
+			length: text.chars().count().try_into().unwrap(),
+			text: text.into(),
+		}
+		.into()
+	}
+
+	fn child_added(item: usize, child: usize) -> Event {
+		ChildrenChangedEvent {
+			item: mock::accessible(item),
+			operation: "add".into(),
+			index_in_parent: 0,
+			child: mock::accessible(child),
+		}
+		.into()
+	}
+
+	#[test]
+	fn coalesces_duplicate_updates() {
+		tokio_test::block_on(async {
+			let mut monitor = LiveRegionMonitor::new(tree().serve().await, CallGuard::default());
+			let start = Instant::now();
+			monitor.process(&child_added(1, 2), start).await;
+			monitor.process(&text_changed(2, "insert", "Saved"), start).await;
+			assert_eq!(monitor.deadline(), Some(start + DEFAULT_COALESCE));
+			assert_eq!(monitor.settle(start).await, []);
+
+			let announcements = monitor.settle(start + DEFAULT_COALESCE).await;
+			assert_eq!(
+				announcements,
+				[LiveAnnouncement {
+					text: "Saved".into(),
+					priority: Live::Polite,
+					source: mock::accessible(1),
+				}]
+			);
+			assert_eq!(monitor.deadline(), None);
+		});
+	}
+
+	#[test]
+	fn honours_atomic_and_relevant() {
+		tokio_test::block_on(async {
+			let mut monitor = LiveRegionMonitor::new(tree().serve().await, CallGuard::default());
+			let now = Instant::now();
+			monitor.process(&text_changed(4, "insert", "3"), now).await;
+			// Removals are not relevant by default.
+			monitor.process(&text_changed(2, "delete", "Saved"), now).await;
+			monitor.process(&text_changed(7, "insert", "Quiet"), now).await;
+
+			let announcements = monitor.flush().await;
+			assert_eq!(announcements.len(), 1);
+			assert_eq!(announcements[0].text, "Result 3 of 10");
+			assert_eq!(announcements[0].source, mock::accessible(3));
+		});
+	}
+
+	#[test]
+	fn assertive_announcements_come_first() {
+		tokio_test::block_on(async {
+			let mut monitor = LiveRegionMonitor::new(tree().serve().await, CallGuard::default());
+			let now = Instant::now();
+			let polite = AnnouncementEvent {
+				item: mock::accessible(7),
+				text: "Hello".into(),
+				politeness: 1,
+			};
+			monitor.process(&polite.into(), now).await;
+			monitor.process(&child_added(5, 6), now).await;
+			let assertive = AnnouncementEvent {
+				item: mock::accessible(0),
+				text: "Saving failed".into(),
+				politeness: ASSERTIVE,
+			};
+			monitor.process(&assertive.into(), now).await;
+
+			let announcements = monitor.flush().await;
+			let texts: Vec<(&str, Live)> =
+				announcements.iter().map(|a| (a.text.as_str(), a.priority)).collect();
+			assert_eq!(
+				texts,
+				[
+					("Connection lost", Live::Assertive),
+					("Saving failed", Live::Assertive),
+					("Hello", Live::Polite)
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn forgets_regions_that_change() {
+		tokio_test::block_on(async {
+			let mut monitor = LiveRegionMonitor::new(tree().serve().await, CallGuard::default());
+			let now = Instant::now();
+			monitor.process(&text_changed(2, "insert", "Saved"), now).await;
+			monitor.process(&text_changed(4, "insert", "3"), now).await;
+			monitor.process(&text_changed(7, "insert", "Quiet"), now).await;
+			assert!(monitor.regions.contains_key(&mock::accessible(2)));
+
+			// The log turning busy changes the region of its message, not that of the status.
+			let busy =
+				StateChangedEvent { item: mock::accessible(1), state: State::Busy, enabled: 1 };
+			monitor.process(&busy.into(), now).await;
+			assert!(!monitor.regions.contains_key(&mock::accessible(2)));
+			assert!(monitor.regions.contains_key(&mock::accessible(4)));
+
+			// Any object may become a live region, or stop being one.
+			let changed = AttributesChangedEvent { item: mock::accessible(0) };
+			monitor.process(&changed.into(), now).await;
+			assert!(monitor.regions.is_empty());
+		});
+	}
+
+	#[test]
+	fn streams_announcements() {
+		tokio_test::block_on(async {
+			let monitor = LiveRegionMonitor::new(tree().serve().await, CallGuard::default());
+			let events =
+				stream::iter([Ok(child_added(1, 2)), Ok(text_changed(2, "insert", "Saved"))]);
+			let announcements: Vec<_> = monitor.announcements(events).collect().await;
+			assert_eq!(announcements.len(), 1);
+			assert_eq!(announcements[0].text, "Saved");
+		});
+	}
+}
@@ -0,0 +1,735 @@
+//! An accessibility tree served from the test process itself.
+//!
+//! [`Tree::serve`] exposes the nodes over a peer-to-peer connection, so code that talks to
+//! applications can be tested without an accessibility bus. Like in applications, the root is
+//! served at [`ROOT_PATH`] as well.
+
+use atspi_common::{
+	accessible::ROOT_PATH, Accessible, CoordType, Interface, InterfaceSet, Layer, RelationType,
+	Role, State, StateSet,
+};
+use enumflags2::BitFlags;
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+use zbus::{
+	dbus_interface, fdo,
+	zvariant::{ObjectPath, OwnedObjectPath},
+	ConnectionBuilder, Guid,
+};
+
+/// The bus name every node in the tree claims to belong to.
+pub(crate) const BUS_NAME: &str = ":1.42";
+
+/// The row, column, row span and column span of a table cell.
+pub(crate) type Extents = (i32, i32, i32, i32);
+
+/// One object in a [`Tree`].
+#[derive(Clone, Debug)]
+pub(crate) struct Node {
+	pub role: Role,
+	pub name: String,
+	pub description: String,
+	pub states: StateSet,
+	pub attributes: HashMap<String, String>,
+	pub children: Vec<usize>,
+	pub relations: Vec<(RelationType, Vec<usize>)>,
+	pub text: Option<String>,
+	pub extents: Option<(i32, i32, i32, i32)>,
+	pub value: Option<f64>,
+	pub actions: Vec<String>,
+	/// The description of the image, for images.
+	pub image: Option<String>,
+	/// The number of rows and columns, for tables.
+	pub table: Option<(i32, i32)>,
+	/// The cells of a table, and where they are.
+	pub cells: Vec<(usize, Extents)>,
+	/// What `Table.GetColumnHeader` replies, by column.
+	pub column_headers: Vec<usize>,
+	/// What `TableCell.GetColumnHeaderCells` replies, for cells implementing `TableCell`.
+	pub header_cells: Option<Vec<usize>>,
+	/// What `GetIndexInParent` replies, if not the actual index.
+	pub index_in_parent: Option<i32>,
+	/// Properties of the `Accessible` interface that reply with an error, by their D-Bus name.
+	pub failing: Vec<&'static str>,
+}
+
+impl Node {
+	pub fn new(role: Role, name: &str) -> Self {
+		Self {
+			role,
+			name: name.to_string(),
+			description: String::new(),
+			states: StateSet::empty(),
+			attributes: HashMap::new(),
+			children: Vec::new(),
+			relations: Vec::new(),
+			text: None,
+			extents: None,
+			value: None,
+			actions: Vec::new(),
+			image: None,
+			table: None,
+			cells: Vec::new(),
+			column_headers: Vec::new(),
+			header_cells: None,
+			index_in_parent: None,
+			failing: Vec::new(),
+		}
+	}
+
+	pub fn description(mut self, description: &str) -> Self {
+		self.description = description.to_string();
+		self
+	}
+
+	pub fn attribute(mut self, key: &str, value: &str) -> Self {
+		self.attributes.insert(key.to_string(), value.to_string());
+		self
+	}
+
+	pub fn states(mut self, states: impl Into<BitFlags<State>>) -> Self {
+		self.states = StateSet::new(states);
+		self
+	}
+
+	pub fn children(mut self, children: &[usize]) -> Self {
+		self.children = children.to_vec();
+		self
+	}
+
+	pub fn text(mut self, text: &str) -> Self {
+		self.text = Some(text.to_string());
+		self
+	}
+
+	pub fn extents(mut self, extents: (i32, i32, i32, i32)) -> Self {
+		self.extents = Some(extents);
+		self
+	}
+
+	pub fn value(mut self, value: f64) -> Self {
+		self.value = Some(value);
+		self
+	}
+
+	pub fn action(mut self, name: &str) -> Self {
+		self.actions.push(name.to_string());
+		self
+	}
+
+	pub fn relation(mut self, relation: RelationType, targets: &[usize]) -> Self {
+		self.relations.push((relation, targets.to_vec()));
+		self
+	}
+
+	pub fn image(mut self, description: &str) -> Self {
+		self.image = Some(description.to_string());
+		self
+	}
+
+	pub fn table(mut self, rows: i32, columns: i32) -> Self {
+		self.table = Some((rows, columns));
+		self
+	}
+
+	/// Places node `cell` at `row` and `column` of the table, spanning `spans` rows and columns.
+	pub fn cell(mut self, cell: usize, row: i32, column: i32, spans: (i32, i32)) -> Self {
+		self.cells.push((cell, (row, column, spans.0, spans.1)));
+		self
+	}
+
+	pub fn column_headers(mut self, headers: &[usize]) -> Self {
+		self.column_headers = headers.to_vec();
+		self
+	}
+
+	pub fn header_cells(mut self, headers: &[usize]) -> Self {
+		self.header_cells = Some(headers.to_vec());
+		self
+	}
+
+	pub fn index_in_parent(mut self, index: i32) -> Self {
+		self.index_in_parent = Some(index);
+		self
+	}
+
+	pub fn failing(mut self, property: &'static str) -> Self {
+		self.failing.push(property);
+		self
+	}
+
+	fn interfaces(&self) -> InterfaceSet {
+		let mut interfaces = InterfaceSet::new(Interface::Accessible);
+		if self.text.is_some() {
+			interfaces.insert(Interface::Text);
+		}
+		if self.extents.is_some() {
+			interfaces.insert(Interface::Component);
+		}
+		if self.value.is_some() {
+			interfaces.insert(Interface::Value);
+		}
+		if !self.actions.is_empty() {
+			interfaces.insert(Interface::Action);
+		}
+		if self.image.is_some() {
+			interfaces.insert(Interface::Image);
+		}
+		if self.table.is_some() {
+			interfaces.insert(Interface::Table);
+		}
+		if self.header_cells.is_some() {
+			interfaces.insert(Interface::TableCell);
+		}
+		interfaces
+	}
+
+	fn check(&self, property: &str) -> fdo::Result<()> {
+		if self.failing.contains(&property) {
+			return Err(fdo::Error::Failed(format!("{property} is broken")));
+		}
+		Ok(())
+	}
+}
+
+/// Nodes, referring to each other by index. Node 0 is the root of the application.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Tree {
+	nodes: Vec<Node>,
+	grabs: Arc<Mutex<Vec<usize>>>,
+}
+
+pub(crate) fn accessible(index: usize) -> Accessible {
+	Accessible {
+		name: BUS_NAME.to_string(),
+		path: OwnedObjectPath::try_from(format!("/org/a11y/atspi/accessible/{index}")).unwrap(),
+	}
+}
+
+/// An interface serving nothing, to have the object server of a connection started while it
+/// is built; an object server started later may miss the first calls.
+struct Served;
+
+#[dbus_interface(name = "org.a11y.atspi.Test")]
+impl Served {}
+
+/// Two connections to each other, each side able to serve objects to the other.
+pub(crate) async fn peers() -> (zbus::Connection, zbus::Connection) {
+	#[cfg(not(feature = "tokio"))]
+	let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+	#[cfg(feature = "tokio")]
+	let (client, server) = tokio::net::UnixStream::pair().unwrap();
+
+	let guid = Guid::generate();
+	let server = ConnectionBuilder::unix_stream(server).server(&guid).p2p();
+	let server = server.serve_at("/", Served).unwrap().build();
+	let client = ConnectionBuilder::unix_stream(client).p2p();
+	let client = client.serve_at("/", Served).unwrap().build();
+	let (client, server) = futures_lite::future::zip(client, server).await;
+	(client.unwrap(), server.unwrap())
+}
+
+/// A connection to a peer which never replies, like a hung application.
+///
+/// The peer's connection is returned as well; dropping it would close the socket.
+pub(crate) async fn unresponsive() -> (zbus::Connection, zbus::Connection) {
+	#[cfg(not(feature = "tokio"))]
+	let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+	#[cfg(feature = "tokio")]
+	let (client, server) = tokio::net::UnixStream::pair().unwrap();
+
+	let guid = Guid::generate();
+	let server = ConnectionBuilder::unix_stream(server).server(&guid).p2p().build();
+	let client = ConnectionBuilder::unix_stream(client).p2p().build();
+	let (client, server) = futures_lite::future::zip(client, server).await;
+	(client.unwrap(), server.unwrap())
+}
+
+impl Tree {
+	/// Adds a node and returns its index.
+	pub fn add(&mut self, node: Node) -> usize {
+		self.nodes.push(node);
+		self.nodes.len() - 1
+	}
+
+	/// The nodes `GrabFocus` is called on once served, in order.
+	pub fn grabs(&self) -> Arc<Mutex<Vec<usize>>> {
+		Arc::clone(&self.grabs)
+	}
+
+	/// Serves the tree, returning the client side of the connection.
+	///
+	/// The server lives for as long as the client connection does.
+	pub async fn serve(self) -> zbus::Connection {
+		#[cfg(not(feature = "tokio"))]
+		let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+		#[cfg(feature = "tokio")]
+		let (client, server) = tokio::net::UnixStream::pair().unwrap();
+
+		let guid = Guid::generate();
+		let nodes = Arc::new(self.nodes);
+		let mut server = ConnectionBuilder::unix_stream(server).server(&guid).p2p();
+		for (index, node) in nodes.iter().enumerate() {
+			// Interfaces registered on the builder are served as soon as the connection is up.
+			let path: ObjectPath<'_> = accessible(index).path.into_inner();
+			let object =
+				Object { nodes: Arc::clone(&nodes), grabs: Arc::clone(&self.grabs), index };
+			server = server.serve_at(path.clone(), object.clone()).unwrap();
+			if index == 0 {
+				server = server.serve_at(ROOT_PATH, object.clone()).unwrap();
+			}
+			if node.text.is_some() {
+				server = server.serve_at(path.clone(), Text(object.clone())).unwrap();
+			}
+			if node.extents.is_some() {
+				server = server.serve_at(path.clone(), Component(object.clone())).unwrap();
+			}
+			if node.value.is_some() {
+				server = server.serve_at(path.clone(), Value(object.clone())).unwrap();
+			}
+			if !node.actions.is_empty() {
+				server = server.serve_at(path.clone(), Action(object.clone())).unwrap();
+			}
+			if node.image.is_some() {
+				server = server.serve_at(path.clone(), Image(object.clone())).unwrap();
+			}
+			if node.header_cells.is_some() {
+				server = server.serve_at(path.clone(), TableCell(object.clone())).unwrap();
+			}
+			if node.table.is_some() {
+				server = server.serve_at(path, Table(object)).unwrap();
+			}
+		}
+		let client = ConnectionBuilder::unix_stream(client).p2p().build();
+		let (client, server) = futures_lite::future::zip(client, server.build()).await;
+		let (client, server) = (client.unwrap(), server.unwrap());
+		// Dropping the server connection would close the socket; keep it for the rest of the test run.
+		std::mem::forget(server);
+		client
+	}
+}
+
+#[derive(Clone)]
+struct Object {
+	nodes: Arc<Vec<Node>>,
+	grabs: Arc<Mutex<Vec<usize>>>,
+	index: usize,
+}
+
+impl Object {
+	fn node(&self) -> &Node {
+		&self.nodes[self.index]
+	}
+
+	fn parent_index(&self) -> Option<usize> {
+		self.nodes.iter().position(|node| node.children.contains(&self.index))
+	}
+}
+
+#[dbus_interface(name = "org.a11y.atspi.Accessible")]
+impl Object {
+	fn get_application(&self) -> Accessible {
+		accessible(0)
+	}
+
+	fn get_attributes(&self) -> fdo::Result<HashMap<String, String>> {
+		self.node().check("GetAttributes")?;
+		Ok(self.node().attributes.clone())
+	}
+
+	fn get_child_at_index(&self, index: i32) -> fdo::Result<Accessible> {
+		let child = usize::try_from(index)
+			.ok()
+			.and_then(|index| self.node().children.get(index));
+		child
+			.map(|&child| accessible(child))
+			.ok_or_else(|| fdo::Error::InvalidArgs(format!("no child at {index}")))
+	}
+
+	fn get_children(&self) -> Vec<Accessible> {
+		self.node().children.iter().map(|&child| accessible(child)).collect()
+	}
+
+	fn get_index_in_parent(&self) -> i32 {
+		if let Some(index) = self.node().index_in_parent {
+			return index;
+		}
+		self.parent_index()
+			.and_then(|parent| self.nodes[parent].children.iter().position(|&c| c == self.index))
+			.map_or(-1, |index| i32::try_from(index).unwrap())
+	}
+
+	fn get_interfaces(&self) -> InterfaceSet {
+		self.node().interfaces()
+	}
+
+	fn get_relation_set(&self) -> Vec<(RelationType, Vec<Accessible>)> {
+		let relation = |(relation, targets): &(RelationType, Vec<usize>)| {
+			(*relation, targets.iter().map(|&target| accessible(target)).collect())
+		};
+		self.node().relations.iter().map(relation).collect()
+	}
+
+	fn get_role(&self) -> fdo::Result<Role> {
+		self.node().check("GetRole")?;
+		Ok(self.node().role)
+	}
+
+	fn get_role_name(&self) -> String {
+		self.node().role.name().to_string()
+	}
+
+	fn get_localized_role_name(&self) -> String {
+		self.node().role.name().to_string()
+	}
+
+	fn get_state(&self) -> fdo::Result<StateSet> {
+		self.node().check("GetState")?;
+		Ok(self.node().states)
+	}
+
+	#[dbus_interface(property)]
+	fn name(&self) -> fdo::Result<String> {
+		self.node().check("Name")?;
+		Ok(self.node().name.clone())
+	}
+
+	#[dbus_interface(property)]
+	fn description(&self) -> fdo::Result<String> {
+		self.node().check("Description")?;
+		Ok(self.node().description.clone())
+	}
+
+	#[dbus_interface(property)]
+	fn parent(&self) -> (String, OwnedObjectPath) {
+		let parent = self.parent_index().map_or_else(Accessible::default, accessible);
+		(parent.name, parent.path)
+	}
+
+	#[dbus_interface(property)]
+	fn child_count(&self) -> i32 {
+		i32::try_from(self.node().children.len()).unwrap()
+	}
+
+	#[dbus_interface(property)]
+	fn locale(&self) -> String {
+		"en_US".to_string()
+	}
+
+	#[dbus_interface(property)]
+	fn accessible_id(&self) -> fdo::Result<String> {
+		self.node().check("AccessibleId")?;
+		Ok(format!("node-{}", self.index))
+	}
+}
+
+struct Text(Object);
+
+#[dbus_interface(name = "org.a11y.atspi.Text")]
+impl Text {
+	fn get_text(&self, start_offset: i32, end_offset: i32) -> String {
+		let text: Vec<char> = self.0.node().text.as_deref().unwrap_or_default().chars().collect();
+		let clamp = |offset: i32| usize::try_from(offset).map_or(text.len(), |o| o.min(text.len()));
+		let (start, end) = (clamp(start_offset), clamp(end_offset));
+		text[start.min(end)..end].iter().collect()
+	}
+
+	#[dbus_interface(property)]
+	fn character_count(&self) -> i32 {
+		let count = self.0.node().text.as_deref().unwrap_or_default().chars().count();
+		i32::try_from(count).unwrap()
+	}
+
+	#[dbus_interface(property)]
+	fn caret_offset(&self) -> i32 {
+		0
+	}
+}
+
+struct Component(Object);
+
+#[dbus_interface(name = "org.a11y.atspi.Component")]
+impl Component {
+	fn get_extents(&self, _coord_type: CoordType) -> (i32, i32, i32, i32) {
+		self.0.node().extents.unwrap_or_default()
+	}
+
+	fn get_layer(&self) -> Layer {
+		Layer::Widget
+	}
+
+	fn get_alpha(&self) -> f64 {
+		1.0
+	}
+
+	fn grab_focus(&self) -> fdo::Result<bool> {
+		self.0.node().check("GrabFocus")?;
+		self.0.grabs.lock().unwrap().push(self.0.index);
+		Ok(true)
+	}
+}
+
+struct Value(Object);
+
+#[dbus_interface(name = "org.a11y.atspi.Value")]
+impl Value {
+	#[dbus_interface(property)]
+	fn current_value(&self) -> f64 {
+		self.0.node().value.unwrap_or_default()
+	}
+
+	#[dbus_interface(property)]
+	fn minimum_value(&self) -> f64 {
+		0.0
+	}
+
+	#[dbus_interface(property)]
+	fn maximum_value(&self) -> f64 {
+		100.0
+	}
+
+	#[dbus_interface(property)]
+	fn minimum_increment(&self) -> f64 {
+		1.0
+	}
+}
+
+struct Action(Object);
+
+#[dbus_interface(name = "org.a11y.atspi.Action")]
+impl Action {
+	fn get_actions(&self) -> Vec<(String, String, String)> {
+		let action = |name: &String| (name.clone(), String::new(), String::new());
+		self.0.node().actions.iter().map(action).collect()
+	}
+
+	#[dbus_interface(property, name = "NActions")]
+	fn nactions(&self) -> i32 {
+		i32::try_from(self.0.node().actions.len()).unwrap()
+	}
+}
+
+struct Image(Object);
+
+#[dbus_interface(name = "org.a11y.atspi.Image")]
+impl Image {
+	fn get_image_extents(&self, _coord_type: CoordType) -> (i32, i32, i32, i32) {
+		self.0.node().extents.unwrap_or_default()
+	}
+
+	#[dbus_interface(property)]
+	fn image_description(&self) -> String {
+		self.0.node().image.clone().unwrap_or_default()
+	}
+
+	#[dbus_interface(property)]
+	fn image_locale(&self) -> String {
+		"en_US".to_string()
+	}
+}
+
+struct Table(Object);
+
+#[dbus_interface(name = "org.a11y.atspi.Table")]
+impl Table {
+	fn get_accessible_at(&self, row: i32, column: i32) -> Accessible {
+		self.cell_at(row, column)
+			.map_or_else(Accessible::default, |(_, (cell, _))| accessible(cell))
+	}
+
+	fn get_index_at(&self, row: i32, column: i32) -> i32 {
+		self.cell_at(row, column)
+			.map_or(-1, |(index, _)| i32::try_from(index).unwrap())
+	}
+
+	fn get_row_column_extents_at_index(&self, index: i32) -> (bool, i32, i32, i32, i32, bool) {
+		let cell = usize::try_from(index)
+			.ok()
+			.and_then(|index| self.0.node().cells.get(index));
+		match cell {
+			Some(&(_, (row, column, row_span, column_span))) => {
+				(true, row, column, row_span, column_span, false)
+			}
+			None => (false, 0, 0, 0, 0, false),
+		}
+	}
+
+	fn get_row_extent_at(&self, row: i32, column: i32) -> i32 {
+		self.cell_at(row, column)
+			.map_or(0, |(_, (_, (_, _, row_span, _)))| row_span)
+	}
+
+	fn get_column_extent_at(&self, row: i32, column: i32) -> i32 {
+		self.cell_at(row, column)
+			.map_or(0, |(_, (_, (_, _, _, column_span)))| column_span)
+	}
+
+	fn get_column_header(&self, column: i32) -> Accessible {
+		let header = usize::try_from(column)
+			.ok()
+			.and_then(|column| self.0.node().column_headers.get(column));
+		header.map_or_else(Accessible::default, |&header| accessible(header))
+	}
+
+	fn get_row_header(&self, _row: i32) -> Accessible {
+		Accessible::default()
+	}
+
+	#[dbus_interface(property, name = "NRows")]
+	fn nrows(&self) -> i32 {
+		self.0.node().table.unwrap_or_default().0
+	}
+
+	#[dbus_interface(property, name = "NColumns")]
+	fn ncolumns(&self) -> i32 {
+		self.0.node().table.unwrap_or_default().1
+	}
+
+	#[dbus_interface(property)]
+	fn caption(&self) -> (String, OwnedObjectPath) {
+		let null = Accessible::default();
+		(null.name, null.path)
+	}
+
+	#[dbus_interface(property)]
+	fn summary(&self) -> (String, OwnedObjectPath) {
+		let null = Accessible::default();
+		(null.name, null.path)
+	}
+}
+
+impl Table {
+	/// The index and placement of the cell covering `row` and `column`.
+	fn cell_at(&self, row: i32, column: i32) -> Option<(usize, (usize, Extents))> {
+		let covers = |(_, (r, c, row_span, column_span)): &&(usize, Extents)| {
+			(*r..r + row_span).contains(&row) && (*c..c + column_span).contains(&column)
+		};
+		let cells = &self.0.node().cells;
+		cells
+			.iter()
+			.position(|cell| covers(&cell))
+			.map(|index| (index, cells[index]))
+	}
+}
+
+struct TableCell(Object);
+
+#[dbus_interface(name = "org.a11y.atspi.TableCell")]
+impl TableCell {
+	fn get_column_header_cells(&self) -> Vec<Accessible> {
+		let headers = self.0.node().header_cells.iter().flatten();
+		headers.map(|&header| accessible(header)).collect()
+	}
+}
+
+/// `TableModel` lives in `atspi-proxies`, which cannot serve a tree; it is tested against one here.
+mod table_model_tests {
+	use super::*;
+	use atspi_common::Role;
+	use atspi_proxies::{
+		proxy_for,
+		table::TableProxy,
+		table_model::{Cell, Direction, TableModel},
+	};
+
+	/// A 3 by 3 table:
+	///
+	/// ```text
+	/// | 1     1 | 2 |
+	/// | 3 | 4   | 5 |
+	/// | 3 | 6   | 7 |
+	/// ```
+	///
+	/// Nodes 8 to 10 head the columns; node 11 heads the cells of node 4, which implements `TableCell`.
+	fn tree() -> Tree {
+		let mut tree = Tree::default();
+		tree.add(
+			Node::new(Role::Table, "")
+				.children(&[1, 2, 3, 4, 5, 6, 7])
+				.table(3, 3)
+				.cell(1, 0, 0, (1, 2))
+				.cell(2, 0, 2, (1, 1))
+				.cell(3, 1, 0, (2, 1))
+				.cell(4, 1, 1, (1, 1))
+				.cell(5, 1, 2, (1, 1))
+				.cell(6, 2, 1, (1, 1))
+				.cell(7, 2, 2, (1, 1))
+				.column_headers(&[8, 9, 10]),
+		);
+		for _ in 1..=3 {
+			tree.add(Node::new(Role::TableCell, ""));
+		}
+		tree.add(Node::new(Role::TableCell, "").header_cells(&[11]));
+		for _ in 5..=7 {
+			tree.add(Node::new(Role::TableCell, ""));
+		}
+		for _ in 8..=11 {
+			tree.add(Node::new(Role::ColumnHeader, ""));
+		}
+		tree
+	}
+
+	fn cell(node: usize, row: i32, column: i32, row_span: i32, column_span: i32) -> Cell {
+		Cell { accessible: accessible(node), row, column, row_span, column_span }
+	}
+
+	#[test]
+	fn navigates_across_spans() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let table: TableProxy = proxy_for(&conn, &accessible(0)).await.unwrap();
+			let mut model = TableModel::new(table);
+			assert_eq!(model.dimensions().await.unwrap(), (3, 3));
+
+			let first = model.cell_at(0, 1).await.unwrap().unwrap();
+			assert_eq!(first, cell(1, 0, 0, 1, 2));
+			let second = model.neighbor(&first, Direction::Right).await.unwrap().unwrap();
+			assert_eq!(second, cell(2, 0, 2, 1, 1));
+			assert_eq!(
+				model.neighbor(&second, Direction::Left).await.unwrap(),
+				Some(first.clone())
+			);
+			assert_eq!(model.neighbor(&second, Direction::Right).await.unwrap(), None);
+
+			let tall = model.neighbor(&first, Direction::Down).await.unwrap().unwrap();
+			assert_eq!(tall, cell(3, 1, 0, 2, 1));
+			assert_eq!(model.neighbor(&tall, Direction::Down).await.unwrap(), None);
+			let bottom = model.cell_at(2, 1).await.unwrap().unwrap();
+			assert_eq!(model.neighbor(&bottom, Direction::Left).await.unwrap(), Some(tall));
+			assert_eq!(
+				model.neighbor(&bottom, Direction::Up).await.unwrap(),
+				Some(cell(4, 1, 1, 1, 1))
+			);
+
+			let row: Vec<_> = model
+				.row(2)
+				.await
+				.unwrap()
+				.into_iter()
+				.map(|c| c.accessible)
+				.collect();
+			assert_eq!(row, [accessible(3), accessible(6), accessible(7)]);
+		});
+	}
+
+	#[test]
+	fn column_headers_fall_back_to_the_table() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let table: TableProxy = proxy_for(&conn, &accessible(0)).await.unwrap();
+			let mut model = TableModel::new(table);
+
+			// Node 1 does not implement `TableCell`: the table heads both columns it spans.
+			let spanning = model.cell_at(0, 0).await.unwrap().unwrap();
+			assert_eq!(
+				model.column_headers(&spanning).await.unwrap(),
+				[accessible(8), accessible(9)]
+			);
+			// Node 4 does, and its own headers win over the table's.
+			let own = model.cell_at(1, 1).await.unwrap().unwrap();
+			assert_eq!(model.column_headers(&own).await.unwrap(), [accessible(11)]);
+		});
+	}
+}
@@ -0,0 +1,401 @@
+//! Computes the name and description an assistive technology should present for an object.
+//!
+//! Many widgets leave their `Name` property empty and rely on the reader deriving one.
+//! [`compute_name`] tries the following sources in order and stops at the first that yields text:
+//!
+//! 1. the `Name` property;
+//! 2. the objects the object is [`RelationType::LabelledBy`];
+//! 3. the text content of the object, for roles that are
+//!    [named from their content](Role::names_from_content), such as [`Role::Heading`];
+//! 4. the names of the children, for buttons and links;
+//! 5. the description of the image, for objects implementing `Image`;
+//! 6. the tooltips of the object: children with [`Role::ToolTip`], and
+//!    [`RelationType::DescribedBy`] targets with that role.
+//!
+//! [`compute_description`] does the same with the `Description` property, the objects the
+//! object is [`RelationType::DescribedBy`] and its tooltips.
+//!
+//! Both return a [`ComputedText`] which keeps every part of the text together with the object
+//! and the [`Source`] it was read from, so a reader can tell a label apart from a tooltip.
+//! All calls are subject to the [`CallGuard`] passed in.
+
+use crate::{relations::text_of, AtspiResult};
+use atspi_common::{Accessible, RelationSet, RelationType, Role};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	image::ImageProxy,
+	proxy_for,
+	text::TextProxy,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+use futures_util::{future::join_all, join};
+
+/// Where a part of a [`ComputedText`] was read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Source {
+	/// The `Name` property.
+	Name,
+	/// The `Description` property.
+	Description,
+	/// A target of [`RelationType::LabelledBy`].
+	LabelledBy,
+	/// A target of [`RelationType::DescribedBy`].
+	DescribedBy,
+	/// The text content of the object.
+	Content,
+	/// The name of a child.
+	Children,
+	/// The `ImageDescription` property.
+	ImageDescription,
+	/// A tooltip of the object.
+	Tooltip,
+}
+
+/// One piece of a computed name or description.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Part {
+	/// Where the text was read from.
+	pub source: Source,
+	/// The object the text was read from; a label or child rather than the object itself
+	/// for some sources.
+	pub accessible: Accessible,
+	/// The text, with surrounding whitespace removed. Never empty.
+	pub text: String,
+}
+
+/// A name or description, made of the [`Part`]s it was assembled from.
+///
+/// All parts come from the same [`Source`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ComputedText {
+	/// The parts, in the order they are presented.
+	pub parts: Vec<Part>,
+}
+
+impl ComputedText {
+	/// Whether no source yielded any text.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.parts.is_empty()
+	}
+
+	/// The source the text was read from, or `None` if it is empty.
+	#[must_use]
+	pub fn source(&self) -> Option<Source> {
+		self.parts.first().map(|part| part.source)
+	}
+
+	/// The text of all parts, separated by spaces.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let texts: Vec<&str> = self.parts.iter().map(|part| part.text.as_str()).collect();
+		texts.join(" ")
+	}
+}
+
+impl std::fmt::Display for ComputedText {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.text())
+	}
+}
+
+/// Computes the name of `accessible`; see the [module documentation](self) for the order
+/// in which sources are tried.
+///
+/// Sources other than the `Name` property that cannot be read are skipped.
+/// The result is empty if no source yields any text.
+///
+/// # Errors
+///
+/// When the `Name` property cannot be read, usually because the object no longer exists.
+pub async fn compute_name(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> AtspiResult<ComputedText> {
+	let proxy = proxy_for::<AccessibleProxy>(conn, accessible).await?;
+	let bus_name = accessible.name.as_str();
+	let (name, role, relations) = join!(
+		proxy.name().guarded(guard, bus_name),
+		proxy.get_role().guarded(guard, bus_name),
+		proxy.get_relation_set().guarded(guard, bus_name),
+	);
+	if let Some(part) = part(Source::Name, accessible, &name?) {
+		return Ok(ComputedText { parts: vec![part] });
+	}
+	let relations = RelationSet::from(relations.unwrap_or_default());
+	let role = role.ok();
+
+	let labels =
+		related(conn, guard, &relations, RelationType::LabelledBy, Source::LabelledBy).await;
+	if !labels.is_empty() {
+		return Ok(labels);
+	}
+	if matches!(role, Some(role) if role.names_from_content()) {
+		let content = content(conn, guard, accessible).await;
+		if !content.is_empty() {
+			return Ok(content);
+		}
+	}
+	if matches!(
+		role,
+		Some(Role::PushButton | Role::ToggleButton | Role::PushButtonMenu | Role::Link)
+	) {
+		let children = child_names(conn, guard, accessible, &proxy).await;
+		if !children.is_empty() {
+			return Ok(children);
+		}
+	}
+	let image = image_description(conn, guard, accessible).await;
+	if !image.is_empty() {
+		return Ok(image);
+	}
+	Ok(tooltips(conn, guard, accessible, &proxy, &relations).await)
+}
+
+/// Computes the description of `accessible` from, in order, the `Description` property,
+/// the objects it is [`RelationType::DescribedBy`] and its tooltips.
+///
+/// Sources other than the `Description` property that cannot be read are skipped.
+/// The result is empty if no source yields any text.
+///
+/// # Errors
+///
+/// When the `Description` property cannot be read, usually because the object no longer exists.
+pub async fn compute_description(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> AtspiResult<ComputedText> {
+	let proxy = proxy_for::<AccessibleProxy>(conn, accessible).await?;
+	let bus_name = accessible.name.as_str();
+	let (description, relations) = join!(
+		proxy.description().guarded(guard, bus_name),
+		proxy.get_relation_set().guarded(guard, bus_name),
+	);
+	if let Some(part) = part(Source::Description, accessible, &description?) {
+		return Ok(ComputedText { parts: vec![part] });
+	}
+	let relations = RelationSet::from(relations.unwrap_or_default());
+	let described =
+		related(conn, guard, &relations, RelationType::DescribedBy, Source::DescribedBy).await;
+	if !described.is_empty() {
+		return Ok(described);
+	}
+	Ok(tooltips(conn, guard, accessible, &proxy, &relations).await)
+}
+
+fn part(source: Source, accessible: &Accessible, text: &str) -> Option<Part> {
+	let text = text.trim();
+	(!text.is_empty()).then(|| Part {
+		source,
+		accessible: accessible.clone(),
+		text: text.to_string(),
+	})
+}
+
+async fn related(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	relations: &RelationSet,
+	relation: RelationType,
+	source: Source,
+) -> ComputedText {
+	let targets: Vec<&Accessible> = relations.targets(relation).collect();
+	let texts = join_all(targets.iter().map(|target| text_of(conn, guard, target))).await;
+	let parts = targets
+		.into_iter()
+		.zip(texts)
+		.filter_map(|(target, text)| part(source, target, &text?))
+		.collect();
+	ComputedText { parts }
+}
+
+async fn content(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> ComputedText {
+	let text = match proxy_for::<TextProxy>(conn, accessible).await {
+		Ok(proxy) => proxy
+			.get_text(0, -1)
+			.guarded(guard, &accessible.name)
+			.await
+			.unwrap_or_default(),
+		Err(_) => String::new(),
+	};
+	ComputedText { parts: part(Source::Content, accessible, &text).into_iter().collect() }
+}
+
+/// The names of the children which are not tooltips.
+async fn child_names(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+	proxy: &AccessibleProxy<'_>,
+) -> ComputedText {
+	let children = proxy.get_children().guarded(guard, &accessible.name).await;
+	let children = children.unwrap_or_default();
+	let names = join_all(children.iter().map(|child| async move {
+		let proxy = proxy_for::<AccessibleProxy>(conn, child).await?;
+		let (role, name) = join!(
+			proxy.get_role().guarded(guard, &child.name),
+			proxy.name().guarded(guard, &child.name),
+		);
+		AtspiResult::Ok((role?, name?))
+	}))
+	.await;
+	let parts = children
+		.iter()
+		.zip(names)
+		.filter_map(|(child, read)| match read {
+			Ok((role, name)) if role != Role::ToolTip => part(Source::Children, child, &name),
+			_ => None,
+		})
+		.collect();
+	ComputedText { parts }
+}
+
+async fn image_description(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> ComputedText {
+	let description = match proxy_for::<ImageProxy>(conn, accessible).await {
+		Ok(proxy) => proxy
+			.image_description()
+			.guarded(guard, &accessible.name)
+			.await
+			.unwrap_or_default(),
+		Err(_) => String::new(),
+	};
+	ComputedText {
+		parts: part(Source::ImageDescription, accessible, &description)
+			.into_iter()
+			.collect(),
+	}
+}
+
+async fn tooltips(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+	proxy: &AccessibleProxy<'_>,
+	relations: &RelationSet,
+) -> ComputedText {
+	let candidates = proxy.get_children().guarded(guard, &accessible.name).await;
+	let mut candidates = candidates.unwrap_or_default();
+	for target in relations.targets(RelationType::DescribedBy) {
+		if !candidates.contains(target) {
+			candidates.push(target.clone());
+		}
+	}
+	let read = join_all(candidates.iter().map(|candidate| async move {
+		let proxy = proxy_for::<AccessibleProxy>(conn, candidate).await.ok()?;
+		if proxy.get_role().guarded(guard, &candidate.name).await.ok()? != Role::ToolTip {
+			return None;
+		}
+		text_of(conn, guard, candidate).await
+	}))
+	.await;
+	let parts = candidates
+		.iter()
+		.zip(read)
+		.filter_map(|(candidate, text)| part(Source::Tooltip, candidate, &text?))
+		.collect();
+	ComputedText { parts }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+
+	fn tree() -> Tree {
+		let mut tree = Tree::default();
+		tree.add(Node::new(Role::Panel, "").children(&[1, 2, 3, 5, 6, 8, 9, 11]));
+		// 1: labelled by 2.
+		tree.add(Node::new(Role::Entry, "").relation(RelationType::LabelledBy, &[2]));
+		tree.add(Node::new(Role::Label, " Email ").relation(RelationType::LabelFor, &[1]));
+		// 3: named by its child, not by its tooltip.
+		tree.add(Node::new(Role::PushButton, "").children(&[4, 7]));
+		tree.add(Node::new(Role::Label, "Send"));
+		// 5: named by its image description.
+		tree.add(Node::new(Role::Image, "").image("A red car"));
+		// 6: named and described by its tooltip only.
+		tree.add(Node::new(Role::ToggleButton, "").children(&[7]));
+		tree.add(Node::new(Role::ToolTip, "Bold"));
+		// 8: named by its content.
+		tree.add(Node::new(Role::Heading, "").text("Introduction"));
+		// 9: described by 10.
+		tree.add(Node::new(Role::Entry, "Search").relation(RelationType::DescribedBy, &[10]));
+		tree.add(Node::new(Role::Paragraph, "").text("Type at least three letters"));
+		// 11: nothing at all.
+		tree.add(Node::new(Role::Panel, "").description("Tools"));
+		tree
+	}
+
+	fn parts(text: &ComputedText) -> Vec<(Source, &str)> {
+		text.parts
+			.iter()
+			.map(|part| (part.source, part.text.as_str()))
+			.collect()
+	}
+
+	#[test]
+	fn names_follow_fallback_order() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let name = |index| {
+				let conn = conn.clone();
+				let guard = CallGuard::default();
+				async move { compute_name(&conn, &guard, &mock::accessible(index)).await.unwrap() }
+			};
+
+			assert_eq!(parts(&name(9).await), [(Source::Name, "Search")]);
+			let label = name(1).await;
+			assert_eq!(parts(&label), [(Source::LabelledBy, "Email")]);
+			assert_eq!(label.parts[0].accessible, mock::accessible(2));
+			assert_eq!(parts(&name(8).await), [(Source::Content, "Introduction")]);
+			assert_eq!(parts(&name(3).await), [(Source::Children, "Send")]);
+			assert_eq!(parts(&name(5).await), [(Source::ImageDescription, "A red car")]);
+			assert_eq!(parts(&name(6).await), [(Source::Tooltip, "Bold")]);
+			let nothing = name(11).await;
+			assert!(nothing.is_empty());
+			assert_eq!(nothing.source(), None);
+			assert_eq!(nothing.text(), "");
+		});
+	}
+
+	#[test]
+	fn descriptions_follow_fallback_order() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let description = |index| {
+				let conn = conn.clone();
+				let guard = CallGuard::default();
+				async move {
+					compute_description(&conn, &guard, &mock::accessible(index))
+						.await
+						.unwrap()
+				}
+			};
+
+			assert_eq!(parts(&description(11).await), [(Source::Description, "Tools")]);
+			let described = description(9).await;
+			assert_eq!(parts(&described), [(Source::DescribedBy, "Type at least three letters")]);
+			assert_eq!(described.to_string(), "Type at least three letters");
+			assert_eq!(parts(&description(6).await), [(Source::Tooltip, "Bold")]);
+			assert!(description(1).await.is_empty());
+		});
+	}
+
+	#[test]
+	fn missing_objects_fail() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let guard = CallGuard::default();
+			assert!(compute_name(&conn, &guard, &mock::accessible(99)).await.is_err());
+		});
+	}
+}
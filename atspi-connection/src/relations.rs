@@ -0,0 +1,169 @@
+//! Reads the text an object gets from the objects it is related to.
+//!
+//! An entry is usually labelled by a separate label object, and a form field may point to
+//! the paragraph describing it. [`label`], [`description`] and [`details`] follow the
+//! [`RelationType::LabelledBy`], [`RelationType::DescribedBy`] and [`RelationType::Details`]
+//! relations of an object and join the text of their targets.
+//!
+//! The text of a target is its name or, when it has none, its text content.
+//! Targets are read concurrently, and targets that cannot be read are left out.
+//! All calls are subject to the [`CallGuard`] passed in.
+
+use crate::AtspiResult;
+use atspi_common::{Accessible, RelationSet, RelationType};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	proxy_for,
+	text::TextProxy,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+use futures_util::future::join_all;
+
+/// Reads the relation set of `accessible`.
+///
+/// # Errors
+///
+/// When the relation set cannot be read.
+pub async fn relation_set(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> AtspiResult<RelationSet> {
+	let proxy = proxy_for::<AccessibleProxy>(conn, accessible).await?;
+	Ok(proxy
+		.get_relation_set()
+		.guarded(guard, &accessible.name)
+		.await?
+		.into())
+}
+
+/// The text of the objects `accessible` is [`RelationType::LabelledBy`].
+///
+/// Returns `None` if there are no such objects or none of them has any text.
+/// The name of `accessible` itself is not consulted.
+///
+/// # Errors
+///
+/// When the relation set of `accessible` cannot be read.
+pub async fn label(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> AtspiResult<Option<String>> {
+	let relations = relation_set(conn, guard, accessible).await?;
+	Ok(related_text(conn, guard, &relations, RelationType::LabelledBy).await)
+}
+
+/// The text of the objects `accessible` is [`RelationType::DescribedBy`].
+///
+/// Returns `None` if there are no such objects or none of them has any text.
+///
+/// # Errors
+///
+/// When the relation set of `accessible` cannot be read.
+pub async fn description(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> AtspiResult<Option<String>> {
+	let relations = relation_set(conn, guard, accessible).await?;
+	Ok(related_text(conn, guard, &relations, RelationType::DescribedBy).await)
+}
+
+/// The text of the objects holding the [`RelationType::Details`] of `accessible`.
+///
+/// Returns `None` if there are no such objects or none of them has any text.
+///
+/// # Errors
+///
+/// When the relation set of `accessible` cannot be read.
+pub async fn details(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> AtspiResult<Option<String>> {
+	let relations = relation_set(conn, guard, accessible).await?;
+	Ok(related_text(conn, guard, &relations, RelationType::Details).await)
+}
+
+/// Joins the text of the targets of `relation` with spaces, skipping targets without text.
+pub(crate) async fn related_text(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	relations: &RelationSet,
+	relation: RelationType,
+) -> Option<String> {
+	let texts = relations.targets(relation).map(|target| text_of(conn, guard, target));
+	let texts = join_all(texts).await;
+	let parts: Vec<String> = texts.into_iter().flatten().collect();
+	if parts.is_empty() {
+		None
+	} else {
+		Some(parts.join(" "))
+	}
+}
+
+/// The name of `accessible`, or its text content if the name is empty.
+pub(crate) async fn text_of(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+) -> Option<String> {
+	let proxy = proxy_for::<AccessibleProxy>(conn, accessible).await.ok()?;
+	let name = proxy
+		.name()
+		.guarded(guard, &accessible.name)
+		.await
+		.unwrap_or_default();
+	let name = name.trim();
+	if !name.is_empty() {
+		return Some(name.to_string());
+	}
+	let text = proxy_for::<TextProxy>(conn, accessible).await.ok()?;
+	let content = text.get_text(0, -1).guarded(guard, &accessible.name).await.ok()?;
+	let content = content.trim();
+	(!content.is_empty()).then(|| content.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+	use atspi_common::Role;
+
+	fn tree() -> Tree {
+		let mut tree = Tree::default();
+		tree.add(Node::new(Role::Panel, "").children(&[1, 2, 3, 4, 5]));
+		tree.add(
+			Node::new(Role::Entry, "")
+				.relation(RelationType::LabelledBy, &[2, 3])
+				.relation(RelationType::DescribedBy, &[4])
+				.relation(RelationType::Details, &[9]),
+		);
+		tree.add(Node::new(Role::Label, "First"));
+		tree.add(Node::new(Role::Label, "").text(" name "));
+		tree.add(Node::new(Role::Paragraph, "").text("Use at least 8 characters."));
+		tree.add(Node::new(Role::PushButton, "Submit"));
+		tree
+	}
+
+	#[test]
+	fn follows_relations() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let guard = CallGuard::default();
+			let entry = mock::accessible(1);
+			assert_eq!(label(&conn, &guard, &entry).await.unwrap().as_deref(), Some("First name"));
+			assert_eq!(
+				description(&conn, &guard, &entry).await.unwrap().as_deref(),
+				Some("Use at least 8 characters.")
+			);
+			// The only details target does not exist.
+			assert_eq!(details(&conn, &guard, &entry).await.unwrap(), None);
+
+			let button = mock::accessible(5);
+			assert_eq!(label(&conn, &guard, &button).await.unwrap(), None);
+			assert!(relation_set(&conn, &guard, &button).await.unwrap().is_empty());
+		});
+	}
+}
@@ -0,0 +1,523 @@
+//! Reads everything there is to know about one object at once.
+//!
+//! Inspecting an object property by property costs one round trip per property.
+//! [`Snapshot::fetch`] sends its requests concurrently instead: first those of the
+//! `Accessible` interface together with the list of interfaces the object implements,
+//! then those of every other interface it implements. A snapshot therefore costs two
+//! round trips, however many properties it holds.
+//!
+//! Each property is read on its own. One that cannot be read is recorded as a [`FieldError`]
+//! and leaves the rest of the snapshot intact. Each round trip is subject to a [`CallGuard`];
+//! when it times out, all properties it was to read fail.
+
+use atspi_common::{Accessible, CoordType, Interface, InterfaceSet, Layer, Role, StateSet};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	action::ActionProxy,
+	component::ComponentProxy,
+	document::DocumentProxy,
+	image::ImageProxy,
+	proxy_for,
+	selection::SelectionProxy,
+	table::TableProxy,
+	text::TextProxy,
+	timeout::{CallGuard, CallTimeoutExt},
+	value::ValueProxy,
+};
+use futures_util::join;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use zbus::DBusError;
+
+/// The outcome of reading one property.
+pub type Field<T> = Result<T, FieldError>;
+
+/// Why a property could not be read.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FieldError {
+	/// The D-Bus error name the application replied with, if it replied with one;
+	/// for example `org.freedesktop.DBus.Error.UnknownMethod`.
+	pub name: Option<String>,
+	/// A description of the error.
+	pub message: String,
+}
+
+impl std::fmt::Display for FieldError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match &self.name {
+			Some(name) => write!(f, "{name}: {}", self.message),
+			None => f.write_str(&self.message),
+		}
+	}
+}
+
+impl std::error::Error for FieldError {}
+
+impl From<zbus::Error> for FieldError {
+	fn from(e: zbus::Error) -> Self {
+		match e {
+			zbus::Error::MethodError(name, message, _) => {
+				Self { name: Some(name.to_string()), message: message.unwrap_or_default() }
+			}
+			zbus::Error::FDO(e) => Self {
+				name: Some(e.name().to_string()),
+				message: e.description().unwrap_or_default().to_string(),
+			},
+			e => Self { name: None, message: e.to_string() },
+		}
+	}
+}
+
+fn field<T>(result: zbus::Result<T>) -> Field<T> {
+	result.map_err(FieldError::from)
+}
+
+/// Every property of an object that can be read without arguments, across all of its interfaces.
+///
+/// Interface sections are `None` when the object does not implement the interface,
+/// or when its interfaces could not be read, and `Some(Err(_))` when no request
+/// could be sent for the interface at all.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+	/// The object this is a snapshot of.
+	pub accessible: Accessible,
+	pub interfaces: Field<InterfaceSet>,
+	pub name: Field<String>,
+	pub description: Field<String>,
+	pub role: Field<Role>,
+	pub role_name: Field<String>,
+	pub states: Field<StateSet>,
+	pub attributes: Field<HashMap<String, String>>,
+	pub locale: Field<String>,
+	pub accessible_id: Field<String>,
+	pub parent: Field<Accessible>,
+	pub child_count: Field<i32>,
+	pub index_in_parent: Field<i32>,
+	pub component: Option<Field<ComponentSnapshot>>,
+	pub text: Option<Field<TextSnapshot>>,
+	pub value: Option<Field<ValueSnapshot>>,
+	pub action: Option<Field<ActionSnapshot>>,
+	pub image: Option<Field<ImageSnapshot>>,
+	pub table: Option<Field<TableSnapshot>>,
+	pub selection: Option<Field<SelectionSnapshot>>,
+	pub document: Option<Field<DocumentSnapshot>>,
+}
+
+/// The properties of the `Component` interface.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComponentSnapshot {
+	/// `(x, y, width, height)` in screen coordinates.
+	pub extents: Field<(i32, i32, i32, i32)>,
+	pub layer: Field<Layer>,
+	pub alpha: Field<f64>,
+}
+
+/// The properties of the `Text` interface.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSnapshot {
+	pub character_count: Field<i32>,
+	pub caret_offset: Field<i32>,
+	/// All of the text.
+	pub text: Field<String>,
+}
+
+/// The properties of the `Value` interface.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValueSnapshot {
+	pub current: Field<f64>,
+	pub minimum: Field<f64>,
+	pub maximum: Field<f64>,
+	pub minimum_increment: Field<f64>,
+}
+
+/// One action of the `Action` interface.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionInfo {
+	pub name: String,
+	pub description: String,
+	pub key_binding: String,
+}
+
+/// The properties of the `Action` interface.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionSnapshot {
+	pub actions: Field<Vec<ActionInfo>>,
+}
+
+/// The properties of the `Image` interface.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageSnapshot {
+	pub description: Field<String>,
+	pub locale: Field<String>,
+	/// `(x, y, width, height)` in screen coordinates.
+	pub extents: Field<(i32, i32, i32, i32)>,
+}
+
+/// The properties of the `Table` interface.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSnapshot {
+	pub rows: Field<i32>,
+	pub columns: Field<i32>,
+	pub caption: Field<Accessible>,
+	pub summary: Field<Accessible>,
+}
+
+/// The properties of the `Selection` interface.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectionSnapshot {
+	pub selected_children: Field<i32>,
+}
+
+/// The properties of the `Document` interface.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentSnapshot {
+	pub locale: Field<String>,
+	pub attributes: Field<HashMap<String, String>>,
+	pub page_count: Field<i32>,
+	pub current_page_number: Field<i32>,
+}
+
+impl Snapshot {
+	/// Reads every property of `accessible`, over `conn`, subject to `guard`.
+	///
+	/// This never fails as a whole; see [`Field`].
+	///
+	/// # Example
+	///
+	/// ```rust,no_run
+	/// use atspi_connection::{application::desktop, snapshot::Snapshot, AccessibilityConnection};
+	///
+	/// # tokio_test::block_on(async {
+	/// let connection = AccessibilityConnection::open().await.unwrap();
+	/// let snapshot =
+	///     Snapshot::fetch(connection.connection(), connection.call_guard(), &desktop()).await;
+	/// if let Ok(name) = &snapshot.name {
+	///     println!("{name}");
+	/// }
+	/// # })
+	/// ```
+	pub async fn fetch(
+		conn: &zbus::Connection,
+		guard: &CallGuard,
+		accessible: &Accessible,
+	) -> Self {
+		let proxy = match proxy_for::<AccessibleProxy>(conn, accessible).await {
+			Ok(proxy) => proxy,
+			Err(e) => return Self::unreadable(accessible, &FieldError::from(e)),
+		};
+		let read = async {
+			join!(
+				proxy.get_interfaces(),
+				proxy.name(),
+				proxy.description(),
+				proxy.get_role(),
+				proxy.get_role_name(),
+				proxy.get_state(),
+				proxy.get_attributes(),
+				proxy.locale(),
+				proxy.accessible_id(),
+				proxy.parent(),
+				proxy.child_count(),
+				proxy.get_index_in_parent(),
+			)
+		};
+		let (
+			interfaces,
+			name,
+			description,
+			role,
+			role_name,
+			states,
+			attributes,
+			locale,
+			accessible_id,
+			parent,
+			child_count,
+			index_in_parent,
+		) = match guarded(guard, accessible, read).await {
+			Ok(read) => read,
+			Err(e) => return Self::unreadable(accessible, &e),
+		};
+		let interfaces = field(interfaces);
+		let implemented = interfaces.as_ref().map_or_else(|_| InterfaceSet::empty(), |i| *i);
+		let sections = Sections { implemented, guard, accessible };
+		let (component, text, value, action, image, table, selection, document) = join!(
+			sections.read(Interface::Component, component(conn, accessible)),
+			sections.read(Interface::Text, text(conn, accessible)),
+			sections.read(Interface::Value, value(conn, accessible)),
+			sections.read(Interface::Action, action(conn, accessible)),
+			sections.read(Interface::Image, image(conn, accessible)),
+			sections.read(Interface::Table, table(conn, accessible)),
+			sections.read(Interface::Selection, selection(conn, accessible)),
+			sections.read(Interface::Document, document(conn, accessible)),
+		);
+		Self {
+			accessible: accessible.clone(),
+			interfaces,
+			name: field(name),
+			description: field(description),
+			role: field(role),
+			role_name: field(role_name),
+			states: field(states),
+			attributes: field(attributes),
+			locale: field(locale),
+			accessible_id: field(accessible_id),
+			parent: field(parent),
+			child_count: field(child_count),
+			index_in_parent: field(index_in_parent),
+			component,
+			text,
+			value,
+			action,
+			image,
+			table,
+			selection,
+			document,
+		}
+	}
+
+	/// A snapshot where every property failed with `e`.
+	fn unreadable(accessible: &Accessible, e: &FieldError) -> Self {
+		Self {
+			accessible: accessible.clone(),
+			interfaces: Err(e.clone()),
+			name: Err(e.clone()),
+			description: Err(e.clone()),
+			role: Err(e.clone()),
+			role_name: Err(e.clone()),
+			states: Err(e.clone()),
+			attributes: Err(e.clone()),
+			locale: Err(e.clone()),
+			accessible_id: Err(e.clone()),
+			parent: Err(e.clone()),
+			child_count: Err(e.clone()),
+			index_in_parent: Err(e.clone()),
+			component: None,
+			text: None,
+			value: None,
+			action: None,
+			image: None,
+			table: None,
+			selection: None,
+			document: None,
+		}
+	}
+}
+
+/// Reads the interface sections of a snapshot.
+struct Sections<'a> {
+	implemented: InterfaceSet,
+	guard: &'a CallGuard,
+	accessible: &'a Accessible,
+}
+
+impl Sections<'_> {
+	/// Awaits `read` only if `interface` is among the implemented ones.
+	async fn read<T>(
+		&self,
+		interface: Interface,
+		read: impl std::future::Future<Output = Field<T>>,
+	) -> Option<Field<T>> {
+		if self.implemented.contains(interface) {
+			Some(
+				guarded(self.guard, self.accessible, read)
+					.await
+					.and_then(|section| section),
+			)
+		} else {
+			None
+		}
+	}
+}
+
+/// Awaits `read`, one round trip to the application owning `accessible`, subject to `guard`.
+async fn guarded<T>(
+	guard: &CallGuard,
+	accessible: &Accessible,
+	read: impl std::future::Future<Output = T>,
+) -> Field<T> {
+	async { Ok::<_, zbus::Error>(read.await) }
+		.guarded(guard, &accessible.name)
+		.await
+		.map_err(|e| FieldError { name: None, message: e.to_string() })
+}
+
+async fn component(conn: &zbus::Connection, accessible: &Accessible) -> Field<ComponentSnapshot> {
+	let proxy = field(proxy_for::<ComponentProxy>(conn, accessible).await)?;
+	let (extents, layer, alpha) =
+		join!(proxy.get_extents(CoordType::Screen), proxy.get_layer(), proxy.get_alpha());
+	Ok(ComponentSnapshot { extents: field(extents), layer: field(layer), alpha: field(alpha) })
+}
+
+async fn text(conn: &zbus::Connection, accessible: &Accessible) -> Field<TextSnapshot> {
+	let proxy = field(proxy_for::<TextProxy>(conn, accessible).await)?;
+	let (character_count, caret_offset, text) =
+		join!(proxy.character_count(), proxy.caret_offset(), proxy.get_text(0, -1));
+	Ok(TextSnapshot {
+		character_count: field(character_count),
+		caret_offset: field(caret_offset),
+		text: field(text),
+	})
+}
+
+async fn value(conn: &zbus::Connection, accessible: &Accessible) -> Field<ValueSnapshot> {
+	let proxy = field(proxy_for::<ValueProxy>(conn, accessible).await)?;
+	let (current, minimum, maximum, minimum_increment) = join!(
+		proxy.current_value(),
+		proxy.minimum_value(),
+		proxy.maximum_value(),
+		proxy.minimum_increment(),
+	);
+	Ok(ValueSnapshot {
+		current: field(current),
+		minimum: field(minimum),
+		maximum: field(maximum),
+		minimum_increment: field(minimum_increment),
+	})
+}
+
+async fn action(conn: &zbus::Connection, accessible: &Accessible) -> Field<ActionSnapshot> {
+	let proxy = field(proxy_for::<ActionProxy>(conn, accessible).await)?;
+	let actions = field(proxy.get_actions().await).map(|actions| {
+		actions
+			.into_iter()
+			.map(|(name, description, key_binding)| ActionInfo { name, description, key_binding })
+			.collect()
+	});
+	Ok(ActionSnapshot { actions })
+}
+
+async fn image(conn: &zbus::Connection, accessible: &Accessible) -> Field<ImageSnapshot> {
+	let proxy = field(proxy_for::<ImageProxy>(conn, accessible).await)?;
+	let (description, locale, extents) = join!(
+		proxy.image_description(),
+		proxy.image_locale(),
+		proxy.get_image_extents(CoordType::Screen),
+	);
+	Ok(ImageSnapshot {
+		description: field(description),
+		locale: field(locale),
+		extents: field(extents),
+	})
+}
+
+async fn table(conn: &zbus::Connection, accessible: &Accessible) -> Field<TableSnapshot> {
+	let proxy = field(proxy_for::<TableProxy>(conn, accessible).await)?;
+	let (rows, columns, caption, summary) =
+		join!(proxy.nrows(), proxy.ncolumns(), proxy.caption(), proxy.summary());
+	Ok(TableSnapshot {
+		rows: field(rows),
+		columns: field(columns),
+		caption: field(caption),
+		summary: field(summary),
+	})
+}
+
+async fn selection(conn: &zbus::Connection, accessible: &Accessible) -> Field<SelectionSnapshot> {
+	let proxy = field(proxy_for::<SelectionProxy>(conn, accessible).await)?;
+	Ok(SelectionSnapshot { selected_children: field(proxy.nselected_children().await) })
+}
+
+async fn document(conn: &zbus::Connection, accessible: &Accessible) -> Field<DocumentSnapshot> {
+	let proxy = field(proxy_for::<DocumentProxy>(conn, accessible).await)?;
+	let (locale, attributes, page_count, current_page_number) = join!(
+		proxy.get_locale(),
+		proxy.get_attributes(),
+		proxy.page_count(),
+		proxy.current_page_number(),
+	);
+	Ok(DocumentSnapshot {
+		locale: field(locale),
+		attributes: field(attributes),
+		page_count: field(page_count),
+		current_page_number: field(current_page_number),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+	use atspi_common::State;
+	use std::time::Duration;
+
+	fn tree() -> Tree {
+		let mut tree = Tree::default();
+		let button = tree.add(
+			Node::new(Role::PushButton, "OK")
+				.states(State::Enabled | State::Focusable)
+				.extents((10, 20, 80, 30))
+				.action("click")
+				.failing("Description"),
+		);
+		let entry = tree.add(Node::new(Role::Entry, "").text("hello").value(3.0));
+		tree.add(Node::new(Role::Frame, "Main").children(&[button, entry]));
+		tree
+	}
+
+	#[test]
+	fn fetches_every_implemented_interface() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let snapshot =
+				Snapshot::fetch(&conn, &CallGuard::default(), &mock::accessible(0)).await;
+
+			assert_eq!(snapshot.name, Ok("OK".to_string()));
+			assert_eq!(snapshot.role, Ok(Role::PushButton));
+			assert_eq!(snapshot.states, Ok(StateSet::new(State::Enabled | State::Focusable)));
+			assert_eq!(snapshot.parent, Ok(mock::accessible(2)));
+			assert_eq!(snapshot.index_in_parent, Ok(0));
+			let component = snapshot.component.unwrap().unwrap();
+			assert_eq!(component.extents, Ok((10, 20, 80, 30)));
+			assert_eq!(component.layer, Ok(Layer::Widget));
+			let actions = snapshot.action.unwrap().unwrap().actions.unwrap();
+			assert_eq!(actions[0].name, "click");
+			assert!(snapshot.text.is_none());
+			assert!(snapshot.table.is_none());
+
+			let snapshot =
+				Snapshot::fetch(&conn, &CallGuard::default(), &mock::accessible(1)).await;
+			assert_eq!(snapshot.text.unwrap().unwrap().text, Ok("hello".to_string()));
+			assert_eq!(snapshot.value.unwrap().unwrap().current, Ok(3.0));
+			assert!(snapshot.component.is_none());
+		});
+	}
+
+	#[test]
+	fn a_failing_property_does_not_fail_the_others() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let snapshot =
+				Snapshot::fetch(&conn, &CallGuard::default(), &mock::accessible(0)).await;
+
+			let error = snapshot.description.unwrap_err();
+			assert_eq!(error.name.as_deref(), Some("org.freedesktop.DBus.Error.Failed"));
+			assert_eq!(snapshot.name, Ok("OK".to_string()));
+		});
+	}
+
+	#[test]
+	fn snapshots_survive_serialization() {
+		tokio_test::block_on(async {
+			let conn = tree().serve().await;
+			let snapshot =
+				Snapshot::fetch(&conn, &CallGuard::default(), &mock::accessible(0)).await;
+
+			let json = serde_json::to_string(&snapshot).unwrap();
+			let decoded: Snapshot = serde_json::from_str(&json).unwrap();
+			assert_eq!(decoded, snapshot);
+		});
+	}
+
+	#[test]
+	fn unanswered_calls_time_out() {
+		tokio_test::block_on(async {
+			let (conn, _hung) = mock::unresponsive().await;
+			let guard = CallGuard::new(Duration::from_millis(50));
+			let snapshot = Snapshot::fetch(&conn, &guard, &mock::accessible(0)).await;
+
+			assert!(snapshot.name.unwrap_err().message.contains("no reply"));
+			assert!(snapshot.interfaces.is_err());
+		});
+	}
+}
@@ -0,0 +1,651 @@
+//! Embedding the accessible tree of one process into that of another.
+//!
+//! A [`Plug`] is the root of a tree, on the side of the application owning it. It embeds itself
+//! into a socket: the registry, which makes the application show up below the desktop root, or
+//! an object of another application, such as the browser window hosting a content process.
+//!
+//! A [`Socket`] is the other side. It serves the `org.a11y.atspi.Socket` interface at one of
+//! its objects, keeps track of the plugs embedded into it, and tells assistive technologies
+//! about them with `ChildrenChanged` events.
+//!
+//! The handshake goes as follows:
+//!
+//! 1. the plug calls `Embed` on the socket, with its root object;
+//! 2. the socket sets the `Id` property of the `Application` interface of the plug's root;
+//! 3. `Embed` returns the object of the socket, which the root of the plug reports as its
+//!    `Accessible.Parent` from then on;
+//! 4. the plug announces its new parent with an `accessible-parent` `PropertyChange` event, so
+//!    assistive technologies drop the parent they have cached.
+//!
+//! The socket embeds the tree of the process calling `Embed`: the bus name of the plug is that
+//! of the sender, whatever the call claims, and a process can only unembed its own trees.
+//!
+//! Neither side serves the `Accessible` interface of the objects involved; the applications do.
+//! The one serving the root of the plug reports [`Plug::parent`] as its `Parent`, and the one
+//! serving the object of the socket reports [`Socket::plugs`] among its children, in
+//! `GetChildren`, `GetChildAtIndex` and `ChildCount`, after those of its own. The indices in the
+//! `ChildrenChanged` events of the socket count those own children as well;
+//! [`Socket::with_own_children`] tells the socket how many there are.
+//!
+//! Applications may exit without unembedding their plugs. [`Socket::watch`] removes the plugs of
+//! processes which leave the bus, and announces them removed.
+//!
+//! When the registry restarts, it announces itself with an [`AvailableEvent`]. Plugs embedded
+//! into the registry have to embed themselves again, which [`Plug::handle_event`] does.
+
+use crate::{application::REGISTRY_BUS_NAME, AtspiResult};
+use atspi_common::{
+	events::{
+		object::{ChildrenChangedEvent, Property, PropertyChangeEvent},
+		AvailableEvent, Event, GenericEvent,
+	},
+	Accessible,
+};
+use atspi_proxies::{
+	application::ApplicationProxy, proxy_for, socket::SocketProxy, timeout::CallTimeoutExt,
+};
+use futures_lite::stream::StreamExt;
+use std::{
+	sync::{Arc, Mutex, PoisonError},
+	time::Duration,
+};
+use zbus::{dbus_interface, fdo::DBusProxy, names::BusName, MessageHeader, SignalContext};
+
+/// How long a socket waits for a plug to accept its identifier.
+const SET_ID_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The root of a tree, embedded into a socket in another process; see the
+/// [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plug {
+	root: Accessible,
+	/// The socket the plug is embedded into, as passed to [`Plug::embed`].
+	socket: Option<Accessible>,
+	/// The object `Embed` returned.
+	parent: Option<Accessible>,
+}
+
+impl Plug {
+	/// A plug for the tree rooted at `root`, not embedded yet.
+	#[must_use]
+	pub fn new(root: Accessible) -> Self {
+		Self { root, socket: None, parent: None }
+	}
+
+	/// The root of the plugged tree.
+	#[must_use]
+	pub fn root(&self) -> &Accessible {
+		&self.root
+	}
+
+	/// The object the root reports as its `Accessible.Parent`, once embedded.
+	#[must_use]
+	pub fn parent(&self) -> Option<&Accessible> {
+		self.parent.as_ref()
+	}
+
+	/// Whether the plug is embedded into a socket.
+	#[must_use]
+	pub fn is_embedded(&self) -> bool {
+		self.parent.is_some()
+	}
+
+	/// Embeds the plug into `socket`, and returns the object the root is now a child of.
+	///
+	/// Pass [`desktop`](crate::application::desktop) to register the application with the
+	/// registry. The root has to implement the `org.a11y.atspi.Application` interface, so the
+	/// socket can set its identifier. Once embedded, the root is announced as a child of the
+	/// returned object.
+	///
+	/// # Errors
+	///
+	/// When the `Embed` call fails.
+	pub async fn embed(
+		&mut self,
+		conn: &zbus::Connection,
+		socket: Accessible,
+	) -> AtspiResult<Accessible> {
+		let proxy = proxy_for::<SocketProxy>(conn, &socket).await?;
+		let parent = proxy
+			.embed(&(self.root.name.as_str(), self.root.path.as_ref()))
+			.await?;
+		self.socket = Some(socket);
+		self.parent = Some(parent.clone());
+		let _ = self.parent_changed(conn, parent.clone()).await;
+		Ok(parent)
+	}
+
+	/// Removes the plug from the socket it is embedded into, if any.
+	///
+	/// This is not necessary when the application exits: the registry, and sockets running
+	/// [`Socket::watch`], notice that by themselves. The root is announced to have no parent any
+	/// more.
+	///
+	/// # Errors
+	///
+	/// When the `Unembed` call fails; the plug is considered removed anyway.
+	pub async fn unembed(&mut self, conn: &zbus::Connection) -> AtspiResult<()> {
+		self.parent = None;
+		let Some(socket) = self.socket.take() else {
+			return Ok(());
+		};
+		let _ = self.parent_changed(conn, Accessible::default()).await;
+		let proxy = proxy_for::<SocketProxy>(conn, &socket).await?;
+		proxy
+			.unembed(&(self.root.name.as_str(), self.root.path.as_ref()))
+			.await?;
+		Ok(())
+	}
+
+	/// Embeds the plug again when `event` announces that the registry restarted.
+	///
+	/// This only applies to plugs embedded into the registry by its well-known name,
+	/// [`REGISTRY_BUS_NAME`]. Returns whether the plug was embedded again; the
+	/// `Socket:Available` events have to be subscribed to for this to work.
+	///
+	/// # Errors
+	///
+	/// When the `Embed` call fails.
+	pub async fn handle_event(
+		&mut self,
+		conn: &zbus::Connection,
+		event: &Event,
+	) -> AtspiResult<bool> {
+		let Event::Available(AvailableEvent { socket, .. }) = event else {
+			return Ok(false);
+		};
+		let Some(embedded) = &self.socket else {
+			return Ok(false);
+		};
+		if embedded.name != REGISTRY_BUS_NAME || embedded.path != socket.path {
+			return Ok(false);
+		}
+		let embedded = embedded.clone();
+		self.embed(conn, embedded).await?;
+		Ok(true)
+	}
+
+	/// Emits the `accessible-parent` change of the root, now a child of `parent`.
+	async fn parent_changed(
+		&self,
+		conn: &zbus::Connection,
+		parent: Accessible,
+	) -> zbus::Result<()> {
+		let event = PropertyChangeEvent {
+			item: self.root.clone(),
+			property: "accessible-parent".to_string(),
+			value: Property::Parent(parent),
+		};
+		conn.emit_signal(
+			Option::<BusName<'_>>::None,
+			self.root.path.clone(),
+			PropertyChangeEvent::DBUS_INTERFACE,
+			PropertyChangeEvent::DBUS_MEMBER,
+			&event.body(),
+		)
+		.await
+	}
+}
+
+#[derive(Debug)]
+struct Plugs {
+	plugs: Vec<Accessible>,
+	next_id: i32,
+}
+
+/// Counts the children the object of a socket has of its own.
+type OwnChildren = dyn Fn() -> usize + Send + Sync;
+
+/// An object other processes can embed their trees into; see the [module documentation](self).
+///
+/// Clones share the embedded plugs.
+#[derive(Clone)]
+pub struct Socket {
+	accessible: Accessible,
+	plugs: Arc<Mutex<Plugs>>,
+	own_children: Arc<OwnChildren>,
+}
+
+impl std::fmt::Debug for Socket {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Socket")
+			.field("accessible", &self.accessible)
+			.field("plugs", &self.plugs)
+			.finish_non_exhaustive()
+	}
+}
+
+impl Socket {
+	/// A socket at the object `accessible`, of the application serving it.
+	///
+	/// Identifiers given to plugs start at `first_id`.
+	#[must_use]
+	pub fn new(accessible: Accessible, first_id: i32) -> Self {
+		Self {
+			accessible,
+			plugs: Arc::new(Mutex::new(Plugs { plugs: Vec::new(), next_id: first_id })),
+			own_children: Arc::new(|| 0),
+		}
+	}
+
+	/// Counts the children the object of the socket has of its own with `count`.
+	///
+	/// Plugs come after those children, so the index of a plug in `ChildrenChanged` events is
+	/// offset by `count()` at the time. Without this, the object is taken to have no children of
+	/// its own.
+	#[must_use]
+	pub fn with_own_children(mut self, count: impl Fn() -> usize + Send + Sync + 'static) -> Self {
+		self.own_children = Arc::new(count);
+		self
+	}
+
+	/// The object of the socket.
+	#[must_use]
+	pub fn accessible(&self) -> &Accessible {
+		&self.accessible
+	}
+
+	/// The roots of the plugs embedded into the socket, in the order they were embedded.
+	///
+	/// The application serving the object of the socket reports these as its children, after
+	/// those of its own.
+	#[must_use]
+	pub fn plugs(&self) -> Vec<Accessible> {
+		self.plugs
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.plugs
+			.clone()
+	}
+
+	/// Serves the `org.a11y.atspi.Socket` interface at the path of the socket on `conn`.
+	///
+	/// Returns `false` if the interface was already served there. On a connection which serves no
+	/// objects yet, its object server starts with this call, and may miss calls arriving at once.
+	///
+	/// # Errors
+	///
+	/// When the path of the socket is invalid.
+	pub async fn serve(&self, conn: &zbus::Connection) -> zbus::Result<bool> {
+		let interface = SocketInterface { socket: self.clone() };
+		conn.object_server().at(self.accessible.path.clone(), interface).await
+	}
+
+	/// Stops serving the `org.a11y.atspi.Socket` interface on `conn`.
+	///
+	/// # Errors
+	///
+	/// When the path of the socket is invalid.
+	pub async fn stop(&self, conn: &zbus::Connection) -> zbus::Result<bool> {
+		conn.object_server()
+			.remove::<SocketInterface, _>(self.accessible.path.clone())
+			.await
+	}
+
+	/// Removes the plugs of processes which leave the bus, until `conn` closes.
+	///
+	/// Run this alongside serving the socket on `conn`; otherwise the plugs of applications which
+	/// exit without unembedding stay in [`Self::plugs`].
+	///
+	/// # Errors
+	///
+	/// When subscribing to `NameOwnerChanged` fails.
+	pub async fn watch(&self, conn: &zbus::Connection) -> AtspiResult<()> {
+		let mut owners = DBusProxy::new(conn).await?.receive_name_owner_changed().await?;
+		while let Some(signal) = owners.next().await {
+			let Ok(args) = signal.args() else {
+				continue;
+			};
+			if args.new_owner().is_none() {
+				self.owner_lost(conn, args.name().as_str()).await;
+			}
+		}
+		Ok(())
+	}
+
+	/// Removes the plugs of the process which owned `name`, and announces them removed.
+	async fn owner_lost(&self, conn: &zbus::Connection, name: &str) {
+		loop {
+			let removed = {
+				let mut plugs = self.plugs.lock().unwrap_or_else(PoisonError::into_inner);
+				plugs
+					.plugs
+					.iter()
+					.position(|plug| plug.name == name)
+					.map(|index| (index, plugs.plugs.remove(index)))
+			};
+			let Some((index, plug)) = removed else {
+				return;
+			};
+			let _ = self.children_changed(conn, "remove", index, plug).await;
+		}
+	}
+
+	/// Adds `plug`, or moves it to the end when it was already embedded.
+	///
+	/// Returns its identifier and index among the plugs.
+	fn insert(&self, plug: Accessible) -> (i32, usize) {
+		let mut plugs = self.plugs.lock().unwrap_or_else(PoisonError::into_inner);
+		plugs.plugs.retain(|p| *p != plug);
+		plugs.plugs.push(plug);
+		let id = plugs.next_id;
+		plugs.next_id += 1;
+		(id, plugs.plugs.len() - 1)
+	}
+
+	fn remove(&self, plug: &Accessible) -> Option<usize> {
+		let mut plugs = self.plugs.lock().unwrap_or_else(PoisonError::into_inner);
+		let index = plugs.plugs.iter().position(|p| p == plug)?;
+		plugs.plugs.remove(index);
+		Some(index)
+	}
+
+	/// Emits the change of the children of the socket, at `index` among the plugs.
+	async fn children_changed(
+		&self,
+		conn: &zbus::Connection,
+		operation: &str,
+		index: usize,
+		child: Accessible,
+	) -> zbus::Result<()> {
+		let event = ChildrenChangedEvent {
+			item: self.accessible.clone(),
+			operation: operation.to_string(),
+			index_in_parent: i32::try_from((self.own_children)() + index).unwrap_or(i32::MAX),
+			child,
+		};
+		conn.emit_signal(
+			Option::<BusName<'_>>::None,
+			self.accessible.path.clone(),
+			ChildrenChangedEvent::DBUS_INTERFACE,
+			ChildrenChangedEvent::DBUS_MEMBER,
+			&event.body(),
+		)
+		.await
+	}
+}
+
+struct SocketInterface {
+	socket: Socket,
+}
+
+/// The plug of the process which sent the call with `header`, at the path of `plug`.
+///
+/// Calls over a peer-to-peer connection have no sender; `plug` is taken as is there.
+fn sent_by(header: &MessageHeader<'_>, plug: Accessible) -> Accessible {
+	match header.sender() {
+		Ok(Some(sender)) => Accessible { name: sender.to_string(), path: plug.path },
+		_ => plug,
+	}
+}
+
+#[dbus_interface(name = "org.a11y.atspi.Socket")]
+impl SocketInterface {
+	async fn embed(
+		&self,
+		#[zbus(header)] header: MessageHeader<'_>,
+		#[zbus(signal_context)] ctxt: SignalContext<'_>,
+		plug: Accessible,
+	) -> Accessible {
+		let plug = sent_by(&header, plug);
+		let (id, index) = self.socket.insert(plug.clone());
+		// Plugs without the `Application` interface are embedded all the same.
+		// A plug which hangs does not hold up the reply for long.
+		if let Ok(application) = proxy_for::<ApplicationProxy>(ctxt.connection(), &plug).await {
+			let _ = application.set_id(id).timeout(SET_ID_TIMEOUT).await;
+		}
+		let _ = self
+			.socket
+			.children_changed(ctxt.connection(), "add", index, plug)
+			.await;
+		self.socket.accessible.clone()
+	}
+
+	async fn unembed(
+		&self,
+		#[zbus(header)] header: MessageHeader<'_>,
+		#[zbus(signal_context)] ctxt: SignalContext<'_>,
+		plug: Accessible,
+	) {
+		let plug = sent_by(&header, plug);
+		if let Some(index) = self.socket.remove(&plug) {
+			let _ = self
+				.socket
+				.children_changed(ctxt.connection(), "remove", index, plug)
+				.await;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{application::desktop, mock};
+	use atspi_common::{events::object::ObjectEvents, AtspiError};
+	use futures_lite::StreamExt;
+	use zbus::MessageStream;
+
+	/// The `Application` interface of a plug, recording the identifier it gets.
+	struct Application(Arc<Mutex<i32>>);
+
+	#[dbus_interface(name = "org.a11y.atspi.Application")]
+	impl Application {
+		#[dbus_interface(property)]
+		fn id(&self) -> i32 {
+			*self.0.lock().unwrap()
+		}
+
+		#[dbus_interface(property)]
+		fn set_id(&mut self, id: i32) {
+			*self.0.lock().unwrap() = id;
+		}
+	}
+
+	fn object(name: &str, path: &str) -> Accessible {
+		Accessible { name: name.into(), path: path.try_into().unwrap() }
+	}
+
+	/// The body of the next signal named `member` on `messages`.
+	async fn member_body(
+		messages: &mut MessageStream,
+		member: &str,
+	) -> atspi_common::events::EventBodyOwned {
+		messages
+			.find_map(|message| {
+				let message = message.ok()?;
+				(message.member()? == member).then(|| message.body().ok())?
+			})
+			.await
+			.unwrap()
+	}
+
+	#[test]
+	fn embeds_the_tree_of_the_sender() {
+		tokio_test::block_on(async {
+			let (plug_side, socket_side) = mock::peers().await;
+			let socket = Socket::new(object(":1.1", "/org/a11y/atspi/accessible/7"), 1);
+			socket.serve(&socket_side).await.unwrap();
+			let mut replies = MessageStream::from(&plug_side);
+
+			let call = |method: &'static str| {
+				let forged = object(":1.3", "/org/a11y/atspi/accessible/root");
+				zbus::MessageBuilder::method_call(socket.accessible().path.as_str(), method)
+					.unwrap()
+					.interface("org.a11y.atspi.Socket")
+					.unwrap()
+					.sender(":1.2")
+					.unwrap()
+					.build(&((forged.name.as_str(), forged.path.as_ref()),))
+					.unwrap()
+			};
+			for method in ["Embed", "Unembed"] {
+				let serial = plug_side.send_message(call(method)).await.unwrap();
+				replies
+					.find(
+						|reply| matches!(reply, Ok(reply) if reply.reply_serial() == Some(serial)),
+					)
+					.await
+					.unwrap()
+					.unwrap();
+				if method == "Embed" {
+					let plug = object(":1.2", "/org/a11y/atspi/accessible/root");
+					assert_eq!(socket.plugs(), [plug]);
+				}
+			}
+			assert!(socket.plugs().is_empty());
+		});
+	}
+
+	#[test]
+	fn embeds_and_unembeds_plugs() {
+		tokio_test::block_on(async {
+			let (plug_side, socket_side) = mock::peers().await;
+			let socket = Socket::new(object(":1.1", "/org/a11y/atspi/accessible/7"), 1);
+			assert!(socket.serve(&socket_side).await.unwrap());
+			let id = Arc::new(Mutex::new(0));
+			let root = object(":1.2", "/org/a11y/atspi/accessible/root");
+			plug_side
+				.object_server()
+				.at(root.path.clone(), Application(id.clone()))
+				.await
+				.unwrap();
+			let mut signals = MessageStream::from(&plug_side);
+			let mut plug_signals = MessageStream::from(&socket_side);
+
+			let mut plug = Plug::new(root.clone());
+			let parent = plug.embed(&plug_side, socket.accessible().clone()).await.unwrap();
+			assert_eq!(parent, *socket.accessible());
+			assert_eq!(plug.parent(), Some(&parent));
+			assert_eq!(socket.plugs(), std::slice::from_ref(&root));
+			assert_eq!(*id.lock().unwrap(), 1);
+
+			// Peer-to-peer messages have no sender, so the signal can not become an `Event`.
+			let body = signals
+				.find_map(|message| {
+					let message = message.ok()?;
+					let member = message.member()?;
+					(member == ChildrenChangedEvent::DBUS_MEMBER).then(|| message.body().ok())?
+				})
+				.await
+				.unwrap();
+			let changed = ChildrenChangedEvent::build(parent.clone(), body).unwrap();
+			assert_eq!((changed.operation.as_str(), changed.index_in_parent), ("add", 0));
+			assert_eq!(changed.child, root);
+			let body = member_body(&mut plug_signals, PropertyChangeEvent::DBUS_MEMBER).await;
+			let changed = PropertyChangeEvent::build(root.clone(), body).unwrap();
+			assert_eq!(changed.value, Property::Parent(parent.clone()));
+
+			plug.unembed(&plug_side).await.unwrap();
+			let body = member_body(&mut plug_signals, PropertyChangeEvent::DBUS_MEMBER).await;
+			let changed = PropertyChangeEvent::build(root.clone(), body).unwrap();
+			assert!(matches!(changed.value, Property::Parent(parent) if parent.is_null()));
+			assert!(!plug.is_embedded());
+			assert!(socket.plugs().is_empty());
+			// Unembedding twice does nothing.
+			plug.unembed(&plug_side).await.unwrap();
+		});
+	}
+
+	#[test]
+	fn plugs_come_after_own_children() {
+		tokio_test::block_on(async {
+			let (plug_side, socket_side) = mock::peers().await;
+			let socket = Socket::new(object(":1.1", "/org/a11y/atspi/accessible/7"), 1)
+				.with_own_children(|| 3);
+			socket.serve(&socket_side).await.unwrap();
+			let mut signals = MessageStream::from(&plug_side);
+
+			for (index, path) in ["/org/a11y/atspi/accessible/a", "/org/a11y/atspi/accessible/b"]
+				.into_iter()
+				.enumerate()
+			{
+				let mut plug = Plug::new(object(":1.2", path));
+				plug.embed(&plug_side, socket.accessible().clone()).await.unwrap();
+				let body = member_body(&mut signals, ChildrenChangedEvent::DBUS_MEMBER).await;
+				let changed =
+					ChildrenChangedEvent::build(socket.accessible().clone(), body).unwrap();
+				assert_eq!(changed.index_in_parent, 3 + i32::try_from(index).unwrap());
+			}
+		});
+	}
+
+	#[test]
+	fn removes_the_plugs_of_processes_leaving_the_bus() {
+		tokio_test::block_on(async {
+			let (plug_side, socket_side) = mock::peers().await;
+			let socket = Socket::new(object(":1.1", "/org/a11y/atspi/accessible/7"), 1);
+			socket.serve(&socket_side).await.unwrap();
+			let plugs = [
+				object(":1.2", "/org/a11y/atspi/accessible/a"),
+				object(":1.3", "/org/a11y/atspi/accessible/root"),
+				object(":1.2", "/org/a11y/atspi/accessible/b"),
+			];
+			for root in &plugs {
+				Plug::new(root.clone())
+					.embed(&plug_side, socket.accessible().clone())
+					.await
+					.unwrap();
+			}
+			let mut signals = MessageStream::from(&plug_side);
+
+			socket.owner_lost(&socket_side, ":1.2").await;
+			assert_eq!(socket.plugs(), [plugs[1].clone()]);
+			for (index, plug) in [(0, &plugs[0]), (1, &plugs[2])] {
+				let body = member_body(&mut signals, ChildrenChangedEvent::DBUS_MEMBER).await;
+				let changed =
+					ChildrenChangedEvent::build(socket.accessible().clone(), body).unwrap();
+				assert_eq!(
+					(changed.operation.as_str(), changed.index_in_parent),
+					("remove", index)
+				);
+				assert_eq!(changed.child, *plug);
+			}
+		});
+	}
+
+	#[test]
+	fn embeds_again_when_registry_restarts() {
+		tokio_test::block_on(async {
+			let (plug_side, registry_side) = mock::peers().await;
+			let socket = Socket::new(desktop(), 1);
+			socket.serve(&registry_side).await.unwrap();
+			let mut plug = Plug::new(object(":1.2", "/org/a11y/atspi/accessible/root"));
+			plug.embed(&plug_side, desktop()).await.unwrap();
+
+			let unrelated =
+				Event::Object(ObjectEvents::ChildrenChanged(ChildrenChangedEvent::default()));
+			assert!(!plug.handle_event(&plug_side, &unrelated).await.unwrap());
+			// The registry does not know the plug any more after restarting.
+			socket.remove(plug.root());
+			let available =
+				AvailableEvent { item: desktop(), socket: object(":1.9", desktop().path.as_str()) };
+			assert!(plug
+				.handle_event(&plug_side, &Event::Available(available))
+				.await
+				.unwrap());
+			assert_eq!(socket.plugs(), [plug.root().clone()]);
+
+			// Plugs embedded into other sockets stay where they are.
+			let other = Socket::new(object(":1.1", "/org/a11y/atspi/accessible/root"), 1);
+			assert!(!other.serve(&registry_side).await.unwrap());
+			let other = Socket::new(object(":1.1", "/org/example"), 1);
+			other.serve(&registry_side).await.unwrap();
+			let mut plug = Plug::new(object(":1.3", "/org/a11y/atspi/accessible/root"));
+			plug.embed(&plug_side, other.accessible().clone()).await.unwrap();
+			let available =
+				AvailableEvent { item: desktop(), socket: object(":1.9", "/org/example") };
+			assert!(!plug
+				.handle_event(&plug_side, &Event::Available(available))
+				.await
+				.unwrap());
+
+			let mut plug = Plug::new(object(":1.4", "/org/a11y/atspi/accessible/root"));
+			let missing = plug.embed(&plug_side, object(":1.1", "/org/missing")).await;
+			assert!(matches!(
+				missing,
+				Err(AtspiError::ObjectDefunct(_) | AtspiError::UnknownMethod(_))
+			));
+			assert!(!plug.is_embedded());
+		});
+	}
+}
@@ -0,0 +1,545 @@
+//! Snapshots of whole subtrees, and the differences between two of them.
+//!
+//! A [`TreeSnapshot`] holds what users of an application perceive of each object:
+//! roles, names, states, interfaces, attributes, text and extents. It leaves out bus names
+//! and object paths, which change every time the application runs, so snapshots of the same
+//! user interface compare equal across runs, and across toolkits that expose it the same way.
+//!
+//! Snapshots implement `serde::Serialize` and `serde::Deserialize`, with a layout meant to be
+//! stable and readable in formats such as JSON or RON; golden files are a typical use.
+//! [`diff`] reports the nodes that were added, removed or changed between two snapshots.
+
+use crate::snapshot::{Field, Snapshot};
+use atspi_common::{Accessible, Interface, Role, State};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	proxy_for,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+use futures_util::{future::join_all, join};
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::{BTreeMap, HashSet},
+	fmt,
+};
+
+/// An object, and the objects below it.
+///
+/// Properties that could not be read hold their default value, and are listed in
+/// [`Self::errors`] along with the reason.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeSnapshot {
+	pub role: Role,
+	pub name: String,
+	#[serde(default, skip_serializing_if = "String::is_empty")]
+	pub description: String,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub states: Vec<State>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub interfaces: Vec<Interface>,
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub attributes: BTreeMap<String, String>,
+	/// The text, for objects implementing the `Text` interface.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub text: Option<String>,
+	/// `(x, y, width, height)` in screen coordinates, for objects implementing the `Component` interface.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub extents: Option<(i32, i32, i32, i32)>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub children: Vec<NodeSnapshot>,
+	/// The properties that could not be read, with the reason.
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub errors: BTreeMap<String, String>,
+}
+
+/// A snapshot of a subtree; see the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TreeSnapshot {
+	pub root: NodeSnapshot,
+}
+
+impl TreeSnapshot {
+	/// Reads `root` and all of its descendants.
+	///
+	/// All objects at the same depth are read concurrently, so this takes a few round trips
+	/// per level of the tree rather than per object.
+	///
+	/// # Example
+	///
+	/// ```rust,no_run
+	/// use atspi_connection::{application::root_of, tree::TreeSnapshot, AccessibilityConnection};
+	///
+	/// # tokio_test::block_on(async {
+	/// let connection = AccessibilityConnection::open().await.unwrap();
+	/// let root = root_of(":1.42");
+	/// let snapshot = TreeSnapshot::fetch(connection.connection(), connection.call_guard(), &root)
+	///     .await
+	///     .without_extents();
+	/// let golden: TreeSnapshot =
+	///     serde_json::from_str(&std::fs::read_to_string("golden.json").unwrap()).unwrap();
+	/// for change in atspi_connection::tree::diff(&golden, &snapshot) {
+	///     println!("{change}");
+	/// }
+	/// # })
+	/// ```
+	pub async fn fetch(conn: &zbus::Connection, guard: &CallGuard, root: &Accessible) -> Self {
+		Self::fetch_to_depth(conn, guard, root, usize::MAX).await
+	}
+
+	/// Like [`Self::fetch`], but leaves out objects more than `max_depth` levels below `root`.
+	pub async fn fetch_to_depth(
+		conn: &zbus::Connection,
+		guard: &CallGuard,
+		root: &Accessible,
+		max_depth: usize,
+	) -> Self {
+		let visited = walk(root, max_depth, |accessible, descend| async move {
+			read(conn, guard, &accessible, descend).await
+		})
+		.await;
+		let mut nodes: Vec<(NodeSnapshot, Vec<usize>)> = Vec::with_capacity(visited.len());
+		for (index, visit) in visited.into_iter().enumerate() {
+			nodes.push((visit.value, Vec::new()));
+			if let Some((parent, _)) = visit.parent {
+				nodes[parent].1.push(index);
+			}
+		}
+
+		// Children always come after their parent, so assembling from the end sees them first.
+		let mut built: Vec<Option<NodeSnapshot>> = Vec::with_capacity(nodes.len());
+		built.resize_with(nodes.len(), || None);
+		for (index, (mut node, children)) in nodes.into_iter().enumerate().rev() {
+			node.children = children.into_iter().filter_map(|child| built[child].take()).collect();
+			built[index] = Some(node);
+		}
+		let root = built.swap_remove(0).expect("the root is always read");
+		Self { root }
+	}
+
+	/// Clears the extents of every node.
+	///
+	/// Positions depend on screen size, fonts and themes; leaving them out makes snapshots
+	/// comparable across machines.
+	#[must_use]
+	pub fn without_extents(mut self) -> Self {
+		fn clear(node: &mut NodeSnapshot) {
+			node.extents = None;
+			node.children.iter_mut().for_each(clear);
+		}
+		clear(&mut self.root);
+		self
+	}
+}
+
+/// An object met by [`walk`].
+pub(crate) struct Visited<T> {
+	pub accessible: Accessible,
+	/// The index of the parent among the visited objects, and the position of this object
+	/// in the children the parent reported.
+	pub parent: Option<(usize, usize)>,
+	pub value: T,
+}
+
+/// Visits `root` and its descendants breadth first, reading each of them with `read`.
+///
+/// `read` is given an object, and whether the walk descends into its children; it returns its
+/// result, and the children. Objects at the same depth are read concurrently, and each object
+/// is read only once, even if an application reports one of its ancestors as a child.
+pub(crate) async fn walk<T, F, Fut>(
+	root: &Accessible,
+	max_depth: usize,
+	mut read: F,
+) -> Vec<Visited<T>>
+where
+	F: FnMut(Accessible, bool) -> Fut,
+	Fut: std::future::Future<Output = (T, Vec<Accessible>)>,
+{
+	let mut visited = Vec::new();
+	let mut level: Vec<(Accessible, Option<(usize, usize)>)> = vec![(root.clone(), None)];
+	let mut seen = HashSet::from([root.clone()]);
+	let mut depth = 0;
+	while !level.is_empty() {
+		let descend = depth < max_depth;
+		let read = join_all(level.iter().map(|(accessible, _)| read(accessible.clone(), descend)));
+		let mut next = Vec::new();
+		for ((accessible, parent), (value, children)) in level.into_iter().zip(read.await) {
+			let index = visited.len();
+			visited.push(Visited { accessible, parent, value });
+			for (position, child) in children.into_iter().enumerate() {
+				if !child.is_null() && seen.insert(child.clone()) {
+					next.push((child, Some((index, position))));
+				}
+			}
+		}
+		level = next;
+		depth += 1;
+	}
+	visited
+}
+
+/// Reads one object, and its children if `descend` is set.
+async fn read(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	accessible: &Accessible,
+	descend: bool,
+) -> (NodeSnapshot, Vec<Accessible>) {
+	let children = async {
+		if !descend {
+			return Ok(Vec::new());
+		}
+		let proxy = proxy_for::<AccessibleProxy>(conn, accessible).await?;
+		proxy.get_children().guarded(guard, &accessible.name).await
+	};
+	let (snapshot, children) = join!(Snapshot::fetch(conn, guard, accessible), children);
+	let mut node = NodeSnapshot::from(snapshot);
+	let children = children.unwrap_or_else(|e| {
+		node.errors.insert("children".to_string(), e.to_string());
+		Vec::new()
+	});
+	(node, children)
+}
+
+/// Takes the value out of `field`, or records its error under `name`.
+fn take<T: Default>(errors: &mut BTreeMap<String, String>, name: &str, field: Field<T>) -> T {
+	field.unwrap_or_else(|e| {
+		errors.insert(name.to_string(), e.to_string());
+		T::default()
+	})
+}
+
+impl From<Snapshot> for NodeSnapshot {
+	fn from(snapshot: Snapshot) -> Self {
+		let mut errors = BTreeMap::new();
+		let role = snapshot.role.unwrap_or_else(|e| {
+			errors.insert("role".to_string(), e.to_string());
+			Role::Invalid
+		});
+		let name = take(&mut errors, "name", snapshot.name);
+		let description = take(&mut errors, "description", snapshot.description);
+		let states = snapshot.states.map(|states| states.iter().collect());
+		let states = take(&mut errors, "states", states);
+		let interfaces = snapshot.interfaces.map(|interfaces| interfaces.iter().collect());
+		let interfaces = take(&mut errors, "interfaces", interfaces);
+		let attributes = snapshot.attributes.map(|attributes| attributes.into_iter().collect());
+		let attributes = take(&mut errors, "attributes", attributes);
+		let text = snapshot.text.map(|text| {
+			let text = text.and_then(|text| text.text);
+			take(&mut errors, "text", text)
+		});
+		let extents = snapshot.component.map(|component| {
+			let extents = component.and_then(|component| component.extents);
+			take(&mut errors, "extents", extents)
+		});
+		Self {
+			role,
+			name,
+			description,
+			states,
+			interfaces,
+			attributes,
+			text,
+			extents,
+			children: Vec::new(),
+			errors,
+		}
+	}
+}
+
+/// A difference between two snapshots, as found by [`diff`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Change {
+	/// A node only in the new snapshot. `path` leads to it in the new snapshot.
+	Added { path: Vec<usize>, node: NodeSnapshot },
+	/// A node only in the old snapshot. `path` leads to it in the old snapshot.
+	Removed { path: Vec<usize>, node: NodeSnapshot },
+	/// A node in both snapshots, with different properties. `path` leads to it in the new snapshot.
+	Changed { path: Vec<usize>, role: Role, fields: Vec<FieldChange> },
+}
+
+/// A property that differs between two versions of a node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+	pub field: String,
+	/// The old value, in its `Debug` form.
+	pub old: String,
+	/// The new value, in its `Debug` form.
+	pub new: String,
+}
+
+fn fmt_path(path: &[usize]) -> String {
+	let path: Vec<String> = path.iter().map(ToString::to_string).collect();
+	format!("/{}", path.join("/"))
+}
+
+impl fmt::Display for Change {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Added { path, node } => {
+				write!(f, "+ {} {} {:?}", fmt_path(path), node.role.name(), node.name)
+			}
+			Self::Removed { path, node } => {
+				write!(f, "- {} {} {:?}", fmt_path(path), node.role.name(), node.name)
+			}
+			Self::Changed { path, role, fields } => {
+				write!(f, "~ {} {}", fmt_path(path), role.name())?;
+				for change in fields {
+					write!(f, "\n    {}: {} -> {}", change.field, change.old, change.new)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+/// Compares two snapshots, usually of the same user interface at different times.
+///
+/// Children are matched by role and name, keeping their order, so inserting or removing
+/// one child does not report all of its later siblings as changed. Children left unmatched
+/// are paired by role, in order, if they sit between the same matched siblings; that is how
+/// a node whose name changed is reported as changed rather than as removed and added.
+/// The changes are listed in document order.
+#[must_use]
+pub fn diff(old: &TreeSnapshot, new: &TreeSnapshot) -> Vec<Change> {
+	let mut changes = Vec::new();
+	diff_node(&old.root, &new.root, &mut Vec::new(), &mut changes);
+	changes
+}
+
+fn diff_node(
+	old: &NodeSnapshot,
+	new: &NodeSnapshot,
+	path: &mut Vec<usize>,
+	changes: &mut Vec<Change>,
+) {
+	let mut fields = Vec::new();
+	let mut compare = |field: &str, old: &dyn fmt::Debug, new: &dyn fmt::Debug| {
+		let (old, new) = (format!("{old:?}"), format!("{new:?}"));
+		if old != new {
+			fields.push(FieldChange { field: field.to_string(), old, new });
+		}
+	};
+	compare("role", &old.role, &new.role);
+	compare("name", &old.name, &new.name);
+	compare("description", &old.description, &new.description);
+	compare("states", &old.states, &new.states);
+	compare("interfaces", &old.interfaces, &new.interfaces);
+	compare("attributes", &old.attributes, &new.attributes);
+	compare("text", &old.text, &new.text);
+	compare("extents", &old.extents, &new.extents);
+	if !fields.is_empty() {
+		changes.push(Change::Changed { path: path.clone(), role: new.role, fields });
+	}
+	diff_children(old, new, path, changes);
+}
+
+fn diff_children(
+	old: &NodeSnapshot,
+	new: &NodeSnapshot,
+	path: &mut Vec<usize>,
+	changes: &mut Vec<Change>,
+) {
+	let key = |node: &NodeSnapshot| (node.role, node.name.clone());
+	let old_keys: Vec<_> = old.children.iter().map(key).collect();
+	let new_keys: Vec<_> = new.children.iter().map(key).collect();
+	let mut anchors = common_subsequence(&old_keys, &new_keys);
+	anchors.push((old.children.len(), new.children.len()));
+
+	let (mut i, mut j) = (0, 0);
+	for (anchor_i, anchor_j) in anchors {
+		// Pair up what lies between two matched siblings by role; the rest was added or removed.
+		let mut removed: Vec<usize> = (i..anchor_i).collect();
+		for j in j..anchor_j {
+			let role = new.children[j].role;
+			if let Some(at) = removed.iter().position(|&i| old.children[i].role == role) {
+				let i = removed.remove(at);
+				// Everything removed before the pair comes first, to keep document order.
+				for i in removed.drain(..at) {
+					let mut old_path = path.clone();
+					old_path.push(i);
+					changes.push(Change::Removed { path: old_path, node: old.children[i].clone() });
+				}
+				path.push(j);
+				diff_node(&old.children[i], &new.children[j], path, changes);
+				path.pop();
+			} else {
+				let mut new_path = path.clone();
+				new_path.push(j);
+				changes.push(Change::Added { path: new_path, node: new.children[j].clone() });
+			}
+		}
+		for i in removed {
+			let mut old_path = path.clone();
+			old_path.push(i);
+			changes.push(Change::Removed { path: old_path, node: old.children[i].clone() });
+		}
+		if anchor_i < old.children.len() {
+			path.push(anchor_j);
+			diff_node(&old.children[anchor_i], &new.children[anchor_j], path, changes);
+			path.pop();
+		}
+		(i, j) = (anchor_i + 1, anchor_j + 1);
+	}
+}
+
+/// The index pairs of a longest common subsequence of `a` and `b`.
+fn common_subsequence<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+	// Common prefixes and suffixes are the usual case, and need no table.
+	let prefix = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+	let suffix = a[prefix..]
+		.iter()
+		.rev()
+		.zip(b[prefix..].iter().rev())
+		.take_while(|(a, b)| a == b)
+		.count();
+	let (middle_a, middle_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+	// lengths[i][j] is the length of a longest common subsequence of middle_a[i..] and middle_b[j..].
+	let mut lengths = vec![vec![0usize; middle_b.len() + 1]; middle_a.len() + 1];
+	for i in (0..middle_a.len()).rev() {
+		for j in (0..middle_b.len()).rev() {
+			lengths[i][j] = if middle_a[i] == middle_b[j] {
+				lengths[i + 1][j + 1] + 1
+			} else {
+				lengths[i + 1][j].max(lengths[i][j + 1])
+			};
+		}
+	}
+
+	let mut pairs: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+	let (mut i, mut j) = (0, 0);
+	while i < middle_a.len() && j < middle_b.len() {
+		if middle_a[i] == middle_b[j] {
+			pairs.push((prefix + i, prefix + j));
+			(i, j) = (i + 1, j + 1);
+		} else if lengths[i + 1][j] >= lengths[i][j + 1] {
+			i += 1;
+		} else {
+			j += 1;
+		}
+	}
+	pairs.extend((0..suffix).map(|k| (a.len() - suffix + k, b.len() - suffix + k)));
+	pairs
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{self, Node, Tree};
+
+	fn node(role: Role, name: &str, children: Vec<NodeSnapshot>) -> NodeSnapshot {
+		NodeSnapshot {
+			role,
+			name: name.to_string(),
+			description: String::new(),
+			states: Vec::new(),
+			interfaces: Vec::new(),
+			attributes: BTreeMap::new(),
+			text: None,
+			extents: None,
+			children,
+			errors: BTreeMap::new(),
+		}
+	}
+
+	fn tree(children: Vec<NodeSnapshot>) -> TreeSnapshot {
+		TreeSnapshot { root: node(Role::Frame, "Main", children) }
+	}
+
+	#[test]
+	fn fetches_the_whole_tree() {
+		tokio_test::block_on(async {
+			let mut tree = Tree::default();
+			let root = tree.add(Node::new(Role::Frame, "Main").children(&[1, 3]));
+			tree.add(Node::new(Role::Panel, "").children(&[2]));
+			tree.add(Node::new(Role::Entry, "Search").text("cats").extents((1, 2, 3, 4)));
+			tree.add(Node::new(Role::PushButton, "OK").failing("Description"));
+			let conn = tree.serve().await;
+
+			let snapshot =
+				TreeSnapshot::fetch(&conn, &CallGuard::default(), &mock::accessible(root)).await;
+			let [first, second] = &snapshot.root.children[..] else { panic!("two children") };
+			assert_eq!(first.role, Role::Panel);
+			let entry = &first.children[0];
+			assert_eq!(entry.name, "Search");
+			assert_eq!(entry.text.as_deref(), Some("cats"));
+			assert_eq!(entry.extents, Some((1, 2, 3, 4)));
+			assert!(entry.interfaces.contains(&Interface::Text));
+			assert_eq!(second.name, "OK");
+			assert!(second.errors.contains_key("description"));
+
+			let shallow = TreeSnapshot::fetch_to_depth(
+				&conn,
+				&CallGuard::default(),
+				&mock::accessible(root),
+				1,
+			)
+			.await;
+			assert!(shallow.root.children[0].children.is_empty());
+		});
+	}
+
+	#[test]
+	fn serializes_without_noise() {
+		let snapshot = tree(vec![node(Role::PushButton, "OK", Vec::new())]);
+		let json = serde_json::to_string(&snapshot).unwrap();
+		assert_eq!(
+			json,
+			r#"{"root":{"role":"Frame","name":"Main","children":[{"role":"PushButton","name":"OK"}]}}"#
+		);
+		assert_eq!(serde_json::from_str::<TreeSnapshot>(&json).unwrap(), snapshot);
+	}
+
+	#[test]
+	fn identical_trees_have_no_differences() {
+		let a = tree(vec![node(Role::PushButton, "OK", Vec::new())]);
+		assert!(diff(&a, &a.clone()).is_empty());
+	}
+
+	#[test]
+	fn reports_added_removed_and_changed_nodes() {
+		let old = tree(vec![
+			node(Role::Label, "Title", Vec::new()),
+			node(Role::PushButton, "Cancel", Vec::new()),
+			node(Role::PushButton, "OK", Vec::new()),
+			node(Role::CheckBox, "Remember", Vec::new()),
+		]);
+		let mut remember = node(Role::CheckBox, "Remember", Vec::new());
+		remember.states = vec![State::Checked];
+		let new = tree(vec![
+			node(Role::Label, "Title", Vec::new()),
+			node(Role::Entry, "Name", Vec::new()),
+			node(Role::PushButton, "Apply", Vec::new()),
+			remember,
+		]);
+
+		let changes = diff(&old, &new);
+		let printed: Vec<String> = changes.iter().map(ToString::to_string).collect();
+		assert_eq!(
+			printed,
+			[
+				"+ /1 entry \"Name\"",
+				"~ /2 push button\n    name: \"Cancel\" -> \"Apply\"",
+				"- /2 push button \"OK\"",
+				"~ /3 check box\n    states: [] -> [Checked]",
+			]
+		);
+	}
+
+	#[test]
+	fn insertions_do_not_shift_later_siblings() {
+		let old = tree(vec![
+			node(Role::PushButton, "A", Vec::new()),
+			node(Role::PushButton, "B", Vec::new()),
+		]);
+		let new = tree(vec![
+			node(Role::PushButton, "A", Vec::new()),
+			node(Role::Separator, "", Vec::new()),
+			node(Role::PushButton, "B", Vec::new()),
+		]);
+		assert_eq!(
+			diff(&old, &new),
+			[Change::Added { path: vec![1], node: node(Role::Separator, "", Vec::new()) }]
+		);
+	}
+}
@@ -0,0 +1,441 @@
+//! A live view of the top-level windows of every accessible application.
+//!
+//! [`WindowRegistry`] loads the applications below the desktop root once,
+//! and then follows `Window:` events and changes to the desktop's children,
+//! so listing windows does not require walking the tree again.
+
+use crate::{
+	application::{desktop, is_desktop, root_of},
+	AccessibilityConnection,
+};
+use atspi_common::{
+	events::{
+		object::{ChildrenChangedEvent, ObjectEvents, Property, PropertyChangeEvent},
+		window::WindowEvents,
+		Event,
+	},
+	Accessible, AtspiError, Role, State, StateSet,
+};
+use atspi_proxies::{
+	accessible::AccessibleProxy,
+	proxy_for,
+	timeout::{CallGuard, CallTimeoutExt},
+};
+
+/// A top-level window of an application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowInfo {
+	/// The object representing the window.
+	pub accessible: Accessible,
+	/// The accessible name of the window, usually its title.
+	pub title: String,
+	/// The role of the window, for example [`Role::Frame`] or [`Role::Dialog`].
+	pub role: Role,
+	/// The states of the window, as last seen.
+	pub states: StateSet,
+}
+
+impl WindowInfo {
+	/// Whether this is the active window.
+	#[must_use]
+	pub fn is_active(&self) -> bool {
+		self.states.contains(State::Active)
+	}
+
+	/// Whether the window is minimized.
+	#[must_use]
+	pub fn is_minimized(&self) -> bool {
+		self.states.contains(State::Iconified)
+	}
+}
+
+/// An application along with its top-level windows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApplicationWindows {
+	/// The root object of the application.
+	pub application: Accessible,
+	/// The accessible name of the application.
+	pub name: String,
+	/// The top-level windows of the application, in the order they were found.
+	pub windows: Vec<WindowInfo>,
+}
+
+/// Lists every application with its top-level windows, and keeps that list up to date.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use atspi_connection::{window::WindowRegistry, AccessibilityConnection};
+/// use futures_lite::StreamExt;
+///
+/// # tokio_test::block_on(async {
+/// let connection = AccessibilityConnection::open().await.unwrap();
+/// WindowRegistry::register(&connection).await.unwrap();
+/// let mut windows = WindowRegistry::load(&connection).await.unwrap();
+///
+/// let events = connection.event_stream();
+/// futures_lite::pin!(events);
+/// while let Some(Ok(event)) = events.next().await {
+///     if windows.update(&connection, &event).await.unwrap() {
+///         for window in windows.windows() {
+///             println!("{} (active: {})", window.title, window.is_active());
+///         }
+///     }
+/// }
+/// # })
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WindowRegistry {
+	applications: Vec<ApplicationWindows>,
+	/// The unique bus name of the registry daemon, which sends the events of the desktop root.
+	registry: String,
+}
+
+impl WindowRegistry {
+	/// Registers all events the registry needs to stay up to date on `connection`.
+	///
+	/// # Errors
+	///
+	/// When any of the calls to [`AccessibilityConnection::register_event`] fail.
+	pub async fn register(connection: &AccessibilityConnection) -> Result<(), AtspiError> {
+		connection.register_event::<WindowEvents>().await?;
+		connection.register_event::<ChildrenChangedEvent>().await?;
+		connection.register_event::<PropertyChangeEvent>().await?;
+		Ok(())
+	}
+
+	/// Loads all applications and their windows, starting at the desktop root.
+	///
+	/// Applications which fail to respond, or time out under the connection's
+	/// [`call_guard`](AccessibilityConnection::call_guard), are left out.
+	///
+	/// # Errors
+	///
+	/// When the registry daemon can not be found, or the children of the desktop root
+	/// can not be retrieved.
+	pub async fn load(connection: &AccessibilityConnection) -> Result<Self, AtspiError> {
+		let (conn, guard) = (connection.connection(), connection.call_guard());
+		let root = desktop();
+		let desktop = proxy_for::<AccessibleProxy>(conn, &root).await?;
+		let mut registry =
+			Self { applications: Vec::new(), registry: connection.registry_owner().await? };
+		for application in desktop.get_children().guarded(guard, &root.name).await? {
+			if let Ok(application) = load_application(conn, guard, application).await {
+				registry.applications.push(application);
+			}
+		}
+		Ok(registry)
+	}
+
+	/// All known applications.
+	pub fn applications(&self) -> impl Iterator<Item = &ApplicationWindows> {
+		self.applications.iter()
+	}
+
+	/// All known windows, grouped by application.
+	pub fn windows(&self) -> impl Iterator<Item = &WindowInfo> {
+		self.applications.iter().flat_map(|app| app.windows.iter())
+	}
+
+	/// The active window, if any.
+	#[must_use]
+	pub fn active_window(&self) -> Option<&WindowInfo> {
+		self.windows().find(|window| window.is_active())
+	}
+
+	/// The application a window belongs to.
+	#[must_use]
+	pub fn application_of(&self, window: &Accessible) -> Option<&ApplicationWindows> {
+		self.applications
+			.iter()
+			.find(|app| app.application.name == window.name)
+	}
+
+	/// Updates the registry with `event`, querying the bus for new windows and applications.
+	///
+	/// Returns whether the registry changed.
+	///
+	/// # Errors
+	///
+	/// When a newly created window or application can not be queried.
+	pub async fn update(
+		&mut self,
+		connection: &AccessibilityConnection,
+		event: &Event,
+	) -> Result<bool, AtspiError> {
+		let (conn, guard) = (connection.connection(), connection.call_guard());
+		match event {
+			Event::Window(WindowEvents::Create(ev)) => {
+				if self.window_mut(&ev.item).is_some() {
+					return Ok(false);
+				}
+				let window = load_window(conn, guard, ev.item.clone()).await?;
+				if let Some(app) = self
+					.applications
+					.iter_mut()
+					.find(|app| app.application.name == ev.item.name)
+				{
+					app.windows.push(window);
+				} else {
+					let mut app = load_application(conn, guard, root_of(&ev.item.name)).await?;
+					if !app.windows.iter().any(|known| known.accessible == window.accessible) {
+						app.windows.push(window);
+					}
+					self.applications.push(app);
+				}
+				Ok(true)
+			}
+			Event::Object(ObjectEvents::ChildrenChanged(ev))
+				if is_desktop(&ev.item, &self.registry) && ev.operation.starts_with("add") =>
+			{
+				if self
+					.applications
+					.iter()
+					.any(|app| app.application.name == ev.child.name)
+				{
+					return Ok(false);
+				}
+				let app = load_application(conn, guard, ev.child.clone()).await?;
+				self.applications.push(app);
+				Ok(true)
+			}
+			_ => Ok(self.apply(event)),
+		}
+	}
+
+	/// Applies the changes `event` describes that need no further information from the bus.
+	///
+	/// Returns whether the registry changed.
+	pub fn apply(&mut self, event: &Event) -> bool {
+		match event {
+			Event::Window(WindowEvents::Destroy(ev)) => self.remove_window(&ev.item),
+			Event::Window(WindowEvents::Close(ev)) => self.remove_window(&ev.item),
+			Event::Window(WindowEvents::Activate(ev)) => {
+				let mut changed = false;
+				for window in self.applications.iter_mut().flat_map(|app| app.windows.iter_mut()) {
+					if window.accessible != ev.item && window.is_active() {
+						window.states.remove(State::Active);
+						changed = true;
+					}
+				}
+				self.set_state(&ev.item, State::Active, true) || changed
+			}
+			Event::Window(WindowEvents::Deactivate(ev)) => {
+				self.set_state(&ev.item, State::Active, false)
+			}
+			Event::Window(WindowEvents::Minimize(ev)) => {
+				self.set_state(&ev.item, State::Iconified, true)
+			}
+			Event::Window(WindowEvents::Restore(ev)) => {
+				self.set_state(&ev.item, State::Iconified, false)
+			}
+			Event::Object(ObjectEvents::PropertyChange(ev)) => match &ev.value {
+				Property::Name(name) => match self.window_mut(&ev.item) {
+					Some(window) if window.title != *name => {
+						window.title = name.clone();
+						true
+					}
+					_ => false,
+				},
+				_ => false,
+			},
+			Event::Object(ObjectEvents::ChildrenChanged(ev))
+				if is_desktop(&ev.item, &self.registry) && ev.operation.starts_with("remove") =>
+			{
+				let before = self.applications.len();
+				self.applications.retain(|app| app.application.name != ev.child.name);
+				before != self.applications.len()
+			}
+			_ => false,
+		}
+	}
+
+	fn window_mut(&mut self, item: &Accessible) -> Option<&mut WindowInfo> {
+		self.applications
+			.iter_mut()
+			.flat_map(|app| app.windows.iter_mut())
+			.find(|window| window.accessible == *item)
+	}
+
+	fn remove_window(&mut self, item: &Accessible) -> bool {
+		let Some(app) = self
+			.applications
+			.iter_mut()
+			.find(|app| app.application.name == item.name)
+		else {
+			return false;
+		};
+		let before = app.windows.len();
+		app.windows.retain(|window| window.accessible != *item);
+		before != app.windows.len()
+	}
+
+	fn set_state(&mut self, item: &Accessible, state: State, enabled: bool) -> bool {
+		let Some(window) = self.window_mut(item) else {
+			return false;
+		};
+		if window.states.contains(state) == enabled {
+			return false;
+		}
+		if enabled {
+			window.states.insert(state);
+		} else {
+			window.states.remove(state);
+		}
+		true
+	}
+}
+
+async fn load_application(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	application: Accessible,
+) -> Result<ApplicationWindows, AtspiError> {
+	let proxy = proxy_for::<AccessibleProxy>(conn, &application).await?;
+	let name = proxy.name().guarded(guard, &application.name).await?;
+	let mut windows = Vec::new();
+	for child in proxy.get_children().guarded(guard, &application.name).await? {
+		if let Ok(window) = load_window(conn, guard, child).await {
+			windows.push(window);
+		}
+	}
+	Ok(ApplicationWindows { application, name, windows })
+}
+
+async fn load_window(
+	conn: &zbus::Connection,
+	guard: &CallGuard,
+	window: Accessible,
+) -> Result<WindowInfo, AtspiError> {
+	let proxy = proxy_for::<AccessibleProxy>(conn, &window).await?;
+	let bus_name = window.name.as_str();
+	Ok(WindowInfo {
+		title: proxy.name().guarded(guard, bus_name).await?,
+		role: proxy.get_role().guarded(guard, bus_name).await?,
+		states: proxy.get_state().guarded(guard, bus_name).await?,
+		accessible: window,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ApplicationWindows, WindowInfo, WindowRegistry};
+	use atspi_common::{
+		events::{
+			object::{ChildrenChangedEvent, Property, PropertyChangeEvent},
+			window::{ActivateEvent, DestroyEvent, MinimizeEvent, RestoreEvent},
+			Event,
+		},
+		Accessible, Role, StateSet,
+	};
+
+	fn object(name: &str, path: &str) -> Accessible {
+		Accessible { name: name.into(), path: path.try_into().unwrap() }
+	}
+
+	fn window(name: &str, path: &str, title: &str) -> WindowInfo {
+		WindowInfo {
+			accessible: object(name, path),
+			title: title.into(),
+			role: Role::Frame,
+			states: StateSet::empty(),
+		}
+	}
+
+	fn registry() -> WindowRegistry {
+		WindowRegistry {
+			applications: vec![
+				ApplicationWindows {
+					application: object(":1.1", "/org/a11y/atspi/accessible/root"),
+					name: "editor".into(),
+					windows: vec![
+						window(":1.1", "/org/a11y/atspi/accessible/1", "Document"),
+						window(":1.1", "/org/a11y/atspi/accessible/2", "Preferences"),
+					],
+				},
+				ApplicationWindows {
+					application: object(":1.2", "/org/a11y/atspi/accessible/root"),
+					name: "terminal".into(),
+					windows: vec![window(":1.2", "/org/a11y/atspi/accessible/1", "Shell")],
+				},
+			],
+			registry: ":1.0".into(),
+		}
+	}
+
+	#[test]
+	fn activation_moves_between_windows() {
+		let mut registry = registry();
+		let document = object(":1.1", "/org/a11y/atspi/accessible/1");
+		let shell = object(":1.2", "/org/a11y/atspi/accessible/1");
+		assert!(registry.apply(&ActivateEvent { item: document.clone() }.into()));
+		assert_eq!(registry.active_window().unwrap().accessible, document);
+		assert!(registry.apply(&ActivateEvent { item: shell.clone() }.into()));
+		assert_eq!(registry.active_window().unwrap().accessible, shell);
+		assert_eq!(registry.windows().filter(|window| window.is_active()).count(), 1);
+		assert_eq!(registry.application_of(&shell).unwrap().name, "terminal");
+	}
+
+	#[test]
+	fn minimize_and_restore() {
+		let mut registry = registry();
+		let shell = object(":1.2", "/org/a11y/atspi/accessible/1");
+		assert!(registry.apply(&MinimizeEvent { item: shell.clone() }.into()));
+		assert!(!registry.apply(&MinimizeEvent { item: shell.clone() }.into()));
+		assert!(registry.windows().any(WindowInfo::is_minimized));
+		assert!(registry.apply(&RestoreEvent { item: shell }.into()));
+		assert!(!registry.windows().any(WindowInfo::is_minimized));
+	}
+
+	#[test]
+	fn destroyed_windows_are_removed() {
+		let mut registry = registry();
+		let preferences = object(":1.1", "/org/a11y/atspi/accessible/2");
+		assert!(registry.apply(&DestroyEvent { item: preferences.clone() }.into()));
+		assert!(!registry.apply(&DestroyEvent { item: preferences }.into()));
+		assert_eq!(registry.windows().count(), 2);
+	}
+
+	#[test]
+	fn title_follows_name_changes() {
+		let mut registry = registry();
+		let document = object(":1.1", "/org/a11y/atspi/accessible/1");
+		let event: Event = PropertyChangeEvent {
+			item: document,
+			property: "accessible-name".into(),
+			value: Property::Name("Document (modified)".into()),
+		}
+		.into();
+		assert!(registry.apply(&event));
+		assert_eq!(registry.windows().next().unwrap().title, "Document (modified)");
+	}
+
+	#[test]
+	fn applications_leaving_the_desktop_are_removed() {
+		let mut registry = registry();
+		let event: Event = ChildrenChangedEvent {
+			item: object(":1.0", "/org/a11y/atspi/accessible/root"),
+			operation: "remove".into(),
+			index_in_parent: 1,
+			child: object(":1.2", "/org/a11y/atspi/accessible/root"),
+		}
+		.into();
+		assert!(registry.apply(&event));
+		assert_eq!(registry.applications().count(), 1);
+	}
+
+	#[test]
+	fn children_of_application_roots_are_not_applications() {
+		let mut registry = registry();
+		// The root of the editor losing a child is not the editor leaving the desktop.
+		let event: Event = ChildrenChangedEvent {
+			item: object(":1.1", "/org/a11y/atspi/accessible/root"),
+			operation: "remove".into(),
+			index_in_parent: 0,
+			child: object(":1.2", "/org/a11y/atspi/accessible/root"),
+		}
+		.into();
+		assert!(!registry.apply(&event));
+		assert_eq!(registry.applications().count(), 2);
+	}
+}
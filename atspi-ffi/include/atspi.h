@@ -0,0 +1,610 @@
+/*
+ * C API for the atspi client library.
+ *
+ * Ownership:
+ * - Pointers passed into the library are borrowed for the duration of the call only.
+ * - Every `char *` handed out is owned by the caller; release it with atspi_string_free().
+ * - Every `AtspiAccessible *` handed out is owned by the caller; release it with atspi_accessible_free().
+ * - Connections and listeners are released with atspi_connection_free() and atspi_listener_free().
+ *   A listener keeps its connection alive, so the two may be released in any order.
+ * - The pointers inside an AtspiEvent are only valid until the callback returns.
+ *
+ * Every fallible function returns an AtspiStatus. Results are written through out-pointers,
+ * which are left untouched on failure. atspi_last_error_message() describes the last failure
+ * on the calling thread.
+ */
+
+#ifndef ATSPI_H
+#define ATSPI_H
+
+/* This file is generated from atspi-ffi/src by `cargo xtask codegen`; do not edit it. */
+
+#include <stdbool.h>
+#include <stdint.h>
+
+/**
+ * A reference to a remote object: the bus name of its application, and its object path.
+ *
+ * Handles are plain values; holding one does not keep the remote object alive.
+ */
+typedef struct AtspiAccessible AtspiAccessible;
+
+/**
+ * A connection to the accessibility bus.
+ */
+typedef struct AtspiConnection AtspiConnection;
+
+/**
+ * Delivers events to a callback on a background thread, until released.
+ */
+typedef struct AtspiListener AtspiListener;
+
+/**
+ * The outcome of a call.
+ */
+typedef int32_t AtspiStatus;
+
+/**
+ * The frame of reference for coordinates.
+ */
+typedef uint32_t AtspiCoordType;
+
+/**
+ * A group of events which can be registered for at once.
+ */
+typedef uint32_t AtspiEventCategory;
+
+/**
+ * An event, as passed to an [`AtspiEventCallback`].
+ *
+ * All pointers are borrowed, and only valid until the callback returns.
+ */
+typedef struct AtspiEvent {
+  /**
+   * The interface, without the `org.a11y.atspi.Event.` prefix; for example `"Object"`.
+   */
+  const char *interface;
+  /**
+   * The signal; for example `"StateChanged"`.
+   */
+  const char *member;
+  /**
+   * The kind, or minor; for example `"focused"`. May be empty.
+   */
+  const char *kind;
+  int32_t detail1;
+  int32_t detail2;
+  /**
+   * `any_data` if it holds a string, `NULL` otherwise.
+   */
+  const char *any_data;
+  /**
+   * The object that emitted the event.
+   */
+  const struct AtspiAccessible *source;
+} AtspiEvent;
+
+/**
+ * Called for every event received by an [`AtspiListener`], on a thread owned by the listener.
+ */
+typedef void (*AtspiEventCallback)(const struct AtspiEvent *event, void *user_data);
+
+/**
+ * The call succeeded.
+ */
+#define ATSPI_STATUS_OK 0
+
+/**
+ * A required pointer argument was `NULL`.
+ */
+#define ATSPI_STATUS_NULL_ARGUMENT 1
+
+/**
+ * An argument was out of range, not valid UTF-8, or not a valid bus name or object path.
+ */
+#define ATSPI_STATUS_INVALID_ARGUMENT 2
+
+/**
+ * The call over D-Bus failed, or its reply could not be understood.
+ */
+#define ATSPI_STATUS_ERROR 3
+
+/**
+ * The library panicked. This is a bug.
+ */
+#define ATSPI_STATUS_PANIC 4
+
+/**
+ * Coordinates are relative to the screen.
+ */
+#define ATSPI_COORD_TYPE_SCREEN 0
+
+/**
+ * Coordinates are relative to the window.
+ */
+#define ATSPI_COORD_TYPE_WINDOW 1
+
+/**
+ * Coordinates are relative to the parent of the object.
+ */
+#define ATSPI_COORD_TYPE_PARENT 2
+
+/**
+ * Events on the `org.a11y.atspi.Event.Object` interface.
+ */
+#define ATSPI_EVENT_CATEGORY_OBJECT 0
+
+/**
+ * Events on the `org.a11y.atspi.Event.Window` interface.
+ */
+#define ATSPI_EVENT_CATEGORY_WINDOW 1
+
+/**
+ * Events on the `org.a11y.atspi.Event.Focus` interface.
+ */
+#define ATSPI_EVENT_CATEGORY_FOCUS 2
+
+/**
+ * Events on the `org.a11y.atspi.Event.Document` interface.
+ */
+#define ATSPI_EVENT_CATEGORY_DOCUMENT 3
+
+/**
+ * Events on the `org.a11y.atspi.Event.Keyboard` interface.
+ */
+#define ATSPI_EVENT_CATEGORY_KEYBOARD 4
+
+/**
+ * Events on the `org.a11y.atspi.Event.Mouse` interface.
+ */
+#define ATSPI_EVENT_CATEGORY_MOUSE 5
+
+/**
+ * Events on the `org.a11y.atspi.Event.Terminal` interface.
+ */
+#define ATSPI_EVENT_CATEGORY_TERMINAL 6
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns a description of the last failed call on this thread, or `NULL` if the last call succeeded.
+ *
+ * The returned string must be released with [`atspi_string_free`].
+ */
+char *atspi_last_error_message(void);
+
+/**
+ * Releases a string handed out by this library.
+ *
+ * # Safety
+ *
+ * `string` must be `NULL`, or a string returned by this library which has not been released yet.
+ */
+void atspi_string_free(char *string);
+
+/**
+ * Creates a handle for the object at `path`, owned by the application with bus name `bus_name`.
+ *
+ * Release the handle with [`atspi_accessible_free`].
+ *
+ * # Safety
+ *
+ * `bus_name` and `path` must be NUL-terminated strings, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_new(const char *bus_name,
+                                 const char *path,
+                                 struct AtspiAccessible **out);
+
+/**
+ * Creates a handle for the desktop root, whose children are the root objects of all applications.
+ *
+ * Release the handle with [`atspi_accessible_free`].
+ */
+struct AtspiAccessible *atspi_accessible_desktop(void);
+
+/**
+ * Creates a copy of `accessible`, or returns `NULL` if `accessible` is `NULL`.
+ *
+ * Release the copy with [`atspi_accessible_free`].
+ *
+ * # Safety
+ *
+ * `accessible` must be `NULL` or a valid handle.
+ */
+struct AtspiAccessible *atspi_accessible_clone(const struct AtspiAccessible *accessible);
+
+/**
+ * Releases a handle.
+ *
+ * # Safety
+ *
+ * `accessible` must be `NULL`, or a handle handed out by this library which has not been released yet.
+ */
+void atspi_accessible_free(struct AtspiAccessible *accessible);
+
+/**
+ * Whether two handles refer to the same object. `NULL` only equals `NULL`.
+ *
+ * # Safety
+ *
+ * Both arguments must be `NULL` or valid handles.
+ */
+bool atspi_accessible_equal(const struct AtspiAccessible *a, const struct AtspiAccessible *b);
+
+/**
+ * Whether `accessible` is the null object, which methods return when there is no object to return.
+ *
+ * # Safety
+ *
+ * `accessible` must be a valid handle.
+ */
+bool atspi_accessible_is_null(const struct AtspiAccessible *accessible);
+
+/**
+ * Returns the bus name of the application owning `accessible`, or `NULL` if `accessible` is `NULL`.
+ *
+ * The returned string must be released with [`atspi_string_free`](crate::atspi_string_free).
+ *
+ * # Safety
+ *
+ * `accessible` must be `NULL` or a valid handle.
+ */
+char *atspi_accessible_bus_name(const struct AtspiAccessible *accessible);
+
+/**
+ * Returns the object path of `accessible`, or `NULL` if `accessible` is `NULL`.
+ *
+ * The returned string must be released with [`atspi_string_free`](crate::atspi_string_free).
+ *
+ * # Safety
+ *
+ * `accessible` must be `NULL` or a valid handle.
+ */
+char *atspi_accessible_path(const struct AtspiAccessible *accessible);
+
+/**
+ * Reads the `Name` property.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_name(const struct AtspiConnection *connection,
+                                      const struct AtspiAccessible *accessible,
+                                      char **out);
+
+/**
+ * Reads the `Description` property.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_description(const struct AtspiConnection *connection,
+                                             const struct AtspiAccessible *accessible,
+                                             char **out);
+
+/**
+ * Gets the role, as the numeric value of `AtspiRole` in libatspi.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_role(const struct AtspiConnection *connection,
+                                      const struct AtspiAccessible *accessible,
+                                      uint32_t *out);
+
+/**
+ * Gets the name of the role, not translated.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_role_name(const struct AtspiConnection *connection,
+                                           const struct AtspiAccessible *accessible,
+                                           char **out);
+
+/**
+ * Gets the states, as a bit set indexed by the numeric values of `AtspiStateType` in libatspi.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_state(const struct AtspiConnection *connection,
+                                       const struct AtspiAccessible *accessible,
+                                       uint64_t *out);
+
+/**
+ * Reads the `ChildCount` property.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_child_count(const struct AtspiConnection *connection,
+                                             const struct AtspiAccessible *accessible,
+                                             int32_t *out);
+
+/**
+ * Gets the child at `index`.
+ *
+ * The handle written to `out` must be released with [`atspi_accessible_free`].
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_child_at_index(const struct AtspiConnection *connection,
+                                                const struct AtspiAccessible *accessible,
+                                                int32_t index,
+                                                struct AtspiAccessible **out);
+
+/**
+ * Gets the index of `accessible` among the children of its parent.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_index_in_parent(const struct AtspiConnection *connection,
+                                                 const struct AtspiAccessible *accessible,
+                                                 int32_t *out);
+
+/**
+ * Reads the `Parent` property.
+ *
+ * The handle written to `out` must be released with [`atspi_accessible_free`].
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_parent(const struct AtspiConnection *connection,
+                                        const struct AtspiAccessible *accessible,
+                                        struct AtspiAccessible **out);
+
+/**
+ * Gets the root object of the application `accessible` belongs to.
+ *
+ * The handle written to `out` must be released with [`atspi_accessible_free`].
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_accessible_get_application(const struct AtspiConnection *connection,
+                                             const struct AtspiAccessible *accessible,
+                                             struct AtspiAccessible **out);
+
+/**
+ * Reads the `NActions` property.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_action_get_n_actions(const struct AtspiConnection *connection,
+                                       const struct AtspiAccessible *accessible,
+                                       int32_t *out);
+
+/**
+ * Gets the name of the action at `index`, not translated.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_action_get_name(const struct AtspiConnection *connection,
+                                  const struct AtspiAccessible *accessible,
+                                  int32_t index,
+                                  char **out);
+
+/**
+ * Gets the description of the action at `index`.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_action_get_description(const struct AtspiConnection *connection,
+                                         const struct AtspiAccessible *accessible,
+                                         int32_t index,
+                                         char **out);
+
+/**
+ * Performs the action at `index`. Writes whether that succeeded to `out`.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_action_do_action(const struct AtspiConnection *connection,
+                                   const struct AtspiAccessible *accessible,
+                                   int32_t index,
+                                   bool *out);
+
+/**
+ * Gets the position and size of `accessible`.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and the out-pointers valid for writes.
+ */
+AtspiStatus atspi_component_get_extents(const struct AtspiConnection *connection,
+                                        const struct AtspiAccessible *accessible,
+                                        AtspiCoordType coord_type,
+                                        int32_t *x,
+                                        int32_t *y,
+                                        int32_t *width,
+                                        int32_t *height);
+
+/**
+ * Whether the point at `x`, `y` lies within `accessible`.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_component_contains(const struct AtspiConnection *connection,
+                                     const struct AtspiAccessible *accessible,
+                                     int32_t x,
+                                     int32_t y,
+                                     AtspiCoordType coord_type,
+                                     bool *out);
+
+/**
+ * Gets the descendant of `accessible` at the point `x`, `y`.
+ *
+ * Writes the null object when there is none; see `atspi_accessible_is_null`.
+ * The handle written to `out` must be released with `atspi_accessible_free`.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_component_get_accessible_at_point(const struct AtspiConnection *connection,
+                                                    const struct AtspiAccessible *accessible,
+                                                    int32_t x,
+                                                    int32_t y,
+                                                    AtspiCoordType coord_type,
+                                                    struct AtspiAccessible **out);
+
+/**
+ * Asks for `accessible` to receive keyboard focus. Writes whether that succeeded to `out`.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_component_grab_focus(const struct AtspiConnection *connection,
+                                       const struct AtspiAccessible *accessible,
+                                       bool *out);
+
+/**
+ * Opens a connection to the accessibility bus, and writes it to `out`.
+ *
+ * Release the connection with [`atspi_connection_free`].
+ *
+ * # Safety
+ *
+ * `out` must be valid for writes.
+ */
+AtspiStatus atspi_connection_open(struct AtspiConnection **out);
+
+/**
+ * Releases a connection.
+ *
+ * # Safety
+ *
+ * `connection` must be `NULL`, or a connection returned by [`atspi_connection_open`] which has
+ * not been released yet.
+ */
+void atspi_connection_free(struct AtspiConnection *connection);
+
+/**
+ * Asks applications to emit the events in `category`, and subscribes to them.
+ *
+ * # Safety
+ *
+ * `connection` must be a valid connection.
+ */
+AtspiStatus atspi_connection_register_event(const struct AtspiConnection *connection,
+                                            AtspiEventCategory category);
+
+/**
+ * Undoes [`atspi_connection_register_event`].
+ *
+ * # Safety
+ *
+ * `connection` must be a valid connection.
+ */
+AtspiStatus atspi_connection_deregister_event(const struct AtspiConnection *connection,
+                                              AtspiEventCategory category);
+
+/**
+ * Starts delivering the events received on `connection` to `callback`, and writes the listener to `out`.
+ *
+ * `callback` runs on a thread owned by the listener, one event at a time.
+ * It receives `user_data` unchanged. Release the listener with [`atspi_listener_free`].
+ *
+ * # Safety
+ *
+ * `connection` must be a valid connection, and `out` valid for writes.
+ * `callback` must be safe to call from another thread with `user_data`, until the listener is released.
+ */
+AtspiStatus atspi_connection_listen(const struct AtspiConnection *connection,
+                                    AtspiEventCallback callback,
+                                    void *user_data,
+                                    struct AtspiListener **out);
+
+/**
+ * Stops a listener, and releases it.
+ *
+ * Once this returns, the callback is not called anymore, and the listener thread has ended.
+ * If the callback is running, this waits for it to return; so do not call it from within the
+ * callback.
+ *
+ * # Safety
+ *
+ * `listener` must be `NULL`, or a listener returned by [`atspi_connection_listen`] which has
+ * not been released yet.
+ */
+void atspi_listener_free(struct AtspiListener *listener);
+
+/**
+ * Reads the `CharacterCount` property.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_text_get_character_count(const struct AtspiConnection *connection,
+                                           const struct AtspiAccessible *accessible,
+                                           int32_t *out);
+
+/**
+ * Gets the text between the character offsets `start` and `end`. An `end` of -1 means the end of the text.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_text_get_text(const struct AtspiConnection *connection,
+                                const struct AtspiAccessible *accessible,
+                                int32_t start,
+                                int32_t end,
+                                char **out);
+
+/**
+ * Reads the `CaretOffset` property.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_text_get_caret_offset(const struct AtspiConnection *connection,
+                                        const struct AtspiAccessible *accessible,
+                                        int32_t *out);
+
+/**
+ * Moves the caret to `offset`. Writes whether that succeeded to `out`.
+ *
+ * # Safety
+ *
+ * `connection` and `accessible` must be valid, and `out` valid for writes.
+ */
+AtspiStatus atspi_text_set_caret_offset(const struct AtspiConnection *connection,
+                                        const struct AtspiAccessible *accessible,
+                                        int32_t offset,
+                                        bool *out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* ATSPI_H */
@@ -0,0 +1,381 @@
+//! Handles to remote objects, and the `org.a11y.atspi.Accessible` interface.
+
+use crate::{
+	borrow, borrow_str, into_c_string, run, write, write_string, AtspiConnection, AtspiStatus,
+	Error,
+};
+use atspi_common::Accessible;
+use atspi_connection::application::desktop;
+use atspi_proxies::{accessible::AccessibleProxyBlocking, proxy_for_blocking};
+use std::ffi::c_char;
+
+/// A reference to a remote object: the bus name of its application, and its object path.
+///
+/// Handles are plain values; holding one does not keep the remote object alive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtspiAccessible(pub(crate) Accessible);
+
+/// Creates a proxy of type `T` for `accessible` on `connection`.
+///
+/// # Safety
+///
+/// Both pointers must be `NULL` or valid.
+pub(crate) unsafe fn proxy<T>(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+) -> Result<T, Error>
+where
+	T: From<zbus::Proxy<'static>> + zbus::ProxyDefault,
+{
+	let connection = borrow(connection, "connection")?;
+	let accessible = borrow(accessible, "accessible")?;
+	Ok(proxy_for_blocking(connection.0.connection(), &accessible.0)?)
+}
+
+/// Writes a new handle for `accessible` to `out`.
+///
+/// # Safety
+///
+/// `out` must be `NULL`, or valid for writes.
+pub(crate) unsafe fn write_accessible(
+	out: *mut *mut AtspiAccessible,
+	accessible: Accessible,
+) -> Result<(), Error> {
+	write(out, Box::into_raw(Box::new(AtspiAccessible(accessible))))
+}
+
+/// Creates a handle for the object at `path`, owned by the application with bus name `bus_name`.
+///
+/// Release the handle with [`atspi_accessible_free`].
+///
+/// # Safety
+///
+/// `bus_name` and `path` must be NUL-terminated strings, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_new(
+	bus_name: *const c_char,
+	path: *const c_char,
+	out: *mut *mut AtspiAccessible,
+) -> AtspiStatus {
+	run(|| {
+		let name = zbus::names::BusName::try_from(borrow_str(bus_name, "bus_name")?)
+			.map_err(|e| Error::InvalidArgument(format!("invalid bus name: {e}")))?;
+		let path = zbus::zvariant::ObjectPath::try_from(borrow_str(path, "path")?)
+			.map_err(|e| Error::InvalidArgument(format!("invalid object path: {e}")))?;
+		write_accessible(out, Accessible { name: name.to_string(), path: path.into() })
+	})
+}
+
+/// Creates a handle for the desktop root, whose children are the root objects of all applications.
+///
+/// Release the handle with [`atspi_accessible_free`].
+#[no_mangle]
+pub extern "C" fn atspi_accessible_desktop() -> *mut AtspiAccessible {
+	Box::into_raw(Box::new(AtspiAccessible(desktop())))
+}
+
+/// Creates a copy of `accessible`, or returns `NULL` if `accessible` is `NULL`.
+///
+/// Release the copy with [`atspi_accessible_free`].
+///
+/// # Safety
+///
+/// `accessible` must be `NULL` or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_clone(
+	accessible: *const AtspiAccessible,
+) -> *mut AtspiAccessible {
+	accessible
+		.as_ref()
+		.map_or(std::ptr::null_mut(), |accessible| Box::into_raw(Box::new(accessible.clone())))
+}
+
+/// Releases a handle.
+///
+/// # Safety
+///
+/// `accessible` must be `NULL`, or a handle handed out by this library which has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_free(accessible: *mut AtspiAccessible) {
+	if !accessible.is_null() {
+		drop(Box::from_raw(accessible));
+	}
+}
+
+/// Whether two handles refer to the same object. `NULL` only equals `NULL`.
+///
+/// # Safety
+///
+/// Both arguments must be `NULL` or valid handles.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_equal(
+	a: *const AtspiAccessible,
+	b: *const AtspiAccessible,
+) -> bool {
+	a.as_ref() == b.as_ref()
+}
+
+/// Whether `accessible` is the null object, which methods return when there is no object to return.
+///
+/// # Safety
+///
+/// `accessible` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_is_null(accessible: *const AtspiAccessible) -> bool {
+	accessible.as_ref().map_or(true, |accessible| accessible.0.is_null())
+}
+
+/// Returns the bus name of the application owning `accessible`, or `NULL` if `accessible` is `NULL`.
+///
+/// The returned string must be released with [`atspi_string_free`](crate::atspi_string_free).
+///
+/// # Safety
+///
+/// `accessible` must be `NULL` or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_bus_name(
+	accessible: *const AtspiAccessible,
+) -> *mut c_char {
+	accessible
+		.as_ref()
+		.map_or(std::ptr::null_mut(), |accessible| into_c_string(accessible.0.name.clone()))
+}
+
+/// Returns the object path of `accessible`, or `NULL` if `accessible` is `NULL`.
+///
+/// The returned string must be released with [`atspi_string_free`](crate::atspi_string_free).
+///
+/// # Safety
+///
+/// `accessible` must be `NULL` or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_path(accessible: *const AtspiAccessible) -> *mut c_char {
+	accessible
+		.as_ref()
+		.map_or(std::ptr::null_mut(), |accessible| into_c_string(accessible.0.path.to_string()))
+}
+
+/// Reads the `Name` property.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_name(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut *mut c_char,
+) -> AtspiStatus {
+	run(|| write_string(out, proxy::<AccessibleProxyBlocking>(connection, accessible)?.name()?))
+}
+
+/// Reads the `Description` property.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_description(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut *mut c_char,
+) -> AtspiStatus {
+	run(|| {
+		write_string(out, proxy::<AccessibleProxyBlocking>(connection, accessible)?.description()?)
+	})
+}
+
+/// Gets the role, as the numeric value of `AtspiRole` in libatspi.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_role(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut u32,
+) -> AtspiStatus {
+	run(|| write(out, proxy::<AccessibleProxyBlocking>(connection, accessible)?.get_role()? as u32))
+}
+
+/// Gets the name of the role, not translated.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_role_name(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut *mut c_char,
+) -> AtspiStatus {
+	run(|| {
+		write_string(
+			out,
+			proxy::<AccessibleProxyBlocking>(connection, accessible)?.get_role_name()?,
+		)
+	})
+}
+
+/// Gets the states, as a bit set indexed by the numeric values of `AtspiStateType` in libatspi.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_state(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut u64,
+) -> AtspiStatus {
+	run(|| {
+		write(
+			out,
+			proxy::<AccessibleProxyBlocking>(connection, accessible)?
+				.get_state()?
+				.bits(),
+		)
+	})
+}
+
+/// Reads the `ChildCount` property.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_child_count(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut i32,
+) -> AtspiStatus {
+	run(|| write(out, proxy::<AccessibleProxyBlocking>(connection, accessible)?.child_count()?))
+}
+
+/// Gets the child at `index`.
+///
+/// The handle written to `out` must be released with [`atspi_accessible_free`].
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_child_at_index(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	index: i32,
+	out: *mut *mut AtspiAccessible,
+) -> AtspiStatus {
+	run(|| {
+		let proxy = proxy::<AccessibleProxyBlocking>(connection, accessible)?;
+		write_accessible(out, proxy.get_child_at_index(index)?)
+	})
+}
+
+/// Gets the index of `accessible` among the children of its parent.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_index_in_parent(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut i32,
+) -> AtspiStatus {
+	run(|| {
+		write(out, proxy::<AccessibleProxyBlocking>(connection, accessible)?.get_index_in_parent()?)
+	})
+}
+
+/// Reads the `Parent` property.
+///
+/// The handle written to `out` must be released with [`atspi_accessible_free`].
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_parent(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut *mut AtspiAccessible,
+) -> AtspiStatus {
+	run(|| {
+		write_accessible(out, proxy::<AccessibleProxyBlocking>(connection, accessible)?.parent()?)
+	})
+}
+
+/// Gets the root object of the application `accessible` belongs to.
+///
+/// The handle written to `out` must be released with [`atspi_accessible_free`].
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_accessible_get_application(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut *mut AtspiAccessible,
+) -> AtspiStatus {
+	run(|| {
+		let proxy = proxy::<AccessibleProxyBlocking>(connection, accessible)?;
+		write_accessible(out, proxy.get_application()?)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		atspi_accessible_bus_name, atspi_accessible_clone, atspi_accessible_equal,
+		atspi_accessible_free, atspi_accessible_new, atspi_accessible_path, AtspiAccessible,
+	};
+	use crate::{atspi_string_free, ATSPI_STATUS_INVALID_ARGUMENT, ATSPI_STATUS_OK};
+	use std::{ffi::CStr, ptr};
+
+	fn take_string(string: *mut std::ffi::c_char) -> String {
+		let copy = unsafe { CStr::from_ptr(string) }.to_str().unwrap().to_string();
+		unsafe { atspi_string_free(string) };
+		copy
+	}
+
+	#[test]
+	fn handles_round_trip() {
+		let mut accessible: *mut AtspiAccessible = ptr::null_mut();
+		let status = unsafe {
+			atspi_accessible_new(
+				b":1.7\0".as_ptr().cast(),
+				b"/org/a11y/atspi/accessible/3\0".as_ptr().cast(),
+				&mut accessible,
+			)
+		};
+		assert_eq!(status, ATSPI_STATUS_OK);
+		let copy = unsafe { atspi_accessible_clone(accessible) };
+		assert!(unsafe { atspi_accessible_equal(accessible, copy) });
+		assert_eq!(take_string(unsafe { atspi_accessible_bus_name(copy) }), ":1.7");
+		assert_eq!(
+			take_string(unsafe { atspi_accessible_path(copy) }),
+			"/org/a11y/atspi/accessible/3"
+		);
+		unsafe {
+			atspi_accessible_free(accessible);
+			atspi_accessible_free(copy);
+		}
+	}
+
+	#[test]
+	fn invalid_paths_are_rejected() {
+		let mut accessible: *mut AtspiAccessible = ptr::null_mut();
+		let status = unsafe {
+			atspi_accessible_new(
+				b":1.7\0".as_ptr().cast(),
+				b"no/slash\0".as_ptr().cast(),
+				&mut accessible,
+			)
+		};
+		assert_eq!(status, ATSPI_STATUS_INVALID_ARGUMENT);
+		assert!(accessible.is_null());
+	}
+}
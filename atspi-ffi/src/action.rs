@@ -0,0 +1,71 @@
+//! The `org.a11y.atspi.Action` interface.
+
+use crate::{
+	accessible::proxy, run, write, write_string, AtspiAccessible, AtspiConnection, AtspiStatus,
+};
+use atspi_proxies::action::ActionProxyBlocking;
+use std::ffi::c_char;
+
+/// Reads the `NActions` property.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_action_get_n_actions(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut i32,
+) -> AtspiStatus {
+	run(|| write(out, proxy::<ActionProxyBlocking>(connection, accessible)?.nactions()?))
+}
+
+/// Gets the name of the action at `index`, not translated.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_action_get_name(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	index: i32,
+	out: *mut *mut c_char,
+) -> AtspiStatus {
+	run(|| {
+		write_string(out, proxy::<ActionProxyBlocking>(connection, accessible)?.get_name(index)?)
+	})
+}
+
+/// Gets the description of the action at `index`.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_action_get_description(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	index: i32,
+	out: *mut *mut c_char,
+) -> AtspiStatus {
+	run(|| {
+		let proxy = proxy::<ActionProxyBlocking>(connection, accessible)?;
+		write_string(out, proxy.get_description(index)?)
+	})
+}
+
+/// Performs the action at `index`. Writes whether that succeeded to `out`.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_action_do_action(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	index: i32,
+	out: *mut bool,
+) -> AtspiStatus {
+	run(|| write(out, proxy::<ActionProxyBlocking>(connection, accessible)?.do_action(index)?))
+}
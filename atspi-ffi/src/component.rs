@@ -0,0 +1,115 @@
+//! The `org.a11y.atspi.Component` interface.
+
+use crate::{
+	accessible::{proxy, write_accessible},
+	run, write, AtspiAccessible, AtspiConnection, AtspiStatus, Error,
+};
+use atspi_common::CoordType;
+use atspi_proxies::component::ComponentProxyBlocking;
+
+/// The frame of reference for coordinates.
+pub type AtspiCoordType = u32;
+
+/// Coordinates are relative to the screen.
+pub const ATSPI_COORD_TYPE_SCREEN: AtspiCoordType = 0;
+/// Coordinates are relative to the window.
+pub const ATSPI_COORD_TYPE_WINDOW: AtspiCoordType = 1;
+/// Coordinates are relative to the parent of the object.
+pub const ATSPI_COORD_TYPE_PARENT: AtspiCoordType = 2;
+
+fn coord_type(value: AtspiCoordType) -> Result<CoordType, Error> {
+	match value {
+		ATSPI_COORD_TYPE_SCREEN => Ok(CoordType::Screen),
+		ATSPI_COORD_TYPE_WINDOW => Ok(CoordType::Window),
+		ATSPI_COORD_TYPE_PARENT => Ok(CoordType::Parent),
+		_ => Err(Error::InvalidArgument(format!("unknown coordinate type {value}"))),
+	}
+}
+
+/// Gets the position and size of `accessible`.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and the out-pointers valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_component_get_extents(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	coord_type: AtspiCoordType,
+	x: *mut i32,
+	y: *mut i32,
+	width: *mut i32,
+	height: *mut i32,
+) -> AtspiStatus {
+	run(|| {
+		if x.is_null() || y.is_null() || width.is_null() || height.is_null() {
+			return Err(Error::NullArgument("out"));
+		}
+		let coord_type = self::coord_type(coord_type)?;
+		let extents =
+			proxy::<ComponentProxyBlocking>(connection, accessible)?.get_extents(coord_type)?;
+		write(x, extents.0)?;
+		write(y, extents.1)?;
+		write(width, extents.2)?;
+		write(height, extents.3)
+	})
+}
+
+/// Whether the point at `x`, `y` lies within `accessible`.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_component_contains(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	x: i32,
+	y: i32,
+	coord_type: AtspiCoordType,
+	out: *mut bool,
+) -> AtspiStatus {
+	run(|| {
+		let coord_type = self::coord_type(coord_type)?;
+		let proxy = proxy::<ComponentProxyBlocking>(connection, accessible)?;
+		write(out, proxy.contains(x, y, coord_type)?)
+	})
+}
+
+/// Gets the descendant of `accessible` at the point `x`, `y`.
+///
+/// Writes the null object when there is none; see `atspi_accessible_is_null`.
+/// The handle written to `out` must be released with `atspi_accessible_free`.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_component_get_accessible_at_point(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	x: i32,
+	y: i32,
+	coord_type: AtspiCoordType,
+	out: *mut *mut AtspiAccessible,
+) -> AtspiStatus {
+	run(|| {
+		let coord_type = self::coord_type(coord_type)?;
+		let proxy = proxy::<ComponentProxyBlocking>(connection, accessible)?;
+		write_accessible(out, proxy.get_accessible_at_point(x, y, coord_type)?)
+	})
+}
+
+/// Asks for `accessible` to receive keyboard focus. Writes whether that succeeded to `out`.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_component_grab_focus(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut bool,
+) -> AtspiStatus {
+	run(|| write(out, proxy::<ComponentProxyBlocking>(connection, accessible)?.grab_focus()?))
+}
@@ -0,0 +1,423 @@
+//! Connecting to the accessibility bus, and listening for events.
+
+use crate::{borrow, run, write, AtspiAccessible, AtspiStatus, Error};
+use atspi_common::{
+	events::{
+		document::DocumentEvents, focus::FocusEvents, keyboard::KeyboardEvents, mouse::MouseEvents,
+		object::ObjectEvents, terminal::TerminalEvents, window::WindowEvents, EventBodyOwned,
+	},
+	Accessible,
+};
+use atspi_connection::AccessibilityConnectionBlocking;
+use futures_lite::{future, StreamExt};
+use std::{
+	ffi::{c_char, c_void, CString},
+	io, ptr,
+	sync::Arc,
+	thread::{self, JoinHandle},
+};
+use zbus::{MessageStream, MessageType};
+
+/// A connection to the accessibility bus.
+pub struct AtspiConnection(pub(crate) Arc<AccessibilityConnectionBlocking>);
+
+/// A group of events which can be registered for at once.
+pub type AtspiEventCategory = u32;
+
+/// Events on the `org.a11y.atspi.Event.Object` interface.
+pub const ATSPI_EVENT_CATEGORY_OBJECT: AtspiEventCategory = 0;
+/// Events on the `org.a11y.atspi.Event.Window` interface.
+pub const ATSPI_EVENT_CATEGORY_WINDOW: AtspiEventCategory = 1;
+/// Events on the `org.a11y.atspi.Event.Focus` interface.
+pub const ATSPI_EVENT_CATEGORY_FOCUS: AtspiEventCategory = 2;
+/// Events on the `org.a11y.atspi.Event.Document` interface.
+pub const ATSPI_EVENT_CATEGORY_DOCUMENT: AtspiEventCategory = 3;
+/// Events on the `org.a11y.atspi.Event.Keyboard` interface.
+pub const ATSPI_EVENT_CATEGORY_KEYBOARD: AtspiEventCategory = 4;
+/// Events on the `org.a11y.atspi.Event.Mouse` interface.
+pub const ATSPI_EVENT_CATEGORY_MOUSE: AtspiEventCategory = 5;
+/// Events on the `org.a11y.atspi.Event.Terminal` interface.
+pub const ATSPI_EVENT_CATEGORY_TERMINAL: AtspiEventCategory = 6;
+
+const EVENT_INTERFACE_PREFIX: &str = "org.a11y.atspi.Event.";
+
+/// An event, as passed to an [`AtspiEventCallback`].
+///
+/// All pointers are borrowed, and only valid until the callback returns.
+#[repr(C)]
+pub struct AtspiEvent {
+	/// The interface, without the `org.a11y.atspi.Event.` prefix; for example `"Object"`.
+	pub interface: *const c_char,
+	/// The signal; for example `"StateChanged"`.
+	pub member: *const c_char,
+	/// The kind, or minor; for example `"focused"`. May be empty.
+	pub kind: *const c_char,
+	pub detail1: i32,
+	pub detail2: i32,
+	/// `any_data` if it holds a string, `NULL` otherwise.
+	pub any_data: *const c_char,
+	/// The object that emitted the event.
+	pub source: *const AtspiAccessible,
+}
+
+/// Called for every event received by an [`AtspiListener`], on a thread owned by the listener.
+pub type AtspiEventCallback =
+	Option<unsafe extern "C" fn(event: *const AtspiEvent, user_data: *mut c_void)>;
+
+/// Delivers events to a callback on a background thread, until released.
+pub struct AtspiListener {
+	/// Dropping the sender closes the channel, which wakes the listener thread up.
+	stop: Option<async_channel::Sender<()>>,
+	thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for AtspiListener {
+	fn drop(&mut self) {
+		drop(self.stop.take());
+		let Some(thread) = self.thread.take() else { return };
+		// Released from within the callback, the thread can only end once the callback returns.
+		if thread.thread().id() != thread::current().id() {
+			let _ = thread.join();
+		}
+	}
+}
+
+struct UserData(*mut c_void);
+
+// SAFETY: the caller of `atspi_connection_listen` promises `user_data` may be used from the listener thread.
+unsafe impl Send for UserData {}
+
+/// Opens a connection to the accessibility bus, and writes it to `out`.
+///
+/// Release the connection with [`atspi_connection_free`].
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_connection_open(out: *mut *mut AtspiConnection) -> AtspiStatus {
+	run(|| {
+		let connection = AccessibilityConnectionBlocking::open()?;
+		write(out, Box::into_raw(Box::new(AtspiConnection(Arc::new(connection)))))
+	})
+}
+
+/// Releases a connection.
+///
+/// # Safety
+///
+/// `connection` must be `NULL`, or a connection returned by [`atspi_connection_open`] which has
+/// not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_connection_free(connection: *mut AtspiConnection) {
+	if !connection.is_null() {
+		drop(Box::from_raw(connection));
+	}
+}
+
+/// Asks applications to emit the events in `category`, and subscribes to them.
+///
+/// # Safety
+///
+/// `connection` must be a valid connection.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_connection_register_event(
+	connection: *const AtspiConnection,
+	category: AtspiEventCategory,
+) -> AtspiStatus {
+	run(|| {
+		let connection = &borrow(connection, "connection")?.0;
+		match category {
+			ATSPI_EVENT_CATEGORY_OBJECT => connection.register_event::<ObjectEvents>(),
+			ATSPI_EVENT_CATEGORY_WINDOW => connection.register_event::<WindowEvents>(),
+			ATSPI_EVENT_CATEGORY_FOCUS => connection.register_event::<FocusEvents>(),
+			ATSPI_EVENT_CATEGORY_DOCUMENT => connection.register_event::<DocumentEvents>(),
+			ATSPI_EVENT_CATEGORY_KEYBOARD => connection.register_event::<KeyboardEvents>(),
+			ATSPI_EVENT_CATEGORY_MOUSE => connection.register_event::<MouseEvents>(),
+			ATSPI_EVENT_CATEGORY_TERMINAL => connection.register_event::<TerminalEvents>(),
+			_ => return Err(unknown_category(category)),
+		}?;
+		Ok(())
+	})
+}
+
+/// Undoes [`atspi_connection_register_event`].
+///
+/// # Safety
+///
+/// `connection` must be a valid connection.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_connection_deregister_event(
+	connection: *const AtspiConnection,
+	category: AtspiEventCategory,
+) -> AtspiStatus {
+	run(|| {
+		let connection = &borrow(connection, "connection")?.0;
+		match category {
+			ATSPI_EVENT_CATEGORY_OBJECT => connection.deregister_event::<ObjectEvents>(),
+			ATSPI_EVENT_CATEGORY_WINDOW => connection.deregister_event::<WindowEvents>(),
+			ATSPI_EVENT_CATEGORY_FOCUS => connection.deregister_event::<FocusEvents>(),
+			ATSPI_EVENT_CATEGORY_DOCUMENT => connection.deregister_event::<DocumentEvents>(),
+			ATSPI_EVENT_CATEGORY_KEYBOARD => connection.deregister_event::<KeyboardEvents>(),
+			ATSPI_EVENT_CATEGORY_MOUSE => connection.deregister_event::<MouseEvents>(),
+			ATSPI_EVENT_CATEGORY_TERMINAL => connection.deregister_event::<TerminalEvents>(),
+			_ => return Err(unknown_category(category)),
+		}?;
+		Ok(())
+	})
+}
+
+/// Starts delivering the events received on `connection` to `callback`, and writes the listener to `out`.
+///
+/// `callback` runs on a thread owned by the listener, one event at a time.
+/// It receives `user_data` unchanged. Release the listener with [`atspi_listener_free`].
+///
+/// # Safety
+///
+/// `connection` must be a valid connection, and `out` valid for writes.
+/// `callback` must be safe to call from another thread with `user_data`, until the listener is released.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_connection_listen(
+	connection: *const AtspiConnection,
+	callback: AtspiEventCallback,
+	user_data: *mut c_void,
+	out: *mut *mut AtspiListener,
+) -> AtspiStatus {
+	run(|| {
+		let connection = Arc::clone(&borrow(connection, "connection")?.0);
+		let callback = callback.ok_or(Error::NullArgument("callback"))?;
+		if out.is_null() {
+			return Err(Error::NullArgument("out"));
+		}
+		let messages = MessageStream::from(connection.inner().connection());
+		let listener = spawn_listener(messages, connection, callback, UserData(user_data))
+			.map_err(|e| Error::Atspi(atspi_common::AtspiError::IO(e)))?;
+		write(out, Box::into_raw(Box::new(listener)))
+	})
+}
+
+/// Starts a thread passing the events among `messages` to `callback`, until the listener is dropped.
+///
+/// `keep_alive` is held by the thread until it ends.
+fn spawn_listener<T: Send + 'static>(
+	mut messages: MessageStream,
+	keep_alive: T,
+	callback: unsafe extern "C" fn(event: *const AtspiEvent, user_data: *mut c_void),
+	user_data: UserData,
+) -> io::Result<AtspiListener> {
+	let (stop, stopped) = async_channel::bounded::<()>(1);
+	let thread = thread::Builder::new()
+		.name("atspi-ffi listener".into())
+		.spawn(move || {
+			// Capture `user_data` as a whole, rather than its pointer which is not `Send`.
+			let user_data = user_data;
+			let _keep_alive = keep_alive;
+			zbus::block_on(async {
+				loop {
+					let stop = async {
+						let _ = stopped.recv().await;
+						None
+					};
+					let Some(Some(message)) =
+						future::or(stop, async { Some(messages.next().await) }).await
+					else {
+						break;
+					};
+					let Ok(message) = message else { continue };
+					let Some(event) = OwnedEvent::from_message(&message) else { continue };
+					if stopped.is_closed() {
+						break;
+					}
+					// SAFETY: the caller of `atspi_connection_listen` promises `callback` may be called
+					// from this thread with `user_data`, until the listener is released.
+					event.with_ffi(|event| unsafe { callback(event, user_data.0) });
+				}
+			});
+		})?;
+	Ok(AtspiListener { stop: Some(stop), thread: Some(thread) })
+}
+
+/// Stops a listener, and releases it.
+///
+/// Once this returns, the callback is not called anymore, and the listener thread has ended.
+/// If the callback is running, this waits for it to return; so do not call it from within the
+/// callback.
+///
+/// # Safety
+///
+/// `listener` must be `NULL`, or a listener returned by [`atspi_connection_listen`] which has
+/// not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_listener_free(listener: *mut AtspiListener) {
+	if listener.is_null() {
+		return;
+	}
+	drop(Box::from_raw(listener));
+}
+
+fn unknown_category(category: AtspiEventCategory) -> Error {
+	Error::InvalidArgument(format!("unknown event category {category}"))
+}
+
+/// The owned data behind an [`AtspiEvent`].
+struct OwnedEvent {
+	interface: CString,
+	member: CString,
+	kind: CString,
+	detail1: i32,
+	detail2: i32,
+	any_data: Option<CString>,
+	source: AtspiAccessible,
+}
+
+impl OwnedEvent {
+	fn from_message(message: &zbus::Message) -> Option<Self> {
+		if message.message_type() != MessageType::Signal {
+			return None;
+		}
+		let interface = message.interface()?;
+		let interface = interface.as_str().strip_prefix(EVENT_INTERFACE_PREFIX)?;
+		let member = message.member()?;
+		let body = EventBodyOwned::try_from(message).ok()?;
+		let source = Accessible::try_from(message).ok()?;
+		let any_data = match &*body.any_data {
+			zbus::zvariant::Value::Str(data) => Some(c_string(data.as_str())),
+			_ => None,
+		};
+		Some(Self {
+			interface: c_string(interface),
+			member: c_string(member.as_str()),
+			kind: c_string(&body.kind),
+			detail1: body.detail1,
+			detail2: body.detail2,
+			any_data,
+			source: AtspiAccessible(source),
+		})
+	}
+
+	fn with_ffi(&self, f: impl FnOnce(*const AtspiEvent)) {
+		let event = AtspiEvent {
+			interface: self.interface.as_ptr(),
+			member: self.member.as_ptr(),
+			kind: self.kind.as_ptr(),
+			detail1: self.detail1,
+			detail2: self.detail2,
+			any_data: self.any_data.as_ref().map_or(ptr::null(), |data| data.as_ptr()),
+			source: &self.source,
+		};
+		f(&event);
+	}
+}
+
+fn c_string(value: &str) -> CString {
+	CString::new(value.replace('\0', "")).expect("all NUL bytes were removed")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{spawn_listener, AtspiEvent, OwnedEvent, UserData};
+	use std::{
+		ffi::{c_void, CStr},
+		sync::mpsc,
+		time::Duration,
+	};
+	use zbus::{ConnectionBuilder, Guid, MessageStream};
+
+	/// Two peer-to-peer connections to each other.
+	fn peers() -> (zbus::Connection, zbus::Connection) {
+		let (client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+		let guid = Guid::generate();
+		let server = ConnectionBuilder::unix_stream(server).server(&guid).p2p().build();
+		let client = ConnectionBuilder::unix_stream(client).p2p().build();
+		let (client, server) = zbus::block_on(futures_lite::future::zip(client, server));
+		(client.unwrap(), server.unwrap())
+	}
+
+	unsafe extern "C" fn record(event: *const AtspiEvent, user_data: *mut c_void) {
+		let members = &*user_data.cast::<mpsc::Sender<String>>();
+		let member = CStr::from_ptr((*event).member).to_str().unwrap();
+		members.send(member.to_string()).unwrap();
+	}
+
+	#[test]
+	fn listeners_stop_when_released() {
+		let (application, screen_reader) = peers();
+		let (members, received) = mpsc::channel::<String>();
+		let user_data = UserData(std::ptr::addr_of!(members).cast_mut().cast());
+		let messages = MessageStream::from(&screen_reader);
+		let listener = spawn_listener(messages, (), record, user_data).unwrap();
+
+		let message = zbus::MessageBuilder::signal(
+			"/org/a11y/atspi/accessible/1",
+			"org.a11y.atspi.Event.Object",
+			"StateChanged",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&(
+			"focused",
+			1,
+			0,
+			zbus::zvariant::Value::from(0),
+			std::collections::HashMap::<&str, zbus::zvariant::Value>::new(),
+		))
+		.unwrap();
+		zbus::block_on(application.send_message(message)).unwrap();
+		assert_eq!(received.recv_timeout(Duration::from_secs(5)).unwrap(), "StateChanged");
+
+		// No further message arrives, yet releasing the listener joins its thread.
+		drop(listener);
+		assert!(received.try_recv().is_err());
+		// Only now may `user_data` go away.
+		drop(members);
+	}
+
+	#[test]
+	fn signals_become_events() {
+		let message = zbus::MessageBuilder::signal(
+			"/org/a11y/atspi/accessible/1",
+			"org.a11y.atspi.Event.Object",
+			"TextChanged",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&(
+			"insert",
+			4,
+			2,
+			zbus::zvariant::Value::from("hi"),
+			std::collections::HashMap::<&str, zbus::zvariant::Value>::new(),
+		))
+		.unwrap();
+		let event = OwnedEvent::from_message(&message).unwrap();
+		event.with_ffi(|event| {
+			let event = unsafe { &*event };
+			let string = |ptr| unsafe { CStr::from_ptr(ptr) }.to_str().unwrap();
+			assert_eq!(string(event.interface), "Object");
+			assert_eq!(string(event.member), "TextChanged");
+			assert_eq!(string(event.kind), "insert");
+			assert_eq!((event.detail1, event.detail2), (4, 2));
+			assert_eq!(string(event.any_data), "hi");
+			let source = unsafe { &(*event.source).0 };
+			assert_eq!(source.name, ":1.23");
+			assert_eq!(source.path.as_str(), "/org/a11y/atspi/accessible/1");
+		});
+	}
+
+	#[test]
+	fn other_signals_are_skipped() {
+		let message = zbus::MessageBuilder::signal(
+			"/org/a11y/atspi/registry",
+			"org.a11y.atspi.Registry",
+			"EventListenerRegistered",
+		)
+		.unwrap()
+		.sender(":1.23")
+		.unwrap()
+		.build(&(":1.5", "Object:StateChanged"))
+		.unwrap();
+		assert!(OwnedEvent::from_message(&message).is_none());
+	}
+}
@@ -0,0 +1,232 @@
+//! # `atspi-ffi`
+//!
+//! A C API over the blocking client API of `atspi-connection`, for hosts that can not link Rust directly.
+//! The matching header, `include/atspi.h`, is generated from these sources by `cargo xtask codegen`.
+//!
+//! ## Conventions
+//!
+//! * Every fallible function returns an [`AtspiStatus`]; `ATSPI_STATUS_OK` on success.
+//!   Results are written through out-pointers, which are left untouched on failure.
+//!   A human readable description of the last failure on the calling thread is available through
+//!   [`atspi_last_error_message`].
+//! * Calls block the calling thread until the remote application answers.
+//!
+//! ## Ownership
+//!
+//! * Pointers passed *into* the library are borrowed for the duration of the call only.
+//! * Every `char *` handed out is owned by the caller, and must be released with [`atspi_string_free`].
+//! * Every `AtspiAccessible *` handed out is owned by the caller, and must be released with
+//!   [`atspi_accessible_free`].
+//! * [`AtspiConnection`] and [`AtspiListener`] handles are released with [`atspi_connection_free`] and
+//!   [`atspi_listener_free`] respectively. A listener keeps its connection alive, so the two may be
+//!   released in any order.
+//! * The exception are the pointers inside an [`AtspiEvent`], which are only valid until the
+//!   callback returns. Use [`atspi_accessible_clone`] to keep the source of an event around.
+
+#![deny(clippy::all, clippy::pedantic, clippy::cargo)]
+#![allow(clippy::multiple_crate_versions)]
+
+pub mod accessible;
+pub mod action;
+pub mod component;
+pub mod connection;
+pub mod text;
+
+pub use accessible::AtspiAccessible;
+pub use connection::{AtspiConnection, AtspiEvent, AtspiEventCallback, AtspiListener};
+
+use atspi_common::AtspiError;
+use std::{
+	cell::RefCell,
+	ffi::{c_char, CStr, CString},
+	panic::{catch_unwind, AssertUnwindSafe},
+	ptr,
+};
+
+/// The outcome of a call.
+pub type AtspiStatus = i32;
+
+/// The call succeeded.
+pub const ATSPI_STATUS_OK: AtspiStatus = 0;
+/// A required pointer argument was `NULL`.
+pub const ATSPI_STATUS_NULL_ARGUMENT: AtspiStatus = 1;
+/// An argument was out of range, not valid UTF-8, or not a valid bus name or object path.
+pub const ATSPI_STATUS_INVALID_ARGUMENT: AtspiStatus = 2;
+/// The call over D-Bus failed, or its reply could not be understood.
+pub const ATSPI_STATUS_ERROR: AtspiStatus = 3;
+/// The library panicked. This is a bug.
+pub const ATSPI_STATUS_PANIC: AtspiStatus = 4;
+
+/// Why a call failed, before it is flattened to an [`AtspiStatus`].
+#[derive(Debug)]
+pub(crate) enum Error {
+	NullArgument(&'static str),
+	InvalidArgument(String),
+	Atspi(AtspiError),
+}
+
+impl Error {
+	fn status(&self) -> AtspiStatus {
+		match self {
+			Self::NullArgument(_) => ATSPI_STATUS_NULL_ARGUMENT,
+			Self::InvalidArgument(_) => ATSPI_STATUS_INVALID_ARGUMENT,
+			Self::Atspi(_) => ATSPI_STATUS_ERROR,
+		}
+	}
+
+	fn message(&self) -> String {
+		match self {
+			Self::NullArgument(name) => format!("argument `{name}` is NULL"),
+			Self::InvalidArgument(message) => message.clone(),
+			Self::Atspi(e) => e.to_string(),
+		}
+	}
+}
+
+impl From<AtspiError> for Error {
+	fn from(e: AtspiError) -> Self {
+		Self::Atspi(e)
+	}
+}
+
+impl From<zbus::Error> for Error {
+	fn from(e: zbus::Error) -> Self {
+		Self::Atspi(e.into())
+	}
+}
+
+thread_local! {
+	static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, recording its error, if any, as the last error of this thread.
+pub(crate) fn run(f: impl FnOnce() -> Result<(), Error>) -> AtspiStatus {
+	let (status, message) = match catch_unwind(AssertUnwindSafe(f)) {
+		Ok(Ok(())) => (ATSPI_STATUS_OK, None),
+		Ok(Err(e)) => (e.status(), Some(e.message())),
+		Err(_) => (ATSPI_STATUS_PANIC, Some("atspi-ffi panicked".to_string())),
+	};
+	LAST_ERROR.with(|last| *last.borrow_mut() = message);
+	status
+}
+
+/// Borrows the object behind `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must be `NULL`, or point to a valid `T` for the lifetime `'a`.
+pub(crate) unsafe fn borrow<'a, T>(ptr: *const T, name: &'static str) -> Result<&'a T, Error> {
+	ptr.as_ref().ok_or(Error::NullArgument(name))
+}
+
+/// Borrows the NUL-terminated UTF-8 string behind `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must be `NULL`, or point to a NUL-terminated string which outlives `'a`.
+pub(crate) unsafe fn borrow_str<'a>(
+	ptr: *const c_char,
+	name: &'static str,
+) -> Result<&'a str, Error> {
+	if ptr.is_null() {
+		return Err(Error::NullArgument(name));
+	}
+	CStr::from_ptr(ptr)
+		.to_str()
+		.map_err(|_| Error::InvalidArgument(format!("argument `{name}` is not valid UTF-8")))
+}
+
+/// Writes `value` to `out`.
+///
+/// # Safety
+///
+/// `out` must be `NULL`, or valid for writes.
+pub(crate) unsafe fn write<T>(out: *mut T, value: T) -> Result<(), Error> {
+	if out.is_null() {
+		return Err(Error::NullArgument("out"));
+	}
+	out.write(value);
+	Ok(())
+}
+
+/// Writes a newly allocated copy of `value` to `out`, to be freed with [`atspi_string_free`].
+///
+/// # Safety
+///
+/// `out` must be `NULL`, or valid for writes.
+pub(crate) unsafe fn write_string(out: *mut *mut c_char, value: String) -> Result<(), Error> {
+	if out.is_null() {
+		return Err(Error::NullArgument("out"));
+	}
+	out.write(into_c_string(value));
+	Ok(())
+}
+
+/// Moves `value` to the heap as a C string. Interior NUL bytes are dropped.
+pub(crate) fn into_c_string(value: String) -> *mut c_char {
+	let value = CString::new(value).unwrap_or_else(|e| {
+		let mut bytes = e.into_vec();
+		bytes.retain(|b| *b != 0);
+		CString::new(bytes).expect("all NUL bytes were removed")
+	});
+	value.into_raw()
+}
+
+/// Returns a description of the last failed call on this thread, or `NULL` if the last call succeeded.
+///
+/// The returned string must be released with [`atspi_string_free`].
+#[no_mangle]
+pub extern "C" fn atspi_last_error_message() -> *mut c_char {
+	LAST_ERROR.with(|last| last.borrow().clone().map_or(ptr::null_mut(), into_c_string))
+}
+
+/// Releases a string handed out by this library.
+///
+/// # Safety
+///
+/// `string` must be `NULL`, or a string returned by this library which has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_string_free(string: *mut c_char) {
+	if !string.is_null() {
+		drop(CString::from_raw(string));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		atspi_last_error_message, atspi_string_free, borrow_str, run, Error,
+		ATSPI_STATUS_INVALID_ARGUMENT, ATSPI_STATUS_NULL_ARGUMENT, ATSPI_STATUS_OK,
+		ATSPI_STATUS_PANIC,
+	};
+	use std::{ffi::CStr, ptr};
+
+	fn last_error() -> Option<String> {
+		let message = atspi_last_error_message();
+		if message.is_null() {
+			return None;
+		}
+		let copy = unsafe { CStr::from_ptr(message) }.to_str().unwrap().to_string();
+		unsafe { atspi_string_free(message) };
+		Some(copy)
+	}
+
+	#[test]
+	fn errors_are_recorded_per_call() {
+		let status = run(|| unsafe { borrow_str(ptr::null(), "path") }.map(drop));
+		assert_eq!(status, ATSPI_STATUS_NULL_ARGUMENT);
+		assert_eq!(last_error().as_deref(), Some("argument `path` is NULL"));
+
+		let status = run(|| unsafe { borrow_str(b"\xff\0".as_ptr().cast(), "path") }.map(drop));
+		assert_eq!(status, ATSPI_STATUS_INVALID_ARGUMENT);
+
+		assert_eq!(run(|| Ok(())), ATSPI_STATUS_OK);
+		assert_eq!(last_error(), None);
+	}
+
+	#[test]
+	fn panics_do_not_unwind() {
+		let status = run(|| -> Result<(), Error> { panic!("boom") });
+		assert_eq!(status, ATSPI_STATUS_PANIC);
+	}
+}
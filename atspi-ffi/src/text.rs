@@ -0,0 +1,70 @@
+//! The `org.a11y.atspi.Text` interface.
+
+use crate::{
+	accessible::proxy, run, write, write_string, AtspiAccessible, AtspiConnection, AtspiStatus,
+};
+use atspi_proxies::text::TextProxyBlocking;
+use std::ffi::c_char;
+
+/// Reads the `CharacterCount` property.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_text_get_character_count(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut i32,
+) -> AtspiStatus {
+	run(|| write(out, proxy::<TextProxyBlocking>(connection, accessible)?.character_count()?))
+}
+
+/// Gets the text between the character offsets `start` and `end`. An `end` of -1 means the end of the text.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_text_get_text(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	start: i32,
+	end: i32,
+	out: *mut *mut c_char,
+) -> AtspiStatus {
+	run(|| {
+		write_string(out, proxy::<TextProxyBlocking>(connection, accessible)?.get_text(start, end)?)
+	})
+}
+
+/// Reads the `CaretOffset` property.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_text_get_caret_offset(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	out: *mut i32,
+) -> AtspiStatus {
+	run(|| write(out, proxy::<TextProxyBlocking>(connection, accessible)?.caret_offset()?))
+}
+
+/// Moves the caret to `offset`. Writes whether that succeeded to `out`.
+///
+/// # Safety
+///
+/// `connection` and `accessible` must be valid, and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn atspi_text_set_caret_offset(
+	connection: *const AtspiConnection,
+	accessible: *const AtspiAccessible,
+	offset: i32,
+	out: *mut bool,
+) -> AtspiStatus {
+	run(|| {
+		write(out, proxy::<TextProxyBlocking>(connection, accessible)?.set_caret_offset(offset)?)
+	})
+}
@@ -5,6 +5,9 @@
 //!
 //! Accessible is the interface which is implemented by all accessible objects.
 //!
+//! The trait is generated from `xml/Accessible.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
+//!
 
 use crate::atspi_proxy;
 use crate::AtspiError;
@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Action`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Action.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Action.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
@@ -33,6 +27,6 @@ trait Action {
 	fn get_name(&self, index: i32) -> zbus::Result<String>;
 
 	/// NActions property
-	#[dbus_proxy(property)]
+	#[dbus_proxy(property, name = "NActions")]
 	fn nactions(&self) -> zbus::Result<i32>;
 }
@@ -1,7 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Cache`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Cache.xml`.
+//! The trait is generated from `xml/Cache.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
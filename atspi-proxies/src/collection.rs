@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Collection`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Collection.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Collection.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 #![allow(clippy::too_many_arguments)]
 // this allow zbus to change the number of parameters in a function without setting off clippy
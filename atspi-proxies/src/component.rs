@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Component`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Component.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Component.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
@@ -36,6 +30,7 @@ trait Component {
 	fn get_layer(&self) -> zbus::Result<Layer>;
 
 	/// GetMDIZOrder method
+	#[dbus_proxy(name = "GetMDIZOrder")]
 	fn get_mdizorder(&self) -> zbus::Result<i16>;
 
 	/// GetPosition method
@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.DeviceEventController`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `DeviceEventController.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/DeviceEventController.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
@@ -69,6 +63,19 @@ pub struct KeyDefinition<'a> {
 	pub unused: i32,
 }
 
+/// A keystroke listener, as [`DeviceEventControllerProxy::get_keystroke_listeners`] lists it:
+/// its bus name and path, its type, the event types it listens to, its keys, its modifier mask
+/// and its mode.
+pub type KeystrokeListener = (
+	String,
+	zbus::zvariant::OwnedObjectPath,
+	u32,
+	u32,
+	Vec<(i32, i32, String, i32)>,
+	u32,
+	EventListenerMode,
+);
+
 #[atspi_proxy(
 	interface = "org.a11y.atspi.DeviceEventController",
 	default_path = "/org/a11y/atspi/registry/deviceeventcontroller",
@@ -102,6 +109,9 @@ trait DeviceEventController {
 	/// GenerateMouseEvent method
 	fn generate_mouse_event(&self, x: i32, y: i32, event_name: &str) -> zbus::Result<()>;
 
+	/// Lists the keystroke listeners registered with the controller.
+	fn get_keystroke_listeners(&self) -> zbus::Result<Vec<KeystrokeListener>>;
+
 	/// NotifyListenersAsync method
 	fn notify_listeners_async(&self, event: &DeviceEvent<'_>) -> zbus::Result<()>;
 
@@ -121,7 +131,7 @@ trait DeviceEventController {
 		listener: &zbus::zvariant::ObjectPath<'_>,
 		keys: &[KeyDefinition<'_>],
 		mask: u32,
-		type_: &[EventType],
+		types: u32,
 		mode: &EventListenerMode,
 	) -> zbus::Result<bool>;
 }
@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Hyperlink`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Hyperlink.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Hyperlink.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
@@ -19,6 +13,7 @@ trait Hyperlink {
 	fn get_object(&self, i: i32) -> zbus::Result<Accessible>;
 
 	/// GetURI method
+	#[dbus_proxy(name = "GetURI")]
 	fn get_uri(&self, i: i32) -> zbus::Result<String>;
 
 	/// IsValid method
@@ -29,7 +24,7 @@ trait Hyperlink {
 	fn end_index(&self) -> zbus::Result<i32>;
 
 	/// NAnchors property
-	#[dbus_proxy(property)]
+	#[dbus_proxy(property, name = "NAnchors")]
 	fn nanchors(&self) -> zbus::Result<i16>;
 
 	/// StartIndex property
@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Hypertext`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Hypertext.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Hypertext.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
@@ -22,5 +16,6 @@ trait Hypertext {
 	fn get_link_index(&self, character_index: i32) -> zbus::Result<i32>;
 
 	/// GetNLinks method
+	#[dbus_proxy(name = "GetNLinks")]
 	fn get_nlinks(&self) -> zbus::Result<i32>;
 }
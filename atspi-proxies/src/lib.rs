@@ -1,12 +1,6 @@
 #![deny(clippy::all, clippy::pedantic, clippy::cargo, unsafe_code)]
 #![allow(clippy::multiple_crate_versions)]
 
-#[cfg(all(feature = "async-std", feature = "tokio"))]
-compile_error!("You may not mix the async-std and tokio features.");
-
-#[cfg(all(not(feature = "async-std"), not(feature = "tokio")))]
-compile_error!("You must specify either the async-std or tokio feature.");
-
 use zbus::dbus_proxy as atspi_proxy;
 
 pub mod accessible;
@@ -26,11 +20,14 @@ pub mod hyperlink;
 pub mod hypertext;
 pub mod image;
 pub mod registry;
+pub mod result;
 pub mod selection;
 pub mod socket;
 pub mod table;
 pub mod table_cell;
+pub mod table_model;
 pub mod text;
+pub mod timeout;
 pub mod value;
 
 pub use zbus;
@@ -38,3 +35,44 @@ pub use zbus;
 pub trait AtspiProxy {
 	const INTERFACE: Interface;
 }
+
+/// Creates a proxy of type `T` for the remote object an [`Accessible`](atspi_common::Accessible) refers to.
+///
+/// Property caching is disabled, as most objects are only queried a few times.
+///
+/// # Errors
+///
+/// When the bus name or object path of `accessible` are invalid, or creating the proxy fails.
+pub async fn proxy_for<T>(
+	conn: &zbus::Connection,
+	accessible: &atspi_common::Accessible,
+) -> zbus::Result<T>
+where
+	T: From<zbus::Proxy<'static>> + zbus::ProxyDefault,
+{
+	zbus::ProxyBuilder::<'static, T>::new(conn)
+		.destination(accessible.name.clone())?
+		.path(accessible.path.clone())?
+		.cache_properties(zbus::CacheProperties::No)
+		.build()
+		.await
+}
+
+/// Blocking variant of [`proxy_for`].
+///
+/// # Errors
+///
+/// When the bus name or object path of `accessible` are invalid, or creating the proxy fails.
+pub fn proxy_for_blocking<T>(
+	conn: &zbus::blocking::Connection,
+	accessible: &atspi_common::Accessible,
+) -> zbus::Result<T>
+where
+	T: From<zbus::Proxy<'static>> + zbus::ProxyDefault,
+{
+	zbus::blocking::ProxyBuilder::<'static, T>::new(conn)
+		.destination(accessible.name.clone())?
+		.path(accessible.path.clone())?
+		.cache_properties(zbus::CacheProperties::No)
+		.build()
+}
@@ -1 +1,77 @@
 pub type AtspiResult<T> = Result<T, crate::AtspiError>;
+
+/// Handles the errors of calls on objects which may have gone away.
+///
+/// Accessible objects can be destroyed, and applications can exit, at any time. Calls on them
+/// then fail with [`AtspiError::ObjectDefunct`](crate::AtspiError::ObjectDefunct) or
+/// [`AtspiError::ServiceUnknown`](crate::AtspiError::ServiceUnknown). These methods turn such
+/// errors into `Ok(None)`, so callers can skip the object rather than give up.
+///
+/// ```rust,no_run
+/// use atspi_proxies::{accessible::AccessibleProxy, proxy_for, result::ProxyResultExt};
+/// # async fn example(conn: &zbus::Connection, children: Vec<atspi_common::Accessible>) -> atspi_proxies::result::AtspiResult<()> {
+/// for child in children {
+///     let proxy = proxy_for::<AccessibleProxy>(conn, &child).await?;
+///     let Some(name) = proxy.name().await.skip_defunct()? else {
+///         continue;
+///     };
+///     println!("{name}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait ProxyResultExt<T> {
+	/// Replaces errors about a defunct object, or an application which exited, by `None`.
+	///
+	/// # Errors
+	///
+	/// All other errors are passed on.
+	fn skip_defunct(self) -> AtspiResult<Option<T>>;
+
+	/// Like [`Self::skip_defunct`], but also replaces errors about an unsupported method or
+	/// interface by `None`.
+	///
+	/// # Errors
+	///
+	/// All other errors are passed on.
+	fn skip_unavailable(self) -> AtspiResult<Option<T>>;
+}
+
+impl<T, E: Into<crate::AtspiError>> ProxyResultExt<T> for Result<T, E> {
+	fn skip_defunct(self) -> AtspiResult<Option<T>> {
+		match self.map_err(Into::into) {
+			Ok(value) => Ok(Some(value)),
+			Err(e) if e.is_defunct() => Ok(None),
+			Err(e) => Err(e),
+		}
+	}
+
+	fn skip_unavailable(self) -> AtspiResult<Option<T>> {
+		match self.map_err(Into::into) {
+			Ok(value) => Ok(Some(value)),
+			Err(e) if e.is_defunct() || e.is_unsupported() => Ok(None),
+			Err(e) => Err(e),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ProxyResultExt;
+	use crate::AtspiError;
+	use zbus::fdo;
+
+	#[test]
+	fn skips_defunct_objects() {
+		let defunct: Result<i32, _> = Err(fdo::Error::UnknownObject(String::new()));
+		assert!(matches!(defunct.skip_defunct(), Ok(None)));
+		let exited: Result<i32, _> = Err(fdo::Error::ServiceUnknown(String::new()));
+		assert!(matches!(exited.skip_unavailable(), Ok(None)));
+		let unsupported: Result<i32, _> = Err(fdo::Error::UnknownMethod(String::new()));
+		assert!(matches!(unsupported.clone().skip_unavailable(), Ok(None)));
+		assert!(matches!(unsupported.skip_defunct(), Err(AtspiError::UnknownMethod(_))));
+		assert!(matches!(Ok::<_, fdo::Error>(1).skip_defunct(), Ok(Some(1))));
+		let failed: Result<i32, _> = Err(fdo::Error::Failed(String::new()));
+		assert!(matches!(failed.skip_unavailable(), Err(AtspiError::Zbus(_))));
+	}
+}
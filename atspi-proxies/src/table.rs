@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Table`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Table.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Table.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
@@ -83,19 +77,19 @@ trait Table {
 	fn caption(&self) -> zbus::Result<Accessible>;
 
 	/// NColumns property
-	#[dbus_proxy(property)]
+	#[dbus_proxy(property, name = "NColumns")]
 	fn ncolumns(&self) -> zbus::Result<i32>;
 
 	/// NRows property
-	#[dbus_proxy(property)]
+	#[dbus_proxy(property, name = "NRows")]
 	fn nrows(&self) -> zbus::Result<i32>;
 
 	/// NSelectedColumns property
-	#[dbus_proxy(property)]
+	#[dbus_proxy(property, name = "NSelectedColumns")]
 	fn nselected_columns(&self) -> zbus::Result<i32>;
 
 	/// NSelectedRows property
-	#[dbus_proxy(property)]
+	#[dbus_proxy(property, name = "NSelectedRows")]
 	fn nselected_rows(&self) -> zbus::Result<i32>;
 
 	/// Summary property
@@ -0,0 +1,343 @@
+//! # `TableModel`
+//!
+//! A navigation model on top of [`TableProxy`] and [`TableCellProxy`].
+//!
+//! Reading a row, moving to the next cell or announcing a column header takes a
+//! handful of calls on the `Table` and `TableCell` interfaces.
+//! [`TableModel`] bundles these and keeps the cells it has seen,
+//! until the table signals that its structure changed.
+//!
+
+use crate::{proxy_for, table::TableProxy, table_cell::TableCellProxy};
+use atspi_common::{events::object::ObjectEvents, Accessible};
+use std::collections::HashMap;
+
+/// The direction to move in, relative to a [`Cell`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+/// A cell in a table, along with the area it occupies.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cell {
+	/// The object representing the cell.
+	pub accessible: Accessible,
+	/// The row the cell starts in.
+	pub row: i32,
+	/// The column the cell starts in.
+	pub column: i32,
+	/// The number of rows the cell occupies; at least 1.
+	pub row_span: i32,
+	/// The number of columns the cell occupies; at least 1.
+	pub column_span: i32,
+}
+
+impl Cell {
+	/// Whether the cell covers the slot at `row` and `column`.
+	#[must_use]
+	pub fn covers(&self, row: i32, column: i32) -> bool {
+		(self.row..self.row + self.row_span).contains(&row)
+			&& (self.column..self.column + self.column_span).contains(&column)
+	}
+}
+
+/// Navigates the cells of a table, respecting row and column spans.
+///
+/// Cells are cached once looked up. Feed the model the [`ObjectEvents`] you receive with
+/// [`TableModel::handle_event`] to have the cache dropped when the table changes shape.
+pub struct TableModel<'a> {
+	table: TableProxy<'a>,
+	dimensions: Option<(i32, i32)>,
+	cells: HashMap<(i32, i32), Cell>,
+}
+
+impl<'a> TableModel<'a> {
+	/// Creates a model for the table behind `table`.
+	#[must_use]
+	pub fn new(table: TableProxy<'a>) -> Self {
+		Self { table, dimensions: None, cells: HashMap::new() }
+	}
+
+	/// The proxy this model was created from.
+	#[must_use]
+	pub fn table(&self) -> &TableProxy<'a> {
+		&self.table
+	}
+
+	/// Drops all cached dimensions and cells.
+	pub fn invalidate(&mut self) {
+		self.dimensions = None;
+		self.cells.clear();
+	}
+
+	/// Drops the cache if `event` signals a change to the structure of this table.
+	///
+	/// Returns whether the cache was dropped.
+	pub fn handle_event(&mut self, event: &ObjectEvents) -> bool {
+		let Some(item) = structure_changed(event) else {
+			return false;
+		};
+		if item.name != self.table.destination().as_str()
+			|| item.path.as_str() != self.table.path().as_str()
+		{
+			return false;
+		}
+		self.invalidate();
+		true
+	}
+
+	/// The number of rows and columns in the table.
+	///
+	/// # Errors
+	///
+	/// When the `NRows` or `NColumns` properties can not be read.
+	pub async fn dimensions(&mut self) -> zbus::Result<(i32, i32)> {
+		if let Some(dimensions) = self.dimensions {
+			return Ok(dimensions);
+		}
+		let dimensions = (self.table.nrows().await?, self.table.ncolumns().await?);
+		self.dimensions = Some(dimensions);
+		Ok(dimensions)
+	}
+
+	/// The cell covering the slot at `row` and `column`.
+	///
+	/// For a cell spanning multiple slots, the returned [`Cell`] describes where it starts.
+	/// Returns `None` when the slot lies outside of the table, or nothing occupies it.
+	///
+	/// # Errors
+	///
+	/// When any of the calls on the `Table` interface fail.
+	pub async fn cell_at(&mut self, row: i32, column: i32) -> zbus::Result<Option<Cell>> {
+		let (rows, columns) = self.dimensions().await?;
+		if !(0..rows).contains(&row) || !(0..columns).contains(&column) {
+			return Ok(None);
+		}
+		if let Some(cell) = self.cells.get(&(row, column)) {
+			return Ok(Some(cell.clone()));
+		}
+		let accessible = self.table.get_accessible_at(row, column).await?;
+		if accessible.is_null() {
+			return Ok(None);
+		}
+		let index = self.table.get_index_at(row, column).await?;
+		let extents = if index < 0 {
+			None
+		} else {
+			let (found, row, column, row_span, column_span, _selected) =
+				self.table.get_row_column_extents_at_index(index).await?;
+			found.then_some((row, column, row_span, column_span))
+		};
+		let (start_row, start_column, row_span, column_span) = match extents {
+			Some(extents) => extents,
+			None => (
+				row,
+				column,
+				self.table.get_row_extent_at(row, column).await?,
+				self.table.get_column_extent_at(row, column).await?,
+			),
+		};
+		let cell = Cell {
+			accessible,
+			row: start_row,
+			column: start_column,
+			row_span: row_span.max(1),
+			column_span: column_span.max(1),
+		};
+		self.cells.insert((row, column), cell.clone());
+		Ok(Some(cell))
+	}
+
+	/// The cell next to `cell` in `direction`.
+	///
+	/// Moving past a spanning cell skips all of the slots it covers.
+	/// Returns `None` at the edges of the table.
+	///
+	/// # Errors
+	///
+	/// When any of the calls on the `Table` interface fail.
+	pub async fn neighbor(
+		&mut self,
+		cell: &Cell,
+		direction: Direction,
+	) -> zbus::Result<Option<Cell>> {
+		let (mut row, mut column) = (cell.row, cell.column);
+		loop {
+			match direction {
+				Direction::Up => row -= 1,
+				Direction::Down => row += 1,
+				Direction::Left => column -= 1,
+				Direction::Right => column += 1,
+			}
+			if cell.covers(row, column) {
+				continue;
+			}
+			let (rows, columns) = self.dimensions().await?;
+			if !(0..rows).contains(&row) || !(0..columns).contains(&column) {
+				return Ok(None);
+			}
+			if let Some(next) = self.cell_at(row, column).await? {
+				return Ok(Some(next));
+			}
+		}
+	}
+
+	/// All distinct cells that cover a slot in `row`, from left to right.
+	///
+	/// # Errors
+	///
+	/// When any of the calls on the `Table` interface fail.
+	pub async fn row(&mut self, row: i32) -> zbus::Result<Vec<Cell>> {
+		let (_, columns) = self.dimensions().await?;
+		let mut cells: Vec<Cell> = Vec::new();
+		let mut column = 0;
+		while column < columns {
+			match self.cell_at(row, column).await? {
+				Some(cell) => {
+					column = cell.column + cell.column_span;
+					if !cells.contains(&cell) {
+						cells.push(cell);
+					}
+				}
+				None => column += 1,
+			}
+		}
+		Ok(cells)
+	}
+
+	/// The column headers of `cell`.
+	///
+	/// Uses `TableCell.GetColumnHeaderCells`, and falls back to `Table.GetColumnHeader`
+	/// for each column the cell spans when the cell does not implement the `TableCell` interface.
+	///
+	/// # Errors
+	///
+	/// When the fallback calls on the `Table` interface fail.
+	pub async fn column_headers(&self, cell: &Cell) -> zbus::Result<Vec<Accessible>> {
+		if let Ok(cell_proxy) = self.cell_proxy(cell).await {
+			if let Ok(headers) = cell_proxy.get_column_header_cells().await {
+				if !headers.is_empty() {
+					return Ok(headers);
+				}
+			}
+		}
+		let mut headers = Vec::new();
+		for column in cell.column..cell.column + cell.column_span {
+			let header = self.table.get_column_header(column).await?;
+			if !header.is_null() && !headers.contains(&header) {
+				headers.push(header);
+			}
+		}
+		Ok(headers)
+	}
+
+	/// The row headers of `cell`.
+	///
+	/// Uses `TableCell.GetRowHeaderCells`, and falls back to `Table.GetRowHeader`
+	/// for each row the cell spans when the cell does not implement the `TableCell` interface.
+	///
+	/// # Errors
+	///
+	/// When the fallback calls on the `Table` interface fail.
+	pub async fn row_headers(&self, cell: &Cell) -> zbus::Result<Vec<Accessible>> {
+		if let Ok(cell_proxy) = self.cell_proxy(cell).await {
+			if let Ok(headers) = cell_proxy.get_row_header_cells().await {
+				if !headers.is_empty() {
+					return Ok(headers);
+				}
+			}
+		}
+		let mut headers = Vec::new();
+		for row in cell.row..cell.row + cell.row_span {
+			let header = self.table.get_row_header(row).await?;
+			if !header.is_null() && !headers.contains(&header) {
+				headers.push(header);
+			}
+		}
+		Ok(headers)
+	}
+
+	/// The caption of the table, if it has one.
+	///
+	/// # Errors
+	///
+	/// When the `Caption` property can not be read.
+	pub async fn caption(&self) -> zbus::Result<Option<Accessible>> {
+		let caption = self.table.caption().await?;
+		Ok((!caption.is_null()).then_some(caption))
+	}
+
+	/// The summary of the table, if it has one.
+	///
+	/// # Errors
+	///
+	/// When the `Summary` property can not be read.
+	pub async fn summary(&self) -> zbus::Result<Option<Accessible>> {
+		let summary = self.table.summary().await?;
+		Ok((!summary.is_null()).then_some(summary))
+	}
+
+	async fn cell_proxy(&self, cell: &Cell) -> zbus::Result<TableCellProxy<'static>> {
+		proxy_for(self.table.connection(), &cell.accessible).await
+	}
+}
+
+/// The table whose structure changed, if `event` signals such a change.
+fn structure_changed(event: &ObjectEvents) -> Option<&Accessible> {
+	match event {
+		ObjectEvents::RowInserted(ev) => Some(&ev.item),
+		ObjectEvents::RowReordered(ev) => Some(&ev.item),
+		ObjectEvents::RowDeleted(ev) => Some(&ev.item),
+		ObjectEvents::ColumnInserted(ev) => Some(&ev.item),
+		ObjectEvents::ColumnReordered(ev) => Some(&ev.item),
+		ObjectEvents::ColumnDeleted(ev) => Some(&ev.item),
+		ObjectEvents::ModelChanged(ev) => Some(&ev.item),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{structure_changed, Cell};
+	use atspi_common::{
+		events::object::{
+			ColumnDeletedEvent, ModelChangedEvent, ObjectEvents, RowInsertedEvent,
+			TextCaretMovedEvent,
+		},
+		Accessible,
+	};
+
+	#[test]
+	fn structure_changes_invalidate() {
+		let events = [
+			ObjectEvents::RowInserted(RowInsertedEvent::default()),
+			ObjectEvents::ColumnDeleted(ColumnDeletedEvent::default()),
+			ObjectEvents::ModelChanged(ModelChangedEvent::default()),
+		];
+		for event in &events {
+			assert_eq!(structure_changed(event), Some(&Accessible::default()));
+		}
+		let caret = ObjectEvents::TextCaretMoved(TextCaretMovedEvent::default());
+		assert_eq!(structure_changed(&caret), None);
+	}
+
+	#[test]
+	fn spanning_cell_covers_its_slots() {
+		let cell = Cell {
+			accessible: Accessible::default(),
+			row: 1,
+			column: 2,
+			row_span: 2,
+			column_span: 3,
+		};
+		assert!(cell.covers(1, 2));
+		assert!(cell.covers(2, 4));
+		assert!(!cell.covers(3, 2));
+		assert!(!cell.covers(1, 5));
+		assert!(!cell.covers(0, 2));
+	}
+}
@@ -1,19 +1,13 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Text`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Text.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Text.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 #![allow(clippy::too_many_arguments)]
 // this is to silience clippy due to zbus expanding parameter expressions
 
 use crate::atspi_proxy;
-use atspi_common::{ClipType, CoordType, Granularity};
+use atspi_common::{ClipType, CoordType, Granularity, ScrollType, TextBoundaryType};
 
 #[atspi_proxy(interface = "org.a11y.atspi.Text", assume_defaults = true)]
 trait Text {
@@ -65,6 +59,7 @@ trait Text {
 	fn get_default_attributes(&self) -> zbus::Result<std::collections::HashMap<String, String>>;
 
 	/// GetNSelections method
+	#[dbus_proxy(name = "GetNSelections")]
 	fn get_nselections(&self) -> zbus::Result<i32>;
 
 	/// GetOffsetAtPoint method
@@ -92,13 +87,25 @@ trait Text {
 	fn get_text(&self, start_offset: i32, end_offset: i32) -> zbus::Result<String>;
 
 	/// GetTextAfterOffset method
-	fn get_text_after_offset(&self, offset: i32, type_: u32) -> zbus::Result<(String, i32, i32)>;
+	fn get_text_after_offset(
+		&self,
+		offset: i32,
+		type_: TextBoundaryType,
+	) -> zbus::Result<(String, i32, i32)>;
 
 	/// GetTextAtOffset method
-	fn get_text_at_offset(&self, offset: i32, type_: u32) -> zbus::Result<(String, i32, i32)>;
+	fn get_text_at_offset(
+		&self,
+		offset: i32,
+		type_: TextBoundaryType,
+	) -> zbus::Result<(String, i32, i32)>;
 
 	/// GetTextBeforeOffset method
-	fn get_text_before_offset(&self, offset: i32, type_: u32) -> zbus::Result<(String, i32, i32)>;
+	fn get_text_before_offset(
+		&self,
+		offset: i32,
+		type_: TextBoundaryType,
+	) -> zbus::Result<(String, i32, i32)>;
 
 	/// RemoveSelection method
 	fn remove_selection(&self, selection_num: i32) -> zbus::Result<bool>;
@@ -108,7 +115,7 @@ trait Text {
 		&self,
 		start_offset: i32,
 		end_offset: i32,
-		type_: u32,
+		type_: ScrollType,
 	) -> zbus::Result<bool>;
 
 	/// ScrollSubstringToPoint method
@@ -116,7 +123,7 @@ trait Text {
 		&self,
 		start_offset: i32,
 		end_offset: i32,
-		type_: u32,
+		type_: CoordType,
 		x: i32,
 		y: i32,
 	) -> zbus::Result<bool>;
@@ -0,0 +1,345 @@
+//! Deadlines for calls into applications which may hang.
+//!
+//! A call waits for the default D-Bus timeout, 25 seconds, when the application it goes to
+//! hangs. [`CallTimeoutExt::timeout`] gives up on a call earlier, and fails it with
+//! [`AtspiError::Timeout`]. Dropping a call future, which happens when it times out, cancels it:
+//! a reply arriving later is ignored.
+//!
+//! A [`CallGuard`] holds the timeout for a whole connection and keeps track of the applications
+//! which time out. An application timing out repeatedly is quarantined for a while: calls into it
+//! fail with [`AtspiError::Timeout`] right away, without waiting.
+//!
+//! ```rust,no_run
+//! use atspi_proxies::{
+//!     accessible::AccessibleProxy,
+//!     proxy_for,
+//!     timeout::{CallGuard, CallTimeoutExt},
+//! };
+//! use std::time::Duration;
+//! # async fn example(conn: &zbus::Connection, object: &atspi_common::Accessible) -> atspi_proxies::result::AtspiResult<()> {
+//! let guard = CallGuard::new(Duration::from_secs(1));
+//! let proxy = proxy_for::<AccessibleProxy>(conn, object).await?;
+//! let children = proxy.get_children().guarded(&guard, &object.name).await?;
+//! // A single call can get more time.
+//! let guard = guard.with_timeout(Duration::from_secs(5));
+//! let attributes = proxy.get_attributes().guarded(&guard, &object.name).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{result::AtspiResult, AtspiError};
+use async_io::Timer;
+use std::{
+	collections::HashMap,
+	future::Future,
+	pin::Pin,
+	sync::{Arc, Mutex, MutexGuard, PoisonError},
+	task::{Context, Poll},
+	time::{Duration, Instant},
+};
+
+/// The timeout of a [`CallGuard`] created with [`Default`].
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How many timeouts in a row get an application quarantined, by default.
+pub const DEFAULT_STRIKES: u32 = 3;
+
+/// How long an application stays quarantined, by default.
+pub const DEFAULT_QUARANTINE: Duration = Duration::from_secs(30);
+
+/// How many applications with timeouts are remembered before those not in quarantine are
+/// forgotten. Unique bus names are never reused, so the records of applications which exited
+/// would otherwise pile up.
+const RECORDS_LIMIT: usize = 256;
+
+#[derive(Debug, Default)]
+struct Record {
+	/// Timeouts in a row.
+	strikes: u32,
+	quarantined_until: Option<Instant>,
+}
+
+/// The timeout for calls over a connection, and the applications in quarantine.
+///
+/// Clones share which applications are quarantined.
+#[derive(Clone, Debug)]
+pub struct CallGuard {
+	timeout: Duration,
+	strikes: u32,
+	quarantine: Duration,
+	records: Arc<Mutex<HashMap<String, Record>>>,
+}
+
+impl Default for CallGuard {
+	fn default() -> Self {
+		Self::new(DEFAULT_TIMEOUT)
+	}
+}
+
+impl CallGuard {
+	/// A guard giving calls `timeout` to complete, quarantining applications for
+	/// [`DEFAULT_QUARANTINE`] after [`DEFAULT_STRIKES`] timeouts in a row.
+	#[must_use]
+	pub fn new(timeout: Duration) -> Self {
+		Self {
+			timeout,
+			strikes: DEFAULT_STRIKES,
+			quarantine: DEFAULT_QUARANTINE,
+			records: Arc::default(),
+		}
+	}
+
+	/// Quarantines applications for `duration` after `strikes` timeouts in a row.
+	///
+	/// With `strikes` at 0, applications are never quarantined.
+	#[must_use]
+	pub fn quarantine(mut self, strikes: u32, duration: Duration) -> Self {
+		self.strikes = strikes;
+		self.quarantine = duration;
+		self
+	}
+
+	/// A guard with a different timeout, sharing the quarantined applications with this one.
+	#[must_use]
+	pub fn with_timeout(&self, timeout: Duration) -> Self {
+		Self { timeout, ..self.clone() }
+	}
+
+	/// The time calls get to complete.
+	#[must_use]
+	pub fn timeout(&self) -> Duration {
+		self.timeout
+	}
+
+	/// Whether calls into the application owning `bus_name` currently fail right away.
+	#[must_use]
+	pub fn is_quarantined(&self, bus_name: &str) -> bool {
+		self.quarantined_until(bus_name).is_some()
+	}
+
+	/// Lifts the quarantine of the application owning `bus_name`, and forgets its timeouts.
+	pub fn release(&self, bus_name: &str) {
+		self.records().remove(bus_name);
+	}
+
+	fn records(&self) -> MutexGuard<'_, HashMap<String, Record>> {
+		// The records stay consistent even if a thread panicked while holding the lock.
+		self.records.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	fn quarantined_until(&self, bus_name: &str) -> Option<Instant> {
+		let mut records = self.records();
+		let record = records.get_mut(bus_name)?;
+		match record.quarantined_until {
+			Some(until) if until > Instant::now() => Some(until),
+			Some(_) => {
+				// Give the application another chance, but quarantine it again on its next timeout.
+				record.quarantined_until = None;
+				None
+			}
+			None => None,
+		}
+	}
+
+	fn record(&self, bus_name: &str, timed_out: bool) {
+		let mut records = self.records();
+		if !timed_out {
+			records.remove(bus_name);
+			return;
+		}
+		if records.len() >= RECORDS_LIMIT && !records.contains_key(bus_name) {
+			let now = Instant::now();
+			records
+				.retain(|_, record| matches!(record.quarantined_until, Some(until) if until > now));
+			if records.len() >= RECORDS_LIMIT {
+				records.clear();
+			}
+		}
+		let record = records.entry(bus_name.to_string()).or_default();
+		record.strikes += 1;
+		if self.strikes > 0 && record.strikes >= self.strikes {
+			record.quarantined_until = Some(Instant::now() + self.quarantine);
+		}
+	}
+}
+
+/// Adds deadlines to calls; implemented for all futures resolving to a `Result`.
+pub trait CallTimeoutExt<T, E>: Future<Output = Result<T, E>> + Sized {
+	/// Fails the call with [`AtspiError::Timeout`] if it does not complete within `timeout`.
+	fn timeout(self, timeout: Duration) -> WithTimeout<Self> {
+		WithTimeout { future: Box::pin(self), timer: Timer::after(timeout), timeout }
+	}
+
+	/// Gives the call the timeout of `guard`, and takes part in the quarantine of the application
+	/// owning `bus_name`.
+	///
+	/// When that application is quarantined, the call fails right away, without being made.
+	fn guarded(self, guard: &CallGuard, bus_name: &str) -> Guarded<Self> {
+		let state = match guard.quarantined_until(bus_name) {
+			Some(until) => GuardedState::Quarantined(until),
+			None => GuardedState::Running(self.timeout(guard.timeout)),
+		};
+		Guarded { state, guard: guard.clone(), bus_name: bus_name.to_string() }
+	}
+}
+
+impl<F, T, E> CallTimeoutExt<T, E> for F where F: Future<Output = Result<T, E>> {}
+
+/// A call with a deadline; see [`CallTimeoutExt::timeout`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct WithTimeout<F> {
+	future: Pin<Box<F>>,
+	timer: Timer,
+	timeout: Duration,
+}
+
+impl<F> std::fmt::Debug for WithTimeout<F> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("WithTimeout")
+			.field("timeout", &self.timeout)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<F, T, E> Future for WithTimeout<F>
+where
+	F: Future<Output = Result<T, E>>,
+	E: Into<AtspiError>,
+{
+	type Output = AtspiResult<T>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		if let Poll::Ready(result) = self.future.as_mut().poll(cx) {
+			return Poll::Ready(result.map_err(Into::into));
+		}
+		match Pin::new(&mut self.timer).poll(cx) {
+			Poll::Ready(_) => Poll::Ready(Err(AtspiError::Timeout(format!(
+				"no reply within {} ms",
+				self.timeout.as_millis()
+			)))),
+			Poll::Pending => Poll::Pending,
+		}
+	}
+}
+
+enum GuardedState<F> {
+	Quarantined(Instant),
+	Running(WithTimeout<F>),
+}
+
+/// A call subject to a [`CallGuard`]; see [`CallTimeoutExt::guarded`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct Guarded<F> {
+	state: GuardedState<F>,
+	guard: CallGuard,
+	bus_name: String,
+}
+
+impl<F> std::fmt::Debug for Guarded<F> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Guarded")
+			.field("guard", &self.guard)
+			.field("bus_name", &self.bus_name)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<F, T, E> Future for Guarded<F>
+where
+	F: Future<Output = Result<T, E>>,
+	E: Into<AtspiError>,
+{
+	type Output = AtspiResult<T>;
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let this = &mut *self;
+		let result = match &mut this.state {
+			GuardedState::Quarantined(until) => {
+				let left = until.saturating_duration_since(Instant::now());
+				return Poll::Ready(Err(AtspiError::Timeout(format!(
+					"{} is quarantined for another {} s after repeated timeouts",
+					this.bus_name,
+					left.as_secs()
+				))));
+			}
+			GuardedState::Running(call) => match Pin::new(call).poll(cx) {
+				Poll::Ready(result) => result,
+				Poll::Pending => return Poll::Pending,
+			},
+		};
+		let timed_out = matches!(result, Err(AtspiError::Timeout(_)));
+		this.guard.record(&this.bus_name, timed_out);
+		Poll::Ready(result)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::future::{pending, ready};
+
+	const SHORT: Duration = Duration::from_millis(10);
+
+	fn hung() -> impl Future<Output = Result<(), AtspiError>> {
+		pending()
+	}
+
+	#[test]
+	fn times_out_hung_calls() {
+		tokio_test::block_on(async {
+			assert!(matches!(hung().timeout(SHORT).await, Err(AtspiError::Timeout(_))));
+			let quick = ready(Ok::<_, zbus::fdo::Error>(1)).timeout(SHORT).await;
+			assert!(matches!(quick, Ok(1)));
+			let failed = ready(Err::<(), _>(zbus::fdo::Error::UnknownObject(String::new())));
+			assert!(matches!(failed.timeout(SHORT).await, Err(AtspiError::ObjectDefunct(_))));
+		});
+	}
+
+	#[test]
+	fn quarantines_after_repeated_timeouts() {
+		tokio_test::block_on(async {
+			let guard = CallGuard::new(SHORT).quarantine(2, Duration::from_secs(60));
+			assert!(hung().guarded(&guard, ":1.1").await.is_err());
+			assert!(!guard.is_quarantined(":1.1"));
+			// A successful call starts the count over.
+			assert!(ready(Ok::<_, AtspiError>(())).guarded(&guard, ":1.1").await.is_ok());
+			assert!(hung().guarded(&guard, ":1.1").await.is_err());
+			assert!(!guard.is_quarantined(":1.1"));
+			assert!(hung().guarded(&guard, ":1.1").await.is_err());
+			assert!(guard.is_quarantined(":1.1"));
+			assert!(!guard.is_quarantined(":1.2"));
+
+			let call =
+				ready(Ok::<_, AtspiError>(())).guarded(&guard.with_timeout(SHORT * 2), ":1.1");
+			assert!(matches!(call.await, Err(AtspiError::Timeout(_))));
+			guard.release(":1.1");
+			assert!(ready(Ok::<_, AtspiError>(())).guarded(&guard, ":1.1").await.is_ok());
+		});
+	}
+
+	#[test]
+	fn quarantine_expires() {
+		tokio_test::block_on(async {
+			let guard = CallGuard::new(SHORT).quarantine(1, SHORT);
+			assert!(hung().guarded(&guard, ":1.1").await.is_err());
+			assert!(guard.is_quarantined(":1.1"));
+			Timer::after(SHORT * 2).await;
+			assert!(!guard.is_quarantined(":1.1"));
+		});
+	}
+
+	#[test]
+	fn forgets_applications_outside_quarantine() {
+		let guard = CallGuard::new(SHORT).quarantine(2, Duration::from_secs(60));
+		guard.record(":1.0", true);
+		guard.record(":1.0", true);
+		for i in 1..RECORDS_LIMIT {
+			guard.record(&format!(":2.{i}"), true);
+		}
+		assert_eq!(guard.records().len(), RECORDS_LIMIT);
+		guard.record(":3.0", true);
+		assert_eq!(guard.records().len(), 2);
+		assert!(guard.is_quarantined(":1.0"));
+		assert!(guard.records().contains_key(":3.0"));
+	}
+}
@@ -1,13 +1,7 @@
 //! # `DBus` interface proxy for: `org.a11y.atspi.Value`
 //!
-//! This code was generated by `zbus-xmlgen` `2.0.1` from `DBus` introspection data.
-//! Source: `Value.xml`.
-//!
-//! You may prefer to adapt it, instead of using it verbatim.
-//!
-//! More information can be found in the
-//! [Writing a client proxy](https://dbus.pages.freedesktop.org/zbus/client.html)
-//! section of the zbus documentation.
+//! The trait is generated from `xml/Value.xml` by `cargo xtask codegen`; the doc comments
+//! of its methods and the rest of the file are written by hand.
 //!
 
 use crate::atspi_proxy;
@@ -33,4 +27,8 @@ trait Value {
 	/// MinimumValue property
 	#[dbus_proxy(property)]
 	fn minimum_value(&self) -> zbus::Result<f64>;
+
+	/// The current value as text, for values better read out as words, such as "low" or "high".
+	#[dbus_proxy(property)]
+	fn text(&self) -> zbus::Result<String>;
 }
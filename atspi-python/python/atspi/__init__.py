@@ -0,0 +1,38 @@
+"""AT-SPI for Python, on top of the Rust atspi crates.
+
+```python
+import atspi
+
+connection = atspi.Connection.open()
+for application in connection.desktop().children:
+    for node in atspi.walk(application):
+        print(node.role, node.name)
+```
+"""
+
+from ._native import (
+    Accessible,
+    Action,
+    AtspiError,
+    Component,
+    Connection,
+    RawEvent,
+    Text,
+)
+from . import events
+from .events import Event, EventStream
+from .tree import walk
+
+__all__ = [
+    "Accessible",
+    "Action",
+    "AtspiError",
+    "Component",
+    "Connection",
+    "Event",
+    "EventStream",
+    "RawEvent",
+    "Text",
+    "events",
+    "walk",
+]
@@ -0,0 +1,176 @@
+"""Event classes, and asynchronous iteration over events.
+
+Every event type has its own class, mirroring the `Event` enum of `atspi-common`, with the same
+fields. Classes are grouped per interface, in modules named after it:
+
+```python
+from atspi.events import object, window
+
+for event in atspi.events.iterate(connection):
+    if isinstance(event, object.StateChangedEvent) and event.state == "focused":
+        ...
+    elif isinstance(event, window.ActivateEvent):
+        ...
+```
+
+Each group also has a common base class, such as `atspi.events.ObjectEvents`.
+Events with an interface or member this module does not know about are still delivered, as
+instances of `UnknownEvent`.
+"""
+
+import asyncio
+import sys
+import threading
+import types
+from typing import Any, Dict, Iterator, Optional, Tuple, Type
+
+from ._native import EVENT_CLASSES, Accessible, Connection, RawEvent
+
+
+class Event:
+    """An AT-SPI event.
+
+    `source` is the object that emitted it. The other fields depend on the event type; their
+    names are in `fields`.
+    """
+
+    interface = ""
+    member = ""
+    fields: Tuple[str, ...] = ()
+
+    __slots__ = ("source",)
+
+    def __init__(self, source: Accessible, *values: Any):
+        self.source = source
+        for name, value in zip(self.fields, values):
+            setattr(self, name, value)
+
+    def __repr__(self) -> str:
+        values = "".join(f" {name}={getattr(self, name)!r}" for name in self.fields)
+        return f"<{type(self).__name__}{values} from {self.source!r}>"
+
+
+class UnknownEvent(Event):
+    """An event without a class: one of a newer version of AT-SPI, or a custom one of a toolkit.
+
+    The fields are the parts of the usual event body. They are missing if the signal has a body
+    of its own.
+    """
+
+    fields = ("kind", "detail1", "detail2", "any_data")
+
+    __slots__ = ("interface", "member") + fields
+
+    def __init__(self, interface: str, member: str, source: Accessible, *values: Any):
+        super().__init__(source, *values)
+        self.interface = interface
+        self.member = member
+
+    def __repr__(self) -> str:
+        return f"<UnknownEvent {self.interface}:{self.member} from {self.source!r}>"
+
+
+_BASES: Dict[str, Type[Event]] = {}
+_CLASSES: Dict[Tuple[str, str], Type[Event]] = {}
+
+
+def _define() -> None:
+    modules: Dict[str, types.ModuleType] = {}
+    for interface, member, name, fields in EVENT_CLASSES:
+        module_name = f"{__name__}.{interface.lower()}"
+        module = modules.get(interface)
+        if module is None:
+            module = types.ModuleType(module_name, f"Events on org.a11y.atspi.Event.{interface}.")
+            modules[interface] = module
+            base = type(
+                f"{interface}Events",
+                (Event,),
+                {"__slots__": (), "__module__": __name__, "interface": interface},
+            )
+            _BASES[interface] = base
+            globals()[base.__name__] = base
+            sys.modules[module_name] = module
+            globals()[interface.lower()] = module
+        fields = tuple(fields)
+        namespace = {
+            "__slots__": fields,
+            "__module__": module_name,
+            "member": member,
+            "fields": fields,
+        }
+        cls = type(name, (_BASES[interface],), namespace)
+        setattr(module, name, cls)
+        _CLASSES[(interface, member)] = cls
+
+
+_define()
+
+
+def from_raw(raw: RawEvent) -> Event:
+    """Wraps a `RawEvent` in the class for its event type."""
+    cls = _CLASSES.get((raw.interface, raw.member))
+    if cls is None:
+        return UnknownEvent(raw.interface, raw.member, raw.source, *raw.fields)
+    return cls(raw.source, *raw.fields)
+
+
+def iterate(connection: Connection) -> Iterator[Event]:
+    """Blocks for, and yields, each event received on `connection`."""
+    for raw in connection.events():
+        yield from_raw(raw)
+
+
+class EventStream:
+    """Asynchronous iteration over the events received on a connection.
+
+    A worker thread waits for events and hands them to the event loop, which stays responsive:
+
+    ```python
+    async for event in atspi.EventStream(connection):
+        ...
+    ```
+
+    Cancelling the wait for an event loses nothing; the event goes to the next one.
+    """
+
+    def __init__(self, connection: Connection):
+        self._events = connection.events()
+        self._queue: Optional[asyncio.Queue] = None
+
+    def __aiter__(self) -> "EventStream":
+        return self
+
+    async def __anext__(self) -> Event:
+        if self._queue is None:
+            self._queue = asyncio.Queue()
+            reader = threading.Thread(
+                target=self._read, args=(asyncio.get_running_loop(), self._queue), daemon=True
+            )
+            reader.start()
+        item = await self._queue.get()
+        if item is None:
+            # Leave the end in place for later calls.
+            self._queue.put_nowait(None)
+            raise StopAsyncIteration
+        if isinstance(item, BaseException):
+            raise item
+        return item
+
+    def _read(self, loop: asyncio.AbstractEventLoop, queue: asyncio.Queue) -> None:
+        """Runs on the worker thread, until the events end or the event loop closes."""
+
+        def put(item: Any) -> bool:
+            try:
+                loop.call_soon_threadsafe(queue.put_nowait, item)
+            except RuntimeError:
+                # The event loop is closed, nobody waits anymore.
+                return False
+            return True
+
+        try:
+            for raw in self._events:
+                if not put(from_raw(raw)):
+                    return
+        except Exception as error:
+            put(error)
+        put(None)
@@ -0,0 +1,24 @@
+"""Walking the accessibility tree."""
+
+from typing import Iterator, Optional
+
+from ._native import Accessible, AtspiError
+
+
+def walk(root: Accessible, max_depth: Optional[int] = None) -> Iterator[Accessible]:
+    """Yields `root` and its descendants, depth first and in document order.
+
+    Objects which disappear while walking are skipped, along with their descendants.
+    With `max_depth`, descendants deeper than that many levels below `root` are skipped.
+    """
+    stack = [(root, 0)]
+    while stack:
+        node, depth = stack.pop()
+        yield node
+        if max_depth is not None and depth >= max_depth:
+            continue
+        try:
+            children = node.children
+        except AtspiError:
+            continue
+        stack.extend((child, depth + 1) for child in reversed(children) if not child.is_null)
@@ -0,0 +1,302 @@
+//! Remote objects, with the `Accessible` interface mapped to properties.
+
+use crate::{
+	call,
+	interfaces::{Action, Component, Text},
+};
+use atspi_common::{Interface, MatchType, Role, SortOrder, State, StateSet};
+use atspi_proxies::{
+	accessible::AccessibleProxyBlocking, collection::CollectionProxyBlocking, proxy_for_blocking,
+};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::{
+	collections::{hash_map::DefaultHasher, HashMap, HashSet},
+	hash::{Hash, Hasher},
+};
+
+/// An object in the accessibility tree of some application.
+///
+/// Objects compare equal when they refer to the same remote object.
+#[pyclass(frozen, module = "atspi._native")]
+#[derive(Clone)]
+pub struct Accessible {
+	conn: zbus::blocking::Connection,
+	pub(crate) inner: atspi_common::Accessible,
+}
+
+impl Accessible {
+	pub(crate) fn new(conn: zbus::blocking::Connection, inner: atspi_common::Accessible) -> Self {
+		Self { conn, inner }
+	}
+
+	/// Another object on the same connection.
+	pub(crate) fn sibling(&self, inner: atspi_common::Accessible) -> Self {
+		Self::new(self.conn.clone(), inner)
+	}
+
+	pub(crate) fn proxy<T>(&self) -> zbus::Result<T>
+	where
+		T: From<zbus::Proxy<'static>> + zbus::ProxyDefault,
+	{
+		proxy_for_blocking(&self.conn, &self.inner)
+	}
+
+	fn accessible(&self, py: Python<'_>) -> PyResult<AccessibleProxyBlocking<'static>> {
+		call(py, || self.proxy::<AccessibleProxyBlocking>())
+	}
+
+	fn implements(&self, py: Python<'_>, interface: Interface) -> PyResult<bool> {
+		let proxy = self.accessible(py)?;
+		Ok(call(py, || proxy.get_interfaces())?.contains(interface))
+	}
+}
+
+#[pymethods]
+impl Accessible {
+	/// The bus name of the application owning this object.
+	#[getter]
+	fn bus_name(&self) -> &str {
+		&self.inner.name
+	}
+
+	/// The object path of this object.
+	#[getter]
+	fn path(&self) -> &str {
+		self.inner.path.as_str()
+	}
+
+	/// Whether this is the null object, which methods return when there is no object to return.
+	#[getter]
+	fn is_null(&self) -> bool {
+		self.inner.is_null()
+	}
+
+	#[getter]
+	fn name(&self, py: Python<'_>) -> PyResult<String> {
+		let proxy = self.accessible(py)?;
+		call(py, || proxy.name())
+	}
+
+	#[getter]
+	fn description(&self, py: Python<'_>) -> PyResult<String> {
+		let proxy = self.accessible(py)?;
+		call(py, || proxy.description())
+	}
+
+	/// The role, by its name; for example "push button".
+	#[getter]
+	fn role(&self, py: Python<'_>) -> PyResult<&'static str> {
+		let proxy = self.accessible(py)?;
+		Ok(call(py, || proxy.get_role())?.name())
+	}
+
+	/// The states, by their names; for example {"enabled", "focusable"}.
+	#[getter]
+	fn states(&self, py: Python<'_>) -> PyResult<HashSet<String>> {
+		let proxy = self.accessible(py)?;
+		Ok(call(py, || proxy.get_state())?.iter().map(String::from).collect())
+	}
+
+	/// The interfaces this object implements; for example {"org.a11y.atspi.Text"}.
+	#[getter]
+	fn interfaces(&self, py: Python<'_>) -> PyResult<HashSet<String>> {
+		let proxy = self.accessible(py)?;
+		Ok(call(py, || proxy.get_interfaces())?
+			.iter()
+			.map(|i| i.to_string())
+			.collect())
+	}
+
+	#[getter]
+	fn attributes(&self, py: Python<'_>) -> PyResult<HashMap<String, String>> {
+		let proxy = self.accessible(py)?;
+		call(py, || proxy.get_attributes())
+	}
+
+	/// The parent, or `None` for the root of an application.
+	#[getter]
+	fn parent(&self, py: Python<'_>) -> PyResult<Option<Self>> {
+		let proxy = self.accessible(py)?;
+		let parent = call(py, || proxy.parent())?;
+		Ok((!parent.is_null()).then(|| self.sibling(parent)))
+	}
+
+	#[getter]
+	fn child_count(&self, py: Python<'_>) -> PyResult<i32> {
+		let proxy = self.accessible(py)?;
+		call(py, || proxy.child_count())
+	}
+
+	#[getter]
+	fn children(&self, py: Python<'_>) -> PyResult<Vec<Self>> {
+		let proxy = self.accessible(py)?;
+		let children = call(py, || proxy.get_children())?;
+		Ok(children.into_iter().map(|child| self.sibling(child)).collect())
+	}
+
+	#[getter]
+	fn index_in_parent(&self, py: Python<'_>) -> PyResult<i32> {
+		let proxy = self.accessible(py)?;
+		call(py, || proxy.get_index_in_parent())
+	}
+
+	/// The root object of the application this object belongs to.
+	#[getter]
+	fn application(&self, py: Python<'_>) -> PyResult<Self> {
+		let proxy = self.accessible(py)?;
+		Ok(self.sibling(call(py, || proxy.get_application())?))
+	}
+
+	/// The `Text` interface, or `None` if this object does not implement it.
+	#[getter]
+	fn text(&self, py: Python<'_>) -> PyResult<Option<Text>> {
+		Ok(self.implements(py, Interface::Text)?.then(|| Text::new(self.clone())))
+	}
+
+	/// The `Component` interface, or `None` if this object does not implement it.
+	#[getter]
+	fn component(&self, py: Python<'_>) -> PyResult<Option<Component>> {
+		Ok(self
+			.implements(py, Interface::Component)?
+			.then(|| Component::new(self.clone())))
+	}
+
+	/// The `Action` interface, or `None` if this object does not implement it.
+	#[getter]
+	fn action(&self, py: Python<'_>) -> PyResult<Option<Action>> {
+		Ok(self
+			.implements(py, Interface::Action)?
+			.then(|| Action::new(self.clone())))
+	}
+
+	fn child_at(&self, py: Python<'_>, index: i32) -> PyResult<Self> {
+		let proxy = self.accessible(py)?;
+		Ok(self.sibling(call(py, || proxy.get_child_at_index(index))?))
+	}
+
+	/// Finds descendants with the `Collection` interface, in a single call.
+	///
+	/// `roles`, `states` and `interfaces` are given by name, like the properties of the same name
+	/// return them. `match_type` is one of "all", "any", "none" and "empty", and applies to each of
+	/// the criteria. `sort` is one of "canonical", "flow", "tab", "reverse-canonical",
+	/// "reverse-flow" and "reverse-tab". A `count` of 0 returns all matches.
+	#[pyo3(signature = (
+		*, roles = Vec::new(), states = Vec::new(), interfaces = Vec::new(),
+		attributes = HashMap::new(), match_type = "all", invert = false,
+		sort = "canonical", count = 0, traverse = true
+	))]
+	#[allow(clippy::too_many_arguments)]
+	fn find(
+		&self,
+		py: Python<'_>,
+		roles: Vec<String>,
+		states: Vec<String>,
+		interfaces: Vec<String>,
+		attributes: HashMap<String, String>,
+		match_type: &str,
+		invert: bool,
+		sort: &str,
+		count: i32,
+		traverse: bool,
+	) -> PyResult<Vec<Self>> {
+		let match_type = parse_match_type(match_type)?;
+		let sort = parse_sort_order(sort)?;
+		let roles = role_bits(&roles)?;
+		let states = state_bits(&states)?;
+		let interfaces = interface_names(&interfaces)?;
+		let interfaces: Vec<&str> = interfaces.iter().map(String::as_str).collect();
+		let attributes = attributes.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+		let rule = (
+			states.as_slice(),
+			match_type,
+			attributes,
+			match_type,
+			roles.as_slice(),
+			match_type,
+			interfaces.as_slice(),
+			match_type,
+			invert,
+		);
+		let matches = call(py, || {
+			self.proxy::<CollectionProxyBlocking>()?
+				.get_matches(&rule, sort, count, traverse)
+		})?;
+		Ok(matches.into_iter().map(|found| self.sibling(found)).collect())
+	}
+
+	fn __eq__(&self, other: PyRef<'_, Self>) -> bool {
+		self.inner == other.inner
+	}
+
+	fn __hash__(&self) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		self.inner.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	fn __repr__(&self) -> String {
+		format!("<Accessible {} {}>", self.inner.name, self.inner.path.as_str())
+	}
+}
+
+fn parse_match_type(name: &str) -> PyResult<MatchType> {
+	Ok(match name {
+		"all" => MatchType::All,
+		"any" => MatchType::Any,
+		"none" => MatchType::NA,
+		"empty" => MatchType::Empty,
+		_ => return Err(PyValueError::new_err(format!("unknown match type {name:?}"))),
+	})
+}
+
+fn parse_sort_order(name: &str) -> PyResult<SortOrder> {
+	Ok(match name {
+		"canonical" => SortOrder::Canonical,
+		"flow" => SortOrder::Flow,
+		"tab" => SortOrder::Tab,
+		"reverse-canonical" => SortOrder::ReverseCanonical,
+		"reverse-flow" => SortOrder::ReverseFlow,
+		"reverse-tab" => SortOrder::ReverseTab,
+		_ => return Err(PyValueError::new_err(format!("unknown sort order {name:?}"))),
+	})
+}
+
+/// Roles as the bit array `Collection` expects: bit `n` of the array is set for the role with value `n`.
+fn role_bits(names: &[String]) -> PyResult<Vec<i32>> {
+	let mut bits = vec![0u32; 4];
+	for name in names {
+		let role = (0..=u32::from(u8::MAX))
+			.filter_map(|value| Role::try_from(value).ok())
+			.find(|role| role.name() == name)
+			.ok_or_else(|| PyValueError::new_err(format!("unknown role {name:?}")))?;
+		let value = role as u32;
+		bits[(value / 32) as usize] |= 1 << (value % 32);
+	}
+	Ok(bits.into_iter().map(|word| word as i32).collect())
+}
+
+/// States as the two 32 bit halves of a [`StateSet`].
+fn state_bits(names: &[String]) -> PyResult<Vec<i32>> {
+	let mut set = StateSet::empty();
+	for name in names {
+		let state = State::from(name.as_str());
+		if state == State::Invalid && name != "invalid" {
+			return Err(PyValueError::new_err(format!("unknown state {name:?}")));
+		}
+		set.insert(state);
+	}
+	let bits = set.bits();
+	Ok(vec![(bits & 0xFFFF_FFFF) as u32 as i32, (bits >> 32) as u32 as i32])
+}
+
+/// Interface names, validated against the interfaces AT-SPI knows about.
+fn interface_names(names: &[String]) -> PyResult<Vec<String>> {
+	names
+		.iter()
+		.map(|name| {
+			Interface::try_from(name.as_str())
+				.map(|interface| interface.to_string())
+				.map_err(|_| PyValueError::new_err(format!("unknown interface {name:?}")))
+		})
+		.collect()
+}
@@ -0,0 +1,232 @@
+//! Receiving events.
+//!
+//! Signals are decoded into the `Event` enum of `atspi-common`, and each event into the values of
+//! its fields, see [`crate::event_classes`]; `atspi.events` builds the classes from the same table.
+
+use crate::{accessible::Accessible, event_classes};
+use atspi_common::{
+	events::{object::Property, Event},
+	State,
+};
+use atspi_connection::AccessibilityConnectionBlocking;
+use pyo3::{prelude::*, types::PyTuple};
+use std::sync::{Arc, Mutex};
+use zbus::{
+	blocking::MessageIterator,
+	zvariant::{OwnedValue, Value},
+	MessageType,
+};
+
+const EVENT_INTERFACE_PREFIX: &str = "org.a11y.atspi.Event.";
+
+/// A class of `atspi.events`.
+pub(crate) struct EventClass {
+	/// The interface, without the `org.a11y.atspi.Event.` prefix; for example "Object".
+	pub interface: &'static str,
+	pub member: &'static str,
+	pub name: &'static str,
+	/// The fields of the event, after `source`.
+	pub fields: &'static [&'static str],
+}
+
+/// The classes of `atspi.events`, as `(interface, member, name, fields)` tuples.
+pub(crate) fn classes() -> Vec<(&'static str, &'static str, &'static str, Vec<&'static str>)> {
+	event_classes::CLASSES
+		.iter()
+		.map(|class| (class.interface, class.member, class.name, class.fields.to_vec()))
+		.collect()
+}
+
+/// Converts the field of an event to a Python object.
+pub(crate) trait IntoField {
+	/// Objects the field refers to are reached over the connection of `source`.
+	fn into_field(self, py: Python<'_>, source: &Accessible) -> PyObject;
+}
+
+impl IntoField for String {
+	fn into_field(self, py: Python<'_>, _source: &Accessible) -> PyObject {
+		self.into_py(py)
+	}
+}
+
+impl IntoField for i32 {
+	fn into_field(self, py: Python<'_>, _source: &Accessible) -> PyObject {
+		self.into_py(py)
+	}
+}
+
+impl IntoField for atspi_common::Accessible {
+	fn into_field(self, py: Python<'_>, source: &Accessible) -> PyObject {
+		source.sibling(self).into_py(py)
+	}
+}
+
+/// A state by its name; for example "focused".
+impl IntoField for State {
+	fn into_field(self, py: Python<'_>, _source: &Accessible) -> PyObject {
+		String::from(self).into_py(py)
+	}
+}
+
+/// The new value of the property: a str, an `Accessible`, or for roles the role's name.
+impl IntoField for Property {
+	fn into_field(self, py: Python<'_>, source: &Accessible) -> PyObject {
+		match self {
+			Property::Name(text)
+			| Property::Description(text)
+			| Property::TableCaption(text)
+			| Property::TableColumnDescription(text)
+			| Property::TableColumnHeader(text)
+			| Property::TableRowDescription(text)
+			| Property::TableRowHeader(text)
+			| Property::TableSummary(text) => text.into_py(py),
+			Property::Role(role) => role.name().into_py(py),
+			Property::Parent(parent) => parent.into_field(py, source),
+			Property::Other((_, value)) => value.into_field(py, source),
+			_ => py.None(),
+		}
+	}
+}
+
+/// A str, int, float, bool or `Accessible`, or `None` for other values.
+impl IntoField for OwnedValue {
+	fn into_field(self, py: Python<'_>, source: &Accessible) -> PyObject {
+		match &*self {
+			Value::Str(s) => s.as_str().into_py(py),
+			Value::Bool(b) => b.into_py(py),
+			Value::I32(i) => i.into_py(py),
+			Value::U32(u) => u.into_py(py),
+			Value::I64(i) => i.into_py(py),
+			Value::U64(u) => u.into_py(py),
+			Value::F64(f) => f.into_py(py),
+			value => match atspi_common::Accessible::try_from(value.clone()) {
+				Ok(accessible) => accessible.into_field(py, source),
+				Err(_) => py.None(),
+			},
+		}
+	}
+}
+
+/// An event as it arrived on the bus, with the values of its fields.
+#[pyclass(frozen, get_all, module = "atspi._native")]
+pub struct RawEvent {
+	/// The interface, without the `org.a11y.atspi.Event.` prefix; for example "Object".
+	interface: String,
+	/// The signal; for example "StateChanged".
+	member: String,
+	/// The values of the fields of the event's class, in order.
+	///
+	/// Events without a class have `kind`, `detail1`, `detail2` and `any_data` instead.
+	fields: Py<PyTuple>,
+	/// The object that emitted the event.
+	source: Accessible,
+}
+
+#[pymethods]
+impl RawEvent {
+	fn __repr__(&self, py: Python<'_>) -> String {
+		format!(
+			"<RawEvent {}:{} {} from {}>",
+			self.interface,
+			self.member,
+			self.fields.as_ref(py),
+			self.source.inner.path.as_str()
+		)
+	}
+}
+
+/// A blocking iterator over the events received on a connection.
+///
+/// Waiting for the next event releases the GIL.
+#[pyclass(frozen, module = "atspi._native")]
+pub struct Events {
+	conn: zbus::blocking::Connection,
+	messages: Mutex<MessageIterator>,
+	// Keep the registry and match rules alive for as long as events are read.
+	_connection: Arc<AccessibilityConnectionBlocking>,
+}
+
+impl Events {
+	pub(crate) fn new(connection: &Arc<AccessibilityConnectionBlocking>) -> Self {
+		let conn = connection.connection().clone();
+		Self {
+			messages: Mutex::new(MessageIterator::from(&conn)),
+			conn,
+			_connection: Arc::clone(connection),
+		}
+	}
+}
+
+/// Decodes a signal of an `org.a11y.atspi.Event.*` interface.
+fn decode(message: &zbus::Message) -> Option<Event> {
+	if message.message_type() != MessageType::Signal {
+		return None;
+	}
+	let interface = message.interface()?;
+	if !interface.as_str().starts_with(EVENT_INTERFACE_PREFIX) {
+		return None;
+	}
+	Event::try_from(message).ok()
+}
+
+/// Converts `event` into a [`RawEvent`]; `None` for events which are not of an
+/// `org.a11y.atspi.Event.*` interface.
+fn convert(py: Python<'_>, conn: &zbus::blocking::Connection, event: Event) -> Option<RawEvent> {
+	let item = event.item().clone();
+	let source = Accessible::new(conn.clone(), item);
+	let (interface, member, fields) = match event {
+		Event::Unknown(unknown) => {
+			let interface = unknown.interface.strip_prefix(EVENT_INTERFACE_PREFIX)?.to_string();
+			let fields = match unknown.body {
+				Some(body) => vec![
+					body.kind.into_field(py, &source),
+					body.detail1.into_field(py, &source),
+					body.detail2.into_field(py, &source),
+					body.any_data.into_field(py, &source),
+				],
+				None => Vec::new(),
+			};
+			(interface, unknown.member, fields)
+		}
+		event => {
+			let (interface, member, fields) = event_classes::fields(py, &source, event)?;
+			(interface.to_string(), member.to_string(), fields)
+		}
+	};
+	Some(RawEvent { interface, member, fields: PyTuple::new(py, fields).into(), source })
+}
+
+#[pymethods]
+impl Events {
+	fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+		slf
+	}
+
+	fn __next__(&self, py: Python<'_>) -> PyResult<Option<RawEvent>> {
+		loop {
+			let event = py.allow_threads(|| {
+				let mut messages = self
+					.messages
+					.lock()
+					.unwrap_or_else(std::sync::PoisonError::into_inner);
+				loop {
+					match messages.next() {
+						None => return Ok(None),
+						Some(Err(e)) => return Err(e),
+						Some(Ok(message)) => {
+							if let Some(event) = decode(&message) {
+								return Ok(Some(event));
+							}
+						}
+					}
+				}
+			});
+			let Some(event) = event.map_err(crate::raise)? else {
+				return Ok(None);
+			};
+			if let Some(event) = convert(py, &self.conn, event) {
+				return Ok(Some(event));
+			}
+		}
+	}
+}
@@ -0,0 +1,399 @@
+//! The classes of `atspi.events`, one for each event of `atspi-common`.
+//!
+//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.
+
+use crate::{
+	accessible::Accessible,
+	event::{EventClass, IntoField},
+};
+use atspi_common::events::{
+	document::DocumentEvents, focus::FocusEvents, keyboard::KeyboardEvents, mouse::MouseEvents,
+	object::ObjectEvents, terminal::TerminalEvents, window::WindowEvents, Event,
+};
+use pyo3::prelude::*;
+
+/// The class of every event, with the names of its fields after `source`.
+pub(crate) const CLASSES: &[EventClass] = &[
+	EventClass {
+		interface: "Object",
+		member: "PropertyChange",
+		name: "PropertyChangeEvent",
+		fields: &["property", "value"],
+	},
+	EventClass {
+		interface: "Object",
+		member: "BoundsChanged",
+		name: "BoundsChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "LinkSelected",
+		name: "LinkSelectedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "StateChanged",
+		name: "StateChangedEvent",
+		fields: &["state", "enabled"],
+	},
+	EventClass {
+		interface: "Object",
+		member: "ChildrenChanged",
+		name: "ChildrenChangedEvent",
+		fields: &["operation", "index_in_parent", "child"],
+	},
+	EventClass {
+		interface: "Object",
+		member: "VisibleDataChanged",
+		name: "VisibleDataChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "SelectionChanged",
+		name: "SelectionChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "ModelChanged",
+		name: "ModelChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "ActiveDescendantChanged",
+		name: "ActiveDescendantChangedEvent",
+		fields: &["child"],
+	},
+	EventClass {
+		interface: "Object",
+		member: "Announcement",
+		name: "AnnouncementEvent",
+		fields: &["text", "politeness"],
+	},
+	EventClass {
+		interface: "Object",
+		member: "AttributesChanged",
+		name: "AttributesChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "RowInserted",
+		name: "RowInsertedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "RowReordered",
+		name: "RowReorderedEvent",
+		fields: &[],
+	},
+	EventClass { interface: "Object", member: "RowDeleted", name: "RowDeletedEvent", fields: &[] },
+	EventClass {
+		interface: "Object",
+		member: "ColumnInserted",
+		name: "ColumnInsertedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "ColumnReordered",
+		name: "ColumnReorderedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "ColumnDeleted",
+		name: "ColumnDeletedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "TextBoundsChanged",
+		name: "TextBoundsChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "TextSelectionChanged",
+		name: "TextSelectionChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "TextChanged",
+		name: "TextChangedEvent",
+		fields: &["operation", "start_pos", "length", "text"],
+	},
+	EventClass {
+		interface: "Object",
+		member: "TextAttributesChanged",
+		name: "TextAttributesChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Object",
+		member: "TextCaretMoved",
+		name: "TextCaretMovedEvent",
+		fields: &["position"],
+	},
+	EventClass {
+		interface: "Window",
+		member: "PropertyChange",
+		name: "PropertyChangeEvent",
+		fields: &["property"],
+	},
+	EventClass { interface: "Window", member: "Minimize", name: "MinimizeEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Maximize", name: "MaximizeEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Restore", name: "RestoreEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Close", name: "CloseEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Create", name: "CreateEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Reparent", name: "ReparentEvent", fields: &[] },
+	EventClass {
+		interface: "Window",
+		member: "DesktopCreate",
+		name: "DesktopCreateEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Window",
+		member: "DesktopDestroy",
+		name: "DesktopDestroyEvent",
+		fields: &[],
+	},
+	EventClass { interface: "Window", member: "Destroy", name: "DestroyEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Activate", name: "ActivateEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Deactivate", name: "DeactivateEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Raise", name: "RaiseEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Lower", name: "LowerEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Move", name: "MoveEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Resize", name: "ResizeEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Shade", name: "ShadeEvent", fields: &[] },
+	EventClass { interface: "Window", member: "uUshade", name: "UUshadeEvent", fields: &[] },
+	EventClass { interface: "Window", member: "Restyle", name: "RestyleEvent", fields: &[] },
+	EventClass { interface: "Mouse", member: "Abs", name: "AbsEvent", fields: &["x", "y"] },
+	EventClass { interface: "Mouse", member: "Rel", name: "RelEvent", fields: &["x", "y"] },
+	EventClass {
+		interface: "Mouse",
+		member: "Button",
+		name: "ButtonEvent",
+		fields: &["detail", "mouse_x", "mouse_y"],
+	},
+	EventClass {
+		interface: "Keyboard",
+		member: "Modifiers",
+		name: "ModifiersEvent",
+		fields: &["previous_modifiers", "current_modifiers"],
+	},
+	EventClass {
+		interface: "Terminal",
+		member: "LineChanged",
+		name: "LineChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Terminal",
+		member: "ColumncountChanged",
+		name: "ColumnCountChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Terminal",
+		member: "LinecountChanged",
+		name: "LineCountChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Terminal",
+		member: "ApplicationChanged",
+		name: "ApplicationChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Terminal",
+		member: "CharwidthChanged",
+		name: "CharWidthChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Document",
+		member: "LoadComplete",
+		name: "LoadCompleteEvent",
+		fields: &[],
+	},
+	EventClass { interface: "Document", member: "Reload", name: "ReloadEvent", fields: &[] },
+	EventClass {
+		interface: "Document",
+		member: "LoadStopped",
+		name: "LoadStoppedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Document",
+		member: "ContentChanged",
+		name: "ContentChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Document",
+		member: "AttributesChanged",
+		name: "AttributesChangedEvent",
+		fields: &[],
+	},
+	EventClass {
+		interface: "Document",
+		member: "PageChanged",
+		name: "PageChangedEvent",
+		fields: &[],
+	},
+	EventClass { interface: "Focus", member: "Focus", name: "FocusEvent", fields: &[] },
+];
+
+/// The interface, member and field values of `event`; `None` if it has no class.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn fields(
+	py: Python<'_>,
+	source: &Accessible,
+	event: Event,
+) -> Option<(&'static str, &'static str, Vec<PyObject>)> {
+	Some(match event {
+		Event::Object(ObjectEvents::PropertyChange(event)) => (
+			"Object",
+			"PropertyChange",
+			vec![event.property.into_field(py, source), event.value.into_field(py, source)],
+		),
+		Event::Object(ObjectEvents::BoundsChanged(_)) => ("Object", "BoundsChanged", vec![]),
+		Event::Object(ObjectEvents::LinkSelected(_)) => ("Object", "LinkSelected", vec![]),
+		Event::Object(ObjectEvents::StateChanged(event)) => (
+			"Object",
+			"StateChanged",
+			vec![event.state.into_field(py, source), event.enabled.into_field(py, source)],
+		),
+		Event::Object(ObjectEvents::ChildrenChanged(event)) => (
+			"Object",
+			"ChildrenChanged",
+			vec![
+				event.operation.into_field(py, source),
+				event.index_in_parent.into_field(py, source),
+				event.child.into_field(py, source),
+			],
+		),
+		Event::Object(ObjectEvents::VisibleDataChanged(_)) => {
+			("Object", "VisibleDataChanged", vec![])
+		}
+		Event::Object(ObjectEvents::SelectionChanged(_)) => ("Object", "SelectionChanged", vec![]),
+		Event::Object(ObjectEvents::ModelChanged(_)) => ("Object", "ModelChanged", vec![]),
+		Event::Object(ObjectEvents::ActiveDescendantChanged(event)) => {
+			("Object", "ActiveDescendantChanged", vec![event.child.into_field(py, source)])
+		}
+		Event::Object(ObjectEvents::Announcement(event)) => (
+			"Object",
+			"Announcement",
+			vec![event.text.into_field(py, source), event.politeness.into_field(py, source)],
+		),
+		Event::Object(ObjectEvents::AttributesChanged(_)) => {
+			("Object", "AttributesChanged", vec![])
+		}
+		Event::Object(ObjectEvents::RowInserted(_)) => ("Object", "RowInserted", vec![]),
+		Event::Object(ObjectEvents::RowReordered(_)) => ("Object", "RowReordered", vec![]),
+		Event::Object(ObjectEvents::RowDeleted(_)) => ("Object", "RowDeleted", vec![]),
+		Event::Object(ObjectEvents::ColumnInserted(_)) => ("Object", "ColumnInserted", vec![]),
+		Event::Object(ObjectEvents::ColumnReordered(_)) => ("Object", "ColumnReordered", vec![]),
+		Event::Object(ObjectEvents::ColumnDeleted(_)) => ("Object", "ColumnDeleted", vec![]),
+		Event::Object(ObjectEvents::TextBoundsChanged(_)) => {
+			("Object", "TextBoundsChanged", vec![])
+		}
+		Event::Object(ObjectEvents::TextSelectionChanged(_)) => {
+			("Object", "TextSelectionChanged", vec![])
+		}
+		Event::Object(ObjectEvents::TextChanged(event)) => (
+			"Object",
+			"TextChanged",
+			vec![
+				event.operation.into_field(py, source),
+				event.start_pos.into_field(py, source),
+				event.length.into_field(py, source),
+				event.text.into_field(py, source),
+			],
+		),
+		Event::Object(ObjectEvents::TextAttributesChanged(_)) => {
+			("Object", "TextAttributesChanged", vec![])
+		}
+		Event::Object(ObjectEvents::TextCaretMoved(event)) => {
+			("Object", "TextCaretMoved", vec![event.position.into_field(py, source)])
+		}
+		Event::Window(WindowEvents::PropertyChange(event)) => {
+			("Window", "PropertyChange", vec![event.property.into_field(py, source)])
+		}
+		Event::Window(WindowEvents::Minimize(_)) => ("Window", "Minimize", vec![]),
+		Event::Window(WindowEvents::Maximize(_)) => ("Window", "Maximize", vec![]),
+		Event::Window(WindowEvents::Restore(_)) => ("Window", "Restore", vec![]),
+		Event::Window(WindowEvents::Close(_)) => ("Window", "Close", vec![]),
+		Event::Window(WindowEvents::Create(_)) => ("Window", "Create", vec![]),
+		Event::Window(WindowEvents::Reparent(_)) => ("Window", "Reparent", vec![]),
+		Event::Window(WindowEvents::DesktopCreate(_)) => ("Window", "DesktopCreate", vec![]),
+		Event::Window(WindowEvents::DesktopDestroy(_)) => ("Window", "DesktopDestroy", vec![]),
+		Event::Window(WindowEvents::Destroy(_)) => ("Window", "Destroy", vec![]),
+		Event::Window(WindowEvents::Activate(_)) => ("Window", "Activate", vec![]),
+		Event::Window(WindowEvents::Deactivate(_)) => ("Window", "Deactivate", vec![]),
+		Event::Window(WindowEvents::Raise(_)) => ("Window", "Raise", vec![]),
+		Event::Window(WindowEvents::Lower(_)) => ("Window", "Lower", vec![]),
+		Event::Window(WindowEvents::Move(_)) => ("Window", "Move", vec![]),
+		Event::Window(WindowEvents::Resize(_)) => ("Window", "Resize", vec![]),
+		Event::Window(WindowEvents::Shade(_)) => ("Window", "Shade", vec![]),
+		Event::Window(WindowEvents::UUshade(_)) => ("Window", "uUshade", vec![]),
+		Event::Window(WindowEvents::Restyle(_)) => ("Window", "Restyle", vec![]),
+		Event::Mouse(MouseEvents::Abs(event)) => {
+			("Mouse", "Abs", vec![event.x.into_field(py, source), event.y.into_field(py, source)])
+		}
+		Event::Mouse(MouseEvents::Rel(event)) => {
+			("Mouse", "Rel", vec![event.x.into_field(py, source), event.y.into_field(py, source)])
+		}
+		Event::Mouse(MouseEvents::Button(event)) => (
+			"Mouse",
+			"Button",
+			vec![
+				event.detail.into_field(py, source),
+				event.mouse_x.into_field(py, source),
+				event.mouse_y.into_field(py, source),
+			],
+		),
+		Event::Keyboard(KeyboardEvents::Modifiers(event)) => (
+			"Keyboard",
+			"Modifiers",
+			vec![
+				event.previous_modifiers.into_field(py, source),
+				event.current_modifiers.into_field(py, source),
+			],
+		),
+		Event::Terminal(TerminalEvents::LineChanged(_)) => ("Terminal", "LineChanged", vec![]),
+		Event::Terminal(TerminalEvents::ColumnCountChanged(_)) => {
+			("Terminal", "ColumncountChanged", vec![])
+		}
+		Event::Terminal(TerminalEvents::LineCountChanged(_)) => {
+			("Terminal", "LinecountChanged", vec![])
+		}
+		Event::Terminal(TerminalEvents::ApplicationChanged(_)) => {
+			("Terminal", "ApplicationChanged", vec![])
+		}
+		Event::Terminal(TerminalEvents::CharWidthChanged(_)) => {
+			("Terminal", "CharwidthChanged", vec![])
+		}
+		Event::Document(DocumentEvents::LoadComplete(_)) => ("Document", "LoadComplete", vec![]),
+		Event::Document(DocumentEvents::Reload(_)) => ("Document", "Reload", vec![]),
+		Event::Document(DocumentEvents::LoadStopped(_)) => ("Document", "LoadStopped", vec![]),
+		Event::Document(DocumentEvents::ContentChanged(_)) => {
+			("Document", "ContentChanged", vec![])
+		}
+		Event::Document(DocumentEvents::AttributesChanged(_)) => {
+			("Document", "AttributesChanged", vec![])
+		}
+		Event::Document(DocumentEvents::PageChanged(_)) => ("Document", "PageChanged", vec![]),
+		Event::Focus(FocusEvents::Focus(_)) => ("Focus", "Focus", vec![]),
+		_ => return None,
+	})
+}
@@ -0,0 +1,144 @@
+//! The `Text`, `Component` and `Action` interfaces.
+
+use crate::{accessible::Accessible, call};
+use atspi_common::CoordType;
+use atspi_proxies::{
+	action::ActionProxyBlocking, component::ComponentProxyBlocking, text::TextProxyBlocking,
+};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+fn parse_coord_type(name: &str) -> PyResult<CoordType> {
+	Ok(match name {
+		"screen" => CoordType::Screen,
+		"window" => CoordType::Window,
+		"parent" => CoordType::Parent,
+		_ => return Err(PyValueError::new_err(format!("unknown coordinate type {name:?}"))),
+	})
+}
+
+/// The text of an object, and its caret.
+#[pyclass(frozen, module = "atspi._native")]
+pub struct Text {
+	accessible: Accessible,
+}
+
+impl Text {
+	pub(crate) fn new(accessible: Accessible) -> Self {
+		Self { accessible }
+	}
+}
+
+#[pymethods]
+impl Text {
+	#[getter]
+	fn character_count(&self, py: Python<'_>) -> PyResult<i32> {
+		call(py, || self.accessible.proxy::<TextProxyBlocking>()?.character_count())
+	}
+
+	/// All of the text.
+	#[getter(text)]
+	fn all_text(&self, py: Python<'_>) -> PyResult<String> {
+		call(py, || self.accessible.proxy::<TextProxyBlocking>()?.get_text(0, -1))
+	}
+
+	#[getter]
+	fn caret_offset(&self, py: Python<'_>) -> PyResult<i32> {
+		call(py, || self.accessible.proxy::<TextProxyBlocking>()?.caret_offset())
+	}
+
+	#[setter]
+	fn set_caret_offset(&self, py: Python<'_>, offset: i32) -> PyResult<()> {
+		call(py, || self.accessible.proxy::<TextProxyBlocking>()?.set_caret_offset(offset))?;
+		Ok(())
+	}
+
+	/// The text between the character offsets `start` and `end`; -1 means the end of the text.
+	fn get_text(&self, py: Python<'_>, start: i32, end: i32) -> PyResult<String> {
+		call(py, || self.accessible.proxy::<TextProxyBlocking>()?.get_text(start, end))
+	}
+}
+
+/// The position and size of an object on screen.
+#[pyclass(frozen, module = "atspi._native")]
+pub struct Component {
+	accessible: Accessible,
+}
+
+impl Component {
+	pub(crate) fn new(accessible: Accessible) -> Self {
+		Self { accessible }
+	}
+}
+
+#[pymethods]
+impl Component {
+	/// `(x, y, width, height)`, relative to the "screen", "window" or "parent".
+	#[pyo3(signature = (coord_type = "screen"))]
+	fn extents(&self, py: Python<'_>, coord_type: &str) -> PyResult<(i32, i32, i32, i32)> {
+		let coord_type = parse_coord_type(coord_type)?;
+		call(py, || {
+			self.accessible
+				.proxy::<ComponentProxyBlocking>()?
+				.get_extents(coord_type)
+		})
+	}
+
+	/// The descendant at the point `x`, `y`, or `None`.
+	#[pyo3(signature = (x, y, coord_type = "screen"))]
+	fn accessible_at_point(
+		&self,
+		py: Python<'_>,
+		x: i32,
+		y: i32,
+		coord_type: &str,
+	) -> PyResult<Option<Accessible>> {
+		let coord_type = parse_coord_type(coord_type)?;
+		let found = call(py, || {
+			self.accessible
+				.proxy::<ComponentProxyBlocking>()?
+				.get_accessible_at_point(x, y, coord_type)
+		})?;
+		Ok((!found.is_null()).then(|| self.accessible.sibling(found)))
+	}
+
+	/// Asks for the object to receive keyboard focus; returns whether that succeeded.
+	fn grab_focus(&self, py: Python<'_>) -> PyResult<bool> {
+		call(py, || self.accessible.proxy::<ComponentProxyBlocking>()?.grab_focus())
+	}
+}
+
+/// The actions an object can perform, such as "click".
+#[pyclass(frozen, module = "atspi._native")]
+pub struct Action {
+	accessible: Accessible,
+}
+
+impl Action {
+	pub(crate) fn new(accessible: Accessible) -> Self {
+		Self { accessible }
+	}
+}
+
+#[pymethods]
+impl Action {
+	/// `(name, description, key binding)` for each action, in order.
+	#[getter]
+	fn actions(&self, py: Python<'_>) -> PyResult<Vec<(String, String, String)>> {
+		call(py, || self.accessible.proxy::<ActionProxyBlocking>()?.get_actions())
+	}
+
+	/// Performs the action at `index`; returns whether that succeeded.
+	fn do_action(&self, py: Python<'_>, index: i32) -> PyResult<bool> {
+		call(py, || self.accessible.proxy::<ActionProxyBlocking>()?.do_action(index))
+	}
+
+	/// Performs the first action called `name`; returns whether that succeeded.
+	fn do_named(&self, py: Python<'_>, name: &str) -> PyResult<bool> {
+		let actions = self.actions(py)?;
+		let index = actions
+			.iter()
+			.position(|(action, _, _)| action == name)
+			.ok_or_else(|| PyValueError::new_err(format!("no action called {name:?}")))?;
+		self.do_action(py, i32::try_from(index).map_err(crate::raise)?)
+	}
+}
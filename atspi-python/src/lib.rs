@@ -0,0 +1,119 @@
+//! # `atspi._native`
+//!
+//! The compiled half of the `atspi` Python package. It wraps the blocking API of
+//! `atspi-connection` and `atspi-proxies`; the pure Python half in `python/atspi` adds tree walking,
+//! asynchronous iteration and the event classes on top.
+//!
+//! Every call into D-Bus releases the GIL while it waits for the reply.
+
+#![deny(clippy::all, clippy::pedantic, clippy::cargo, unsafe_code)]
+#![allow(clippy::multiple_crate_versions, clippy::needless_pass_by_value)]
+
+mod accessible;
+mod event;
+mod event_classes;
+mod interfaces;
+
+use accessible::Accessible;
+use atspi_common::events::{
+	document::DocumentEvents, focus::FocusEvents, keyboard::KeyboardEvents, mouse::MouseEvents,
+	object::ObjectEvents, terminal::TerminalEvents, window::WindowEvents,
+};
+use atspi_connection::{application::desktop, AccessibilityConnectionBlocking};
+use event::{Events, RawEvent};
+use interfaces::{Action, Component, Text};
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+use std::sync::Arc;
+
+create_exception!(_native, AtspiError, PyException, "A call over AT-SPI failed.");
+
+/// Converts any error of the Rust crates into an [`AtspiError`].
+pub(crate) fn raise(e: impl std::fmt::Display) -> PyErr {
+	AtspiError::new_err(e.to_string())
+}
+
+/// Runs `f` without holding the GIL.
+pub(crate) fn call<T, E, F>(py: Python<'_>, f: F) -> PyResult<T>
+where
+	T: Send,
+	E: std::fmt::Display + Send,
+	F: FnOnce() -> Result<T, E> + Send,
+{
+	py.allow_threads(f).map_err(raise)
+}
+
+/// A connection to the accessibility bus.
+#[pyclass(frozen, module = "atspi._native")]
+pub struct Connection {
+	inner: Arc<AccessibilityConnectionBlocking>,
+}
+
+#[pymethods]
+impl Connection {
+	/// Connects to the accessibility bus of the current session.
+	#[staticmethod]
+	fn open(py: Python<'_>) -> PyResult<Self> {
+		let inner = call(py, AccessibilityConnectionBlocking::open)?;
+		Ok(Self { inner: Arc::new(inner) })
+	}
+
+	/// Asks applications to emit one category of events, and subscribes to them.
+	///
+	/// `category` is one of "object", "window", "focus", "document", "keyboard", "mouse" or "terminal".
+	fn register(&self, py: Python<'_>, category: &str) -> PyResult<()> {
+		let inner = &self.inner;
+		match category {
+			"object" => call(py, || inner.register_event::<ObjectEvents>()),
+			"window" => call(py, || inner.register_event::<WindowEvents>()),
+			"focus" => call(py, || inner.register_event::<FocusEvents>()),
+			"document" => call(py, || inner.register_event::<DocumentEvents>()),
+			"keyboard" => call(py, || inner.register_event::<KeyboardEvents>()),
+			"mouse" => call(py, || inner.register_event::<MouseEvents>()),
+			"terminal" => call(py, || inner.register_event::<TerminalEvents>()),
+			_ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+				"unknown event category {category:?}"
+			))),
+		}
+	}
+
+	/// Undoes `register`.
+	fn deregister(&self, py: Python<'_>, category: &str) -> PyResult<()> {
+		let inner = &self.inner;
+		match category {
+			"object" => call(py, || inner.deregister_event::<ObjectEvents>()),
+			"window" => call(py, || inner.deregister_event::<WindowEvents>()),
+			"focus" => call(py, || inner.deregister_event::<FocusEvents>()),
+			"document" => call(py, || inner.deregister_event::<DocumentEvents>()),
+			"keyboard" => call(py, || inner.deregister_event::<KeyboardEvents>()),
+			"mouse" => call(py, || inner.deregister_event::<MouseEvents>()),
+			"terminal" => call(py, || inner.deregister_event::<TerminalEvents>()),
+			_ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+				"unknown event category {category:?}"
+			))),
+		}
+	}
+
+	/// The desktop root, whose children are the root objects of all applications.
+	fn desktop(&self) -> Accessible {
+		Accessible::new(self.inner.connection().clone(), desktop())
+	}
+
+	/// A blocking iterator over the events received on this connection.
+	fn events(&self) -> Events {
+		Events::new(&self.inner)
+	}
+}
+
+#[pymodule]
+fn _native(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+	m.add("AtspiError", py.get_type::<AtspiError>())?;
+	m.add_class::<Connection>()?;
+	m.add_class::<Accessible>()?;
+	m.add_class::<Text>()?;
+	m.add_class::<Component>()?;
+	m.add_class::<Action>()?;
+	m.add_class::<Events>()?;
+	m.add_class::<RawEvent>()?;
+	m.add("EVENT_CLASSES", event::classes())?;
+	Ok(())
+}
@@ -0,0 +1,75 @@
+"""Checks that the extension module loads, and fails cleanly without an accessibility bus."""
+
+import asyncio
+import queue
+from types import SimpleNamespace
+
+import pytest
+
+import atspi
+from atspi import events
+
+
+def test_exports():
+    for name in atspi.__all__:
+        assert hasattr(atspi, name), name
+
+
+def test_event_classes():
+    state_changed = events.object.StateChangedEvent
+    assert issubclass(state_changed, events.ObjectEvents)
+    assert issubclass(events.window.ActivateEvent, events.Event)
+    assert (state_changed.interface, state_changed.member) == ("Object", "StateChanged")
+    assert state_changed.fields == ("state", "enabled")
+    assert events.terminal.ColumnCountChangedEvent.member == "ColumncountChanged"
+
+
+def raw(interface, member, *fields):
+    return SimpleNamespace(interface=interface, member=member, fields=fields, source=None)
+
+
+def test_typed_fields():
+    event = events.from_raw(raw("Object", "TextChanged", "insert", 3, 5, "hello"))
+    assert isinstance(event, events.object.TextChangedEvent)
+    assert (event.operation, event.start_pos, event.length, event.text) == ("insert", 3, 5, "hello")
+
+    event = events.from_raw(raw("Object", "SomethingNew", "kind", 1, 2, None))
+    assert isinstance(event, events.UnknownEvent)
+    assert (event.interface, event.member, event.kind, event.detail1) == (
+        "Object",
+        "SomethingNew",
+        "kind",
+        1,
+    )
+
+
+class FakeConnection:
+    """Hands out the events put into `pending`, blocking until there is one."""
+
+    def __init__(self):
+        self.pending = queue.Queue()
+
+    def events(self):
+        return iter(self.pending.get, None)
+
+
+def test_cancelled_wait_loses_no_event():
+    async def run():
+        connection = FakeConnection()
+        stream = atspi.EventStream(connection)
+        with pytest.raises(asyncio.TimeoutError):
+            await asyncio.wait_for(stream.__anext__(), 0.1)
+        connection.pending.put(raw("Window", "Activate"))
+        event = await asyncio.wait_for(stream.__anext__(), 5)
+        assert isinstance(event, events.window.ActivateEvent)
+        connection.pending.put(None)
+        with pytest.raises(StopAsyncIteration):
+            await stream.__anext__()
+
+    asyncio.run(run())
+
+
+def test_open_without_bus(monkeypatch):
+    monkeypatch.setenv("DBUS_SESSION_BUS_ADDRESS", "unix:path=/nonexistent/atspi-python-test")
+    with pytest.raises(atspi.AtspiError):
+        atspi.Connection.open()
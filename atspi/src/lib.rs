@@ -1,9 +1,3 @@
-#[cfg(all(feature = "async-std", feature = "tokio"))]
-compile_error!("You may not mix the async-std and tokio features.");
-
-#[cfg(all(not(feature = "async-std"), not(feature = "tokio")))]
-compile_error!("You must specify either the async-std or tokio feature.");
-
 pub use atspi_common::*;
 
 #[cfg(feature = "proxies")]
@@ -0,0 +1,254 @@
+//! Generates Rust code from the introspection XML in `xml/`.
+//!
+//! * the match rules, body signatures and argument names of all signals end up in
+//!   `atspi-common/src/events/signals.rs`, which the event types take their constants from;
+//! * the events of `Event.xml` become the modules in `atspi-common/src/events/`, and the event
+//!   classes of `atspi-python`, see [`events`];
+//! * the other interfaces become the proxy traits of `atspi-proxies`, see [`proxies`].
+//!
+//! The Rust code is formatted with `rustfmt`, so the checked-in code is what `cargo fmt` leaves.
+//!
+//! Not from the XML, but generated along with it: the C header of `atspi-ffi`, see [`header`].
+
+use crate::{
+	events, header, proxies,
+	xml::{self, Element},
+};
+use std::{
+	fmt::Write,
+	fs,
+	io::Write as _,
+	path::Path,
+	process::{Command, Stdio},
+};
+
+/// Where the signal table goes, relative to the root of the workspace.
+pub const SIGNALS_PATH: &str = "atspi-common/src/events/signals.rs";
+
+/// A generated file.
+pub struct Output {
+	/// The path, relative to the root of the workspace.
+	pub path: String,
+	pub contents: String,
+}
+
+struct Signal {
+	interface: String,
+	member: String,
+	/// Names and types of the arguments.
+	arguments: Vec<(String, String)>,
+}
+
+/// Generates all files from the XML in `root/xml`, and the C header.
+pub fn generate(root: &Path) -> Result<Vec<Output>, String> {
+	let mut files: Vec<_> = fs::read_dir(root.join("xml"))
+		.map_err(|e| format!("reading xml/: {e}"))?
+		.filter_map(Result::ok)
+		.map(|entry| entry.path())
+		.filter(|path| matches!(path.extension(), Some(extension) if extension == "xml"))
+		.collect();
+	files.sort();
+	let mut signals = Vec::new();
+	let mut outputs = Vec::new();
+	for file in files {
+		let text = fs::read_to_string(&file).map_err(|e| format!("{}: {e}", file.display()))?;
+		// `Bus.xml` holds the proxies `zbus-xmlgen` generated, not introspection data.
+		if !text.trim_start().starts_with('<') {
+			continue;
+		}
+		let node = xml::parse(&text).map_err(|e| format!("{}: {e}", file.display()))?;
+		signals.extend(signals_of(&node)?);
+		let event_interfaces: Vec<_> = node
+			.children("interface")
+			.filter(|interface| {
+				interface
+					.attribute("name")
+					.unwrap_or_default()
+					.starts_with("org.a11y.atspi.Event.")
+			})
+			.collect();
+		if !event_interfaces.is_empty() {
+			outputs.push(events::classes(&event_interfaces)?);
+		}
+		for interface in node.children("interface") {
+			let name = interface.attribute("name").unwrap_or_default();
+			if name.starts_with("org.a11y.atspi.Event.") {
+				outputs.push(events::module(interface)?);
+			} else if interface.children("method").next().is_some()
+				|| interface.children("property").next().is_some()
+			{
+				outputs.push(proxies::proxy(root, interface)?);
+			}
+		}
+	}
+	outputs.push(Output { path: SIGNALS_PATH.to_string(), contents: signal_table(&signals) });
+	for output in &mut outputs {
+		output.contents = rustfmt(root, &output.contents)
+			.map_err(|e| format!("formatting {}: {e}", output.path))?;
+	}
+	outputs.push(header::header(root)?);
+	Ok(outputs)
+}
+
+/// Formats `code` with the `rustfmt.toml` of the workspace.
+fn rustfmt(root: &Path, code: &str) -> Result<String, String> {
+	let mut child = Command::new("rustfmt")
+		.args(["--edition", "2021", "--config-path"])
+		.arg(root)
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()
+		.map_err(|e| format!("running rustfmt: {e}"))?;
+	child
+		.stdin
+		.take()
+		.ok_or("rustfmt has no stdin")?
+		.write_all(code.as_bytes())
+		.map_err(|e| e.to_string())?;
+	let output = child.wait_with_output().map_err(|e| e.to_string())?;
+	if !output.status.success() {
+		return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+	}
+	String::from_utf8(output.stdout).map_err(|e| e.to_string())
+}
+
+fn signals_of(node: &Element) -> Result<Vec<Signal>, String> {
+	let mut signals = Vec::new();
+	for interface in node.children("interface") {
+		let name = interface.attribute("name").ok_or("interface without a name")?;
+		for signal in interface.children("signal") {
+			let member = signal
+				.attribute("name")
+				.ok_or_else(|| format!("signal of {name} without a name"))?;
+			let arguments = signal
+				.children("arg")
+				.map(|arg| {
+					let ty = arg
+						.attribute("type")
+						.ok_or_else(|| format!("{name}.{member}: argument without a type"))?;
+					Ok((arg.attribute("name").unwrap_or_default().to_string(), ty.to_string()))
+				})
+				.collect::<Result<_, String>>()?;
+			signals.push(Signal {
+				interface: name.to_string(),
+				member: member.to_string(),
+				arguments,
+			});
+		}
+	}
+	Ok(signals)
+}
+
+/// `org.a11y.atspi.Event.Object` becomes `OBJECT`, `org.a11y.atspi.DeviceEventListener`
+/// becomes `DEVICE_EVENT_LISTENER`.
+pub fn interface_prefix(interface: &str) -> String {
+	screaming_snake(interface.rsplit('.').next().unwrap_or(interface))
+}
+
+pub fn screaming_snake(name: &str) -> String {
+	snake(name).to_uppercase()
+}
+
+/// `TextCaretMoved` becomes `text_caret_moved` and `GetNSelections` becomes `get_nselections`,
+/// the way `zbus-xmlgen` names them.
+pub fn snake(name: &str) -> String {
+	let mut out = String::new();
+	let mut previous_lower = false;
+	for c in name.chars() {
+		if c.is_uppercase() && previous_lower {
+			out.push('_');
+		}
+		previous_lower = c.is_lowercase() || c.is_ascii_digit();
+		out.extend(c.to_lowercase());
+	}
+	out
+}
+
+const HEADER: &str = "\
+//! The signals declared in the introspection XML of AT-SPI.
+//!
+//! This file is generated from the `xml/` directory by `cargo xtask codegen`; do not edit it.
+
+/// A signal, as declared in the introspection XML.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Signal {
+	/// The D-Bus interface the signal belongs to.
+	pub interface: &'static str,
+	/// The D-Bus member name of the signal.
+	pub member: &'static str,
+	/// The match rule selecting the signal.
+	pub match_rule: &'static str,
+	/// The signature of the body of the signal, without outer parentheses.
+	pub signature: &'static str,
+	/// The names of the arguments; arguments without a name have an empty one.
+	pub arguments: &'static [&'static str],
+}
+";
+
+fn signal_table(signals: &[Signal]) -> String {
+	let mut out = HEADER.to_string();
+	let mut names = Vec::new();
+	let mut interface = "";
+	for signal in signals {
+		let prefix = interface_prefix(&signal.interface);
+		if signal.interface != interface {
+			interface = &signal.interface;
+			let _ = write!(
+				out,
+				"\n/// The match rule selecting all signals of `{interface}`.\n\
+				pub const {prefix}_MATCH_RULE: &str = \"type='signal',interface='{interface}'\";\n"
+			);
+		}
+		let name = format!("{prefix}_{}", screaming_snake(&signal.member));
+		let signature: String = signal.arguments.iter().map(|(_, ty)| ty.as_str()).collect();
+		let arguments: Vec<_> =
+			signal.arguments.iter().map(|(name, _)| format!("{name:?}")).collect();
+		let _ = write!(
+			out,
+			"\n/// `{interface}.{member}`.\n\
+			pub const {name}: Signal = Signal {{\n\
+			\tinterface: \"{interface}\",\n\
+			\tmember: \"{member}\",\n\
+			\tmatch_rule: \"type='signal',interface='{interface}',member='{member}'\",\n\
+			\tsignature: \"{signature}\",\n\
+			\targuments: &[{arguments}],\n\
+			}};\n",
+			member = signal.member,
+			arguments = arguments.join(", "),
+		);
+		names.push(name);
+	}
+	out.push_str("\n/// All signals, in the order of the XML files and their declarations.\npub const SIGNALS: &[Signal] = &[\n");
+	for name in names {
+		let _ = writeln!(out, "\t{name},");
+	}
+	out.push_str("];\n");
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn names_constants() {
+		assert_eq!(interface_prefix("org.a11y.atspi.Event.Object"), "OBJECT");
+		assert_eq!(interface_prefix("org.a11y.atspi.DeviceEventListener"), "DEVICE_EVENT_LISTENER");
+		assert_eq!(screaming_snake("TextCaretMoved"), "TEXT_CARET_MOVED");
+		assert_eq!(screaming_snake("uUshade"), "U_USHADE");
+	}
+
+	#[test]
+	fn checked_in_code_is_up_to_date() {
+		let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+		for output in generate(&root).unwrap() {
+			let checked_in = fs::read_to_string(root.join(&output.path)).unwrap();
+			assert!(
+				checked_in == output.contents,
+				"{} is out of date, run `cargo xtask codegen`",
+				output.path
+			);
+		}
+	}
+}
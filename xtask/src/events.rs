@@ -0,0 +1,521 @@
+//! Generates the event modules in `atspi-common/src/events/` from the signals in `xml/Event.xml`.
+//!
+//! Every one of those signals has the same body, `(siiva{sv})`. The XML names the parts of it a
+//! signal uses, and those become the fields of its event; what the XML can't say is in the tables
+//! below.
+//!
+//! The same events also become the classes of `atspi.events`, in `atspi-python`, see [`classes`].
+
+use crate::{
+	codegen::{interface_prefix, screaming_snake, snake, Output},
+	xml::Element,
+};
+use std::fmt::Write;
+
+const PREFIX: &str = "org.a11y.atspi.Event.";
+
+/// Where the Python event classes go, relative to the root of the workspace.
+const CLASSES_PATH: &str = "atspi-python/src/event_classes.rs";
+
+/// The fields of `EventBodyOwned` the arguments of a signal go to, in order.
+const SLOTS: [&str; 4] = ["kind", "detail1", "detail2", "any_data"];
+
+/// A field which doesn't follow from the XML: the argument has no name there, or has a type the
+/// body can't say more about.
+struct Field {
+	/// `Interface.Member`, without the `org.a11y.atspi.Event.` prefix.
+	signal: &'static str,
+	/// The position of the argument in the body.
+	position: usize,
+	name: &'static str,
+	ty: &'static str,
+	/// Reads the field out of `body`.
+	read: &'static str,
+	/// Writes the field, taken from `event`, back into its place in the body.
+	write: &'static str,
+	doc: Option<&'static str>,
+}
+
+const FIELDS: &[Field] = &[
+	Field {
+		signal: "Object.PropertyChange",
+		position: 0,
+		name: "property",
+		ty: "String",
+		read: "body.kind.clone()",
+		write: "event.property",
+		doc: None,
+	},
+	Field {
+		signal: "Object.PropertyChange",
+		position: 3,
+		name: "value",
+		ty: "Property",
+		read: "body.try_into()?",
+		write: "event.value.into()",
+		doc: None,
+	},
+	Field {
+		signal: "Object.StateChanged",
+		position: 0,
+		name: "state",
+		ty: "crate::State",
+		read: "body.kind.into()",
+		write: "event.state.into()",
+		doc: None,
+	},
+	Field {
+		signal: "Object.ChildrenChanged",
+		position: 3,
+		name: "child",
+		ty: "Accessible",
+		read: "body.any_data.try_into()?",
+		write: "zvariant::Value::from(event.child).into()",
+		doc: None,
+	},
+	Field {
+		signal: "Object.ActiveDescendantChanged",
+		position: 3,
+		name: "child",
+		ty: "Accessible",
+		read: "body.any_data.try_into()?",
+		write: "zvariant::Value::from(event.child).into()",
+		doc: None,
+	},
+	Field {
+		signal: "Object.Announcement",
+		position: 1,
+		name: "politeness",
+		ty: "i32",
+		read: "body.detail1",
+		write: "event.politeness",
+		doc: Some(
+			"How urgent the announcement is: 1 for polite, 2 for assertive; `ATSPI_LIVE_*` in libatspi.",
+		),
+	},
+	// The XML calls it `detail`; `operation` is what it is, "insert" or "delete".
+	Field {
+		signal: "Object.TextChanged",
+		position: 0,
+		name: "operation",
+		ty: "String",
+		read: "body.kind",
+		write: "event.operation",
+		doc: None,
+	},
+	Field {
+		signal: "Object.TextChanged",
+		position: 3,
+		name: "text",
+		ty: "String",
+		read: "body.any_data.try_into()?",
+		write: "zvariant::Value::from(event.text).into()",
+		doc: None,
+	},
+];
+
+/// Events whose names don't follow from their members, as `(Interface.Member, name)`.
+const NAMES: &[(&str, &str)] = &[
+	("Terminal.ColumncountChanged", "ColumnCountChanged"),
+	("Terminal.LinecountChanged", "LineCountChanged"),
+	("Terminal.CharwidthChanged", "CharWidthChanged"),
+];
+
+/// Events with fields which are not `Eq` and `Hash`; those impls are written by hand.
+const NOT_EQ: &[&str] = &["Object.PropertyChange"];
+
+/// Items added to the module of an interface, for what is written by hand elsewhere.
+const ITEMS: &[(&str, &str)] = &[("Object", "pub use super::property::Property;")];
+
+/// A field of an event, from the XML or from [`FIELDS`].
+struct Part {
+	name: String,
+	ty: String,
+	read: String,
+	write: String,
+	doc: Option<&'static str>,
+}
+
+impl From<&Field> for Part {
+	fn from(field: &Field) -> Self {
+		Self {
+			name: field.name.to_string(),
+			ty: field.ty.to_string(),
+			read: field.read.to_string(),
+			write: field.write.to_string(),
+			doc: field.doc,
+		}
+	}
+}
+
+struct Event {
+	member: String,
+	/// The name of the variant; the struct has `Event` appended.
+	name: String,
+	/// The fields after `item`, by position in the body.
+	fields: Vec<(usize, Part)>,
+	eq: bool,
+}
+
+/// Generates the module of `interface`, an `org.a11y.atspi.Event.*` one.
+pub fn module(interface: &Element) -> Result<Output, String> {
+	let full = interface.attribute("name").ok_or("interface without a name")?;
+	let short = full
+		.strip_prefix(PREFIX)
+		.ok_or_else(|| format!("{full} is not an event interface"))?;
+	let events = interface
+		.children("signal")
+		.map(|signal| event(short, signal))
+		.collect::<Result<Vec<_>, _>>()?;
+	let first = events.first().ok_or_else(|| format!("{full} has no signals"))?;
+	let prefix = interface_prefix(full);
+	let wrapper = format!("{short}Events");
+
+	let mut out = format!(
+		"//! The events of `{full}`.\n\
+		//!\n\
+		//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.\n\n\
+		use crate::{{\n\
+		\terror::AtspiError,\n\
+		\tevents::{{signals, Accessible, EventBodyOwned, GenericEvent, HasMatchRule, HasRegistryEventString}},\n\
+		\tEvent,\n\
+		}};\n\
+		use zvariant::ObjectPath;\n"
+	);
+	for (_, item) in ITEMS.iter().filter(|(name, _)| *name == short) {
+		let _ = writeln!(out, "{item}");
+	}
+	let _ = write!(
+		out,
+		"\n/// The events of `{full}`.\n\
+		#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]\n\
+		pub enum {wrapper} {{\n"
+	);
+	for event in &events {
+		let _ = writeln!(out, "\t{0}({0}Event),", event.name);
+	}
+	let _ = write!(
+		out,
+		"}}\n\
+		impl_event_conversions!({wrapper}, Event::{short});\n\
+		event_wrapper_test_cases!({wrapper}, {first}Event);\n\n\
+		impl HasMatchRule for {wrapper} {{\n\
+		\tconst MATCH_RULE_STRING: &'static str = signals::{prefix}_MATCH_RULE;\n\
+		}}\n",
+		first = first.name,
+	);
+
+	for event in &events {
+		out.push_str(&definition(full, event));
+	}
+
+	for event in &events {
+		out.push_str(&generic_event(short, &prefix, event));
+	}
+
+	let _ = write!(
+		out,
+		"\n#[cfg(feature = \"zbus\")]\n\
+		impl TryFrom<&zbus::Message> for {wrapper} {{\n\
+		\ttype Error = AtspiError;\n\
+		\tfn try_from(ev: &zbus::Message) -> Result<Self, Self::Error> {{\n\
+		\t\tlet member = ev\n\
+		\t\t\t.member()\n\
+		\t\t\t.ok_or(AtspiError::MemberMatch(\"Event without member\".into()))?;\n\
+		\t\tmatch member.as_str() {{\n"
+	);
+	for event in &events {
+		let _ = writeln!(
+			out,
+			"\t\t\t\"{}\" => Ok({wrapper}::{name}(ev.try_into()?)),",
+			event.member,
+			name = event.name
+		);
+	}
+	let _ = write!(
+		out,
+		"\t\t\t_ => Err(AtspiError::MemberMatch(\"No matching member for {short}\".into())),\n\
+		\t\t}}\n\
+		\t}}\n\
+		}}\n"
+	);
+
+	for event in &events {
+		out.push_str(&conversions(short, &wrapper, event));
+	}
+
+	let _ = write!(
+		out,
+		"\nimpl HasRegistryEventString for {wrapper} {{\n\
+		\tconst REGISTRY_EVENT_STRING: &'static str = \"{short}:\";\n\
+		}}\n"
+	);
+	Ok(Output { path: format!("atspi-common/src/events/{}.rs", snake(short)), contents: out })
+}
+
+/// Generates the table of Python event classes, and the conversion of each event to its class,
+/// from all `org.a11y.atspi.Event.*` interfaces.
+pub fn classes(interfaces: &[&Element]) -> Result<Output, String> {
+	let mut table = String::new();
+	let mut arms = String::new();
+	let mut wrappers = Vec::new();
+	for interface in interfaces {
+		let full = interface.attribute("name").ok_or("interface without a name")?;
+		let short = full
+			.strip_prefix(PREFIX)
+			.ok_or_else(|| format!("{full} is not an event interface"))?;
+		let wrapper = format!("{short}Events");
+		for signal in interface.children("signal") {
+			let event = event(short, signal)?;
+			let names: Vec<_> =
+				event.fields.iter().map(|(_, f)| format!("\"{}\"", f.name)).collect();
+			let _ = writeln!(
+				table,
+				"\tEventClass {{ interface: \"{short}\", member: \"{member}\", name: \"{name}Event\", fields: &[{fields}] }},",
+				member = event.member,
+				name = event.name,
+				fields = names.join(", "),
+			);
+			let (binding, values) = if event.fields.is_empty() {
+				("_", String::new())
+			} else {
+				let values: Vec<_> = event
+					.fields
+					.iter()
+					.map(|(_, f)| format!("event.{}.into_field(py, source)", f.name))
+					.collect();
+				("event", values.join(", "))
+			};
+			let _ = writeln!(
+				arms,
+				"\t\tEvent::{short}({wrapper}::{name}({binding})) => (\"{short}\", \"{member}\", vec![{values}]),",
+				member = event.member,
+				name = event.name,
+			);
+		}
+		wrappers.push(format!("{}::{wrapper}", snake(short)));
+	}
+	let out = format!(
+		"//! The classes of `atspi.events`, one for each event of `atspi-common`.\n\
+		//!\n\
+		//! This file is generated from `xml/Event.xml` by `cargo xtask codegen`; do not edit it.\n\n\
+		use crate::{{\n\
+		\taccessible::Accessible,\n\
+		\tevent::{{EventClass, IntoField}},\n\
+		}};\n\
+		use atspi_common::events::{{{wrappers}, Event}};\n\
+		use pyo3::prelude::*;\n\n\
+		/// The class of every event, with the names of its fields after `source`.\n\
+		pub(crate) const CLASSES: &[EventClass] = &[\n{table}];\n\n\
+		/// The interface, member and field values of `event`; `None` if it has no class.\n\
+		#[allow(clippy::too_many_lines)]\n\
+		pub(crate) fn fields(\n\
+		\tpy: Python<'_>,\n\
+		\tsource: &Accessible,\n\
+		\tevent: Event,\n\
+		) -> Option<(&'static str, &'static str, Vec<PyObject>)> {{\n\
+		\tSome(match event {{\n{arms}\t\t_ => return None,\n\
+		\t}})\n\
+		}}\n",
+		wrappers = wrappers.join(", "),
+	);
+	Ok(Output { path: CLASSES_PATH.to_string(), contents: out })
+}
+
+/// The struct of `event`.
+fn definition(full: &str, event: &Event) -> String {
+	let mut out = String::new();
+	let derives = if event.eq {
+		"Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Eq, Hash, Default"
+	} else {
+		"Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Default"
+	};
+	let _ = write!(
+		out,
+		"\n/// The `{full}:{member}` event.\n\
+		#[derive({derives})]\n\
+		pub struct {name}Event {{\n\
+		\tpub item: crate::events::Accessible,\n",
+		member = event.member,
+		name = event.name,
+	);
+	for (_, field) in &event.fields {
+		if let Some(doc) = field.doc {
+			let _ = writeln!(out, "\t/// {doc}");
+		}
+		let _ = writeln!(out, "\tpub {}: {},", field.name, field.ty);
+	}
+	out.push_str("}\n");
+	out
+}
+
+/// The `GenericEvent` impl of `event`.
+fn generic_event(short: &str, prefix: &str, event: &Event) -> String {
+	let mut out = String::new();
+	let constant = format!("{prefix}_{}", screaming_snake(&event.member));
+	let (body, fields) = if event.fields.is_empty() {
+		("_body", String::new())
+	} else {
+		let fields: Vec<_> = event
+			.fields
+			.iter()
+			.map(|(_, f)| format!("{}: {}", f.name, f.read))
+			.collect();
+		("body", format!(", {}", fields.join(", ")))
+	};
+	let _ = write!(
+		out,
+		"\nimpl GenericEvent<'_> for {name}Event {{\n\
+		\tconst DBUS_MEMBER: &'static str = signals::{constant}.member;\n\
+		\tconst DBUS_INTERFACE: &'static str = signals::{constant}.interface;\n\
+		\tconst MATCH_RULE_STRING: &'static str = signals::{constant}.match_rule;\n\
+		\tconst REGISTRY_EVENT_STRING: &'static str = \"{short}:\";\n\n\
+		\ttype Body = EventBodyOwned;\n\n\
+		\tfn build(item: Accessible, {body}: Self::Body) -> Result<Self, AtspiError> {{\n\
+		\t\tOk(Self {{ item{fields} }})\n\
+		\t}}\n\
+		\tfn sender(&self) -> String {{\n\
+		\t\tself.item.name.clone()\n\
+		\t}}\n\
+		\tfn path<'a>(&self) -> ObjectPath<'_> {{\n\
+		\t\tself.item.path.clone().into()\n\
+		\t}}\n\
+		\tfn body(&self) -> Self::Body {{\n\
+		\t\tlet copy = self.clone();\n\
+		\t\tcopy.into()\n\
+		\t}}\n\
+		}}\n",
+		name = event.name,
+	);
+	out
+}
+
+/// The conversions of `event` to the wrapper enums, to messages and to the body.
+fn conversions(short: &str, wrapper: &str, event: &Event) -> String {
+	let mut out = String::new();
+	let name = format!("{}Event", event.name);
+	let parameter = if event.fields.is_empty() { "_event" } else { "event" };
+	let slot = |position: usize| {
+		event
+			.fields
+			.iter()
+			.find(|(p, _)| *p == position)
+			.map(|(_, field)| field.write.as_str())
+	};
+	let _ = write!(
+		out,
+		"\nimpl_event_conversions!({name}, {wrapper}, {wrapper}::{variant}, Event::{short});\n\
+		event_test_cases!({name});\n\
+		impl_to_dbus_message!({name});\n\
+		impl_from_dbus_message!({name});\n\
+		impl From<{name}> for EventBodyOwned {{\n\
+		\tfn from({parameter}: {name}) -> Self {{\n\
+		\t\tEventBodyOwned {{\n\
+		\t\t\tproperties: std::collections::HashMap::new(),\n\
+		\t\t\tkind: {kind},\n\
+		\t\t\tdetail1: {detail1},\n\
+		\t\t\tdetail2: {detail2},\n\
+		\t\t\tany_data: {any_data},\n\
+		\t\t}}\n\
+		\t}}\n\
+		}}\n",
+		variant = event.name,
+		kind = slot(0).unwrap_or("String::default()"),
+		detail1 = slot(1).unwrap_or("i32::default()"),
+		detail2 = slot(2).unwrap_or("i32::default()"),
+		any_data = slot(3).unwrap_or("zvariant::Value::U8(0).into()"),
+	);
+	out
+}
+
+fn event(interface: &str, signal: &Element) -> Result<Event, String> {
+	let member = signal
+		.attribute("name")
+		.ok_or_else(|| format!("signal of {interface} without a name"))?;
+	let key = format!("{interface}.{member}");
+	let name = NAMES.iter().find(|(k, _)| *k == key).map_or_else(
+		|| {
+			let mut chars = member.chars();
+			chars
+				.next()
+				.map(|c| c.to_uppercase().chain(chars).collect())
+				.unwrap_or_default()
+		},
+		|(_, name)| (*name).to_string(),
+	);
+	let mut fields = Vec::new();
+	for (position, arg) in signal.children("arg").enumerate().take(SLOTS.len()) {
+		if let Some(field) = FIELDS.iter().find(|f| f.signal == key && f.position == position) {
+			fields.push((position, field.into()));
+			continue;
+		}
+		let Some(name) = arg.attribute("name") else { continue };
+		let (ty, read) = match arg.attribute("type") {
+			Some("s") => ("String", format!("body.{}", SLOTS[position])),
+			Some("i") => ("i32", format!("body.{}", SLOTS[position])),
+			ty => return Err(format!("{key}: `{name}` of type {ty:?} needs an entry in FIELDS")),
+		};
+		fields.push((
+			position,
+			Part {
+				name: name.to_string(),
+				ty: ty.to_string(),
+				read,
+				write: format!("event.{name}"),
+				doc: None,
+			},
+		));
+	}
+	let eq = !NOT_EQ.contains(&key.as_str());
+	Ok(Event { member: member.to_string(), name, fields, eq })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::xml;
+
+	fn interface(signal: &str) -> Element {
+		let node = xml::parse(&format!(
+			"<node><interface name=\"org.a11y.atspi.Event.Test\">{signal}</interface></node>"
+		))
+		.unwrap();
+		node.children.into_iter().next().unwrap()
+	}
+
+	#[test]
+	fn takes_fields_from_argument_names() {
+		let module = module(&interface(
+			r#"<signal name="Moved"><arg name="how" type="s"/><arg type="i"/><arg name="y" type="i"/><arg type="v"/></signal>"#,
+		))
+		.unwrap();
+		assert_eq!(module.path, "atspi-common/src/events/test.rs");
+		assert!(module.contents.contains("pub how: String,\n\tpub y: i32,\n}"));
+		assert!(module
+			.contents
+			.contains("Ok(Self { item, how: body.kind, y: body.detail2 })"));
+	}
+
+	#[test]
+	fn lists_the_fields_of_python_classes() {
+		let interface = interface(
+			r#"<signal name="Moved"><arg name="how" type="s"/><arg type="i"/><arg name="y" type="i"/><arg type="v"/></signal>"#,
+		);
+		let classes = classes(&[&interface]).unwrap();
+		assert_eq!(classes.path, "atspi-python/src/event_classes.rs");
+		assert!(classes.contents.contains(
+			"EventClass { interface: \"Test\", member: \"Moved\", name: \"MovedEvent\", fields: &[\"how\", \"y\"] },"
+		));
+		assert!(classes.contents.contains("Event::Test(TestEvents::Moved(event)) => (\"Test\", \"Moved\", vec![event.how.into_field(py, source), event.y.into_field(py, source)]),"));
+	}
+
+	#[test]
+	fn wants_a_type_for_named_values() {
+		let error = module(&interface(
+			r#"<signal name="Changed"><arg type="s"/><arg type="i"/><arg type="i"/><arg name="thing" type="v"/></signal>"#,
+		))
+		.err()
+		.unwrap();
+		assert!(error.contains("needs an entry in FIELDS"), "{error}");
+	}
+}
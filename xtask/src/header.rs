@@ -0,0 +1,24 @@
+//! Generates the C header of `atspi-ffi` from its sources, with `cbindgen` and the settings in
+//! `atspi-ffi/cbindgen.toml`.
+
+use crate::codegen::Output;
+use std::path::Path;
+
+/// Where the header goes, relative to the root of the workspace.
+const HEADER_PATH: &str = "atspi-ffi/include/atspi.h";
+
+/// Generates `include/atspi.h` from the exported items of `atspi-ffi`.
+pub fn header(root: &Path) -> Result<Output, String> {
+	let crate_dir = root.join("atspi-ffi");
+	let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+		.map_err(|e| format!("atspi-ffi/cbindgen.toml: {e}"))?;
+	let bindings = cbindgen::Builder::new()
+		.with_crate(&crate_dir)
+		.with_config(config)
+		.generate()
+		.map_err(|e| format!("generating {HEADER_PATH}: {e}"))?;
+	let mut contents = Vec::new();
+	bindings.write(&mut contents);
+	let contents = String::from_utf8(contents).map_err(|e| e.to_string())?;
+	Ok(Output { path: HEADER_PATH.to_string(), contents })
+}
@@ -0,0 +1,62 @@
+#![deny(clippy::all, clippy::pedantic, clippy::cargo, unsafe_code)]
+#![allow(clippy::multiple_crate_versions)]
+
+//! Development tasks for the workspace.
+//!
+//! * `cargo xtask codegen` generates code from the introspection XML in `xml/`, and the C header
+//!   of `atspi-ffi`;
+//! * `cargo xtask codegen --check` fails when the checked-in code differs from what would be
+//!   generated, without writing anything.
+
+mod codegen;
+mod events;
+mod header;
+mod proxies;
+mod xml;
+
+use std::{fs, path::Path, process::ExitCode};
+
+const USAGE: &str = "usage: cargo xtask codegen [--check]";
+
+fn main() -> ExitCode {
+	let args: Vec<String> = std::env::args().skip(1).collect();
+	let check = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+		["codegen"] => false,
+		["codegen", "--check"] => true,
+		_ => {
+			eprintln!("{USAGE}");
+			return ExitCode::FAILURE;
+		}
+	};
+	match codegen(check) {
+		Ok(true) => ExitCode::SUCCESS,
+		Ok(false) => ExitCode::FAILURE,
+		Err(e) => {
+			eprintln!("error: {e}");
+			ExitCode::FAILURE
+		}
+	}
+}
+
+/// Generates the code, or checks it is up to date; returns whether it was.
+fn codegen(check: bool) -> Result<bool, String> {
+	let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.parent()
+		.ok_or("xtask is not in a workspace")?;
+	let mut up_to_date = true;
+	for output in codegen::generate(root)? {
+		let path = root.join(&output.path);
+		let current = fs::read_to_string(&path).unwrap_or_default();
+		if current == output.contents {
+			continue;
+		}
+		if check {
+			eprintln!("{} is out of date, run `cargo xtask codegen`", output.path);
+			up_to_date = false;
+		} else {
+			fs::write(&path, output.contents).map_err(|e| format!("{}: {e}", path.display()))?;
+			println!("generated {}", output.path);
+		}
+	}
+	Ok(up_to_date)
+}
@@ -0,0 +1,380 @@
+//! Generates the proxy traits in `atspi-proxies/src/` from the interfaces in `xml/`.
+//!
+//! Only the `trait` of each proxy is generated; the rest of its file is written by hand, and so are
+//! the comments above the methods of the trait, which are kept as they are. Methods without any get
+//! the one-line doc `zbus-xmlgen` would write.
+
+use crate::{
+	codegen::{snake, Output},
+	xml::Element,
+};
+use std::{collections::HashMap, fmt::Write, fs, path::Path};
+
+const PREFIX: &str = "org.a11y.atspi.";
+
+/// Rust types for what the XML only gives a D-Bus type for, as `(place, type)`.
+///
+/// A place is `Interface.Member.argument` for an argument, `Interface.Member` for the result of a
+/// method or the type of a property, and `*.argument` for an argument of any method.
+const TYPES: &[(&str, &str)] = &[
+	("*.coord_type", "CoordType"),
+	("*.granularity", "Granularity"),
+	("*.x_clip_type", "ClipType"),
+	("*.y_clip_type", "ClipType"),
+	("Accessible.GetInterfaces", "InterfaceSet"),
+	("Accessible.GetRelationSet", "Vec<(RelationType, Vec<Accessible>)>"),
+	("Accessible.GetRole", "Role"),
+	("Accessible.GetState", "StateSet"),
+	("Cache.GetItems", "Vec<CacheItem>"),
+	("Collection.GetMatches.rule", "&MatchArgs<'_>"),
+	("Collection.GetMatches.sortby", "SortOrder"),
+	("Collection.GetMatchesFrom.rule", "&MatchArgs<'_>"),
+	("Collection.GetMatchesFrom.sortby", "SortOrder"),
+	("Collection.GetMatchesFrom.tree", "TreeTraversalType"),
+	("Collection.GetMatchesTo.rule", "&MatchArgs<'_>"),
+	("Collection.GetMatchesTo.sortby", "SortOrder"),
+	("Collection.GetMatchesTo.tree", "TreeTraversalType"),
+	("Component.GetLayer", "Layer"),
+	("Component.ScrollTo.type_", "ScrollType"),
+	("DeviceEventController.DeregisterKeystrokeListener.keys", "&[KeyDefinition<'_>]"),
+	("DeviceEventController.DeregisterKeystrokeListener.type_", "EventType"),
+	("DeviceEventController.GenerateKeyboardEvent.type_", "KeySynthType"),
+	("DeviceEventController.GetKeystrokeListeners", "Vec<KeystrokeListener>"),
+	("DeviceEventController.NotifyListenersAsync.event", "&DeviceEvent<'_>"),
+	("DeviceEventController.NotifyListenersSync.event", "&DeviceEvent<'_>"),
+	("DeviceEventController.RegisterKeystrokeListener.keys", "&[KeyDefinition<'_>]"),
+	("DeviceEventController.RegisterKeystrokeListener.mode", "&EventListenerMode"),
+	("DeviceEventListener.NotifyEvent.event", "&DeviceEvent<'_>"),
+	("Registry.GetRegisteredEvents", "Vec<(OwnedBusName, String)>"),
+	("Text.GetTextAfterOffset.type_", "TextBoundaryType"),
+	("Text.GetTextAtOffset.type_", "TextBoundaryType"),
+	("Text.GetTextBeforeOffset.type_", "TextBoundaryType"),
+	("Text.ScrollSubstringTo.type_", "ScrollType"),
+	("Text.ScrollSubstringToPoint.type_", "CoordType"),
+];
+
+/// Methods whose Rust names don't follow from their members, as `(Interface.Member, name)`.
+const NAMES: &[(&str, &str)] = &[("Registry.GetRegisteredEvents", "registered_events")];
+
+/// Trailing arguments left out, as `(Interface.Member, argument)`: the registry takes the call
+/// without them as well.
+const OMITTED: &[(&str, &str)] =
+	&[("Registry.RegisterEvent", "properties"), ("Registry.RegisterEvent", "app_bus_name")];
+
+/// Methods the XML doesn't declare, as `(Interface, member, method)`; they are sorted in by member.
+const EXTRA: &[(&str, &str, &str)] = &[
+	(
+		"Cache",
+		"GetItems",
+		"#[dbus_proxy(name = \"GetItems\")]\n\
+		fn get_legacy_items(&self) -> zbus::Result<Vec<LegacyCacheItem>>;",
+	),
+	(
+		"DeviceEventController",
+		"DeregisterDeviceEventListener",
+		"fn deregister_device_event_listener(&self, listener: &zbus::zvariant::ObjectPath<'_>, types: EventType) -> zbus::Result<()>;",
+	),
+	(
+		"DeviceEventController",
+		"RegisterDeviceEventListener",
+		"fn register_device_event_listener(&self, listener: &zbus::zvariant::ObjectPath<'_>, types: EventType) -> zbus::Result<bool>;",
+	),
+];
+
+/// A method of a trait, without its doc comment.
+struct Item {
+	member: String,
+	name: String,
+	/// The `#[dbus_proxy]` attribute and the signature.
+	code: String,
+	/// The doc comment to use when the checked-in file has none.
+	doc: String,
+}
+
+/// Generates the proxy of `interface`, keeping what is written by hand in its checked-in file.
+pub fn proxy(root: &Path, interface: &Element) -> Result<Output, String> {
+	let full = interface.attribute("name").ok_or("interface without a name")?;
+	let short = full
+		.strip_prefix(PREFIX)
+		.ok_or_else(|| format!("{full} is not an AT-SPI one"))?;
+	let path = format!("atspi-proxies/src/{}.rs", snake(short));
+	let file = fs::read_to_string(root.join(&path)).map_err(|e| format!("{path}: {e}"))?;
+
+	let lines: Vec<&str> = file.lines().collect();
+	let start = lines
+		.iter()
+		.position(|line| *line == format!("trait {short} {{"))
+		.ok_or_else(|| format!("{path}: no `trait {short}`"))?;
+	let end = start
+		+ lines[start..]
+			.iter()
+			.position(|line| *line == "}")
+			.ok_or_else(|| format!("{path}: `trait {short}` is not closed"))?;
+	let comments = comments(&lines[start + 1..end]);
+
+	let mut methods = Vec::new();
+	for method in interface.children("method") {
+		methods.push(method_item(short, method)?);
+	}
+	for (_, member, code) in EXTRA.iter().filter(|(i, _, _)| *i == short) {
+		let name = code
+			.split("fn ")
+			.nth(1)
+			.and_then(|rest| rest.split('(').next())
+			.ok_or_else(|| format!("{short}.{member}: extra method without a name"))?;
+		methods.push(Item {
+			member: (*member).to_string(),
+			name: name.to_string(),
+			code: (*code).to_string(),
+			doc: format!("{member} method"),
+		});
+	}
+	methods.sort_by(|a, b| a.member.cmp(&b.member));
+	let mut properties = Vec::new();
+	for property in interface.children("property") {
+		properties.extend(property_items(short, property)?);
+	}
+	properties.sort_by(|a, b| a.member.cmp(&b.member));
+
+	let mut out = String::new();
+	for line in &lines[..start] {
+		let _ = writeln!(out, "{line}");
+	}
+	let _ = writeln!(out, "trait {short} {{");
+	let items: Vec<_> = methods.iter().chain(&properties).collect();
+	for (index, item) in items.iter().enumerate() {
+		if index > 0 {
+			out.push('\n');
+		}
+		match comments.get(&item.name) {
+			Some(lines) if !lines.is_empty() => {
+				for line in lines {
+					let _ = writeln!(out, "\t{line}");
+				}
+			}
+			_ => {
+				let _ = writeln!(out, "\t/// {}", item.doc);
+			}
+		}
+		for line in item.code.lines() {
+			let _ = writeln!(out, "\t{line}");
+		}
+	}
+	out.push_str("}\n");
+	for line in &lines[end + 1..] {
+		let _ = writeln!(out, "{line}");
+	}
+	Ok(Output { path, contents: out })
+}
+
+/// The comment lines above each method of a trait body, by the name of the method.
+fn comments<'a>(body: &[&'a str]) -> HashMap<String, Vec<&'a str>> {
+	let mut comments = HashMap::new();
+	let mut pending = Vec::new();
+	let mut in_signature = false;
+	for line in body {
+		let trimmed = line.trim();
+		if in_signature {
+			in_signature = !trimmed.ends_with(';');
+		} else if trimmed.is_empty() {
+			pending.clear();
+		} else if let Some(rest) = trimmed.strip_prefix("fn ") {
+			let name = rest.split('(').next().unwrap_or_default();
+			comments.insert(name.to_string(), std::mem::take(&mut pending));
+			in_signature = !trimmed.ends_with(';');
+		} else if !trimmed.starts_with("#[dbus_proxy") {
+			pending.push(line.strip_prefix('\t').unwrap_or(line));
+		}
+	}
+	comments
+}
+
+fn method_item(interface: &str, method: &Element) -> Result<Item, String> {
+	let member = method
+		.attribute("name")
+		.ok_or_else(|| format!("method of {interface} without a name"))?;
+	let place = format!("{interface}.{member}");
+	let name = NAMES
+		.iter()
+		.find(|(p, _)| *p == place)
+		.map_or_else(|| snake(member), |(_, name)| (*name).to_string());
+	let mut arguments = vec!["&self".to_string()];
+	let mut results = Vec::new();
+	for arg in method.children("arg") {
+		let ty = arg
+			.attribute("type")
+			.ok_or_else(|| format!("{place}: argument without a type"))?;
+		if arg.attribute("direction") == Some("out") {
+			results.push(rust_type(ty, false)?);
+			continue;
+		}
+		let xml_name = arg
+			.attribute("name")
+			.ok_or_else(|| format!("{place}: argument without a name"))?;
+		let mut argument = snake(xml_name);
+		if OMITTED.contains(&(place.as_str(), argument.as_str())) {
+			continue;
+		}
+		if argument == "type" {
+			argument.push('_');
+		}
+		let ty = match lookup(&format!("{place}.{argument}"))
+			.or_else(|| lookup(&format!("*.{argument}")))
+		{
+			Some(ty) => ty.to_string(),
+			None => rust_type(ty, true)?,
+		};
+		arguments.push(format!("{argument}: {ty}"));
+	}
+	let result = match lookup(&place) {
+		Some(ty) => ty.to_string(),
+		None if results.len() == 1 => results.remove(0),
+		None => format!("({})", results.join(", ")),
+	};
+	let mut code = String::new();
+	if name != snake(member) || pascal(&name) != member {
+		let _ = writeln!(code, "#[dbus_proxy(name = \"{member}\")]");
+	}
+	let _ = write!(code, "fn {name}({}) -> zbus::Result<{result}>;", arguments.join(", "));
+	Ok(Item { member: member.to_string(), name, code, doc: format!("{member} method") })
+}
+
+/// The getter of a property, and its setter if it can be written.
+fn property_items(interface: &str, property: &Element) -> Result<Vec<Item>, String> {
+	let member = property
+		.attribute("name")
+		.ok_or_else(|| format!("property of {interface} without a name"))?;
+	let place = format!("{interface}.{member}");
+	let xml_type = property
+		.attribute("type")
+		.ok_or_else(|| format!("{place} without a type"))?;
+	let name = snake(member);
+	let attribute = if pascal(&name) == member {
+		"#[dbus_proxy(property)]".to_string()
+	} else {
+		format!("#[dbus_proxy(property, name = \"{member}\")]")
+	};
+	let ty = match lookup(&place) {
+		Some(ty) => ty.to_string(),
+		None => rust_type(xml_type, false)?,
+	};
+	let mut items = vec![Item {
+		member: member.to_string(),
+		name: name.clone(),
+		code: format!("{attribute}\nfn {name}(&self) -> zbus::Result<{ty}>;"),
+		doc: format!("{member} property"),
+	}];
+	if property.attribute("access") == Some("readwrite") {
+		let ty = rust_type(xml_type, true)?;
+		items.push(Item {
+			member: member.to_string(),
+			name: format!("set_{name}"),
+			code: format!("{attribute}\nfn set_{name}(&self, value: {ty}) -> zbus::Result<()>;"),
+			doc: format!("Set {member} property"),
+		});
+	}
+	Ok(items)
+}
+
+fn lookup(place: &str) -> Option<&'static str> {
+	TYPES.iter().find(|(p, _)| *p == place).map(|(_, ty)| *ty)
+}
+
+/// The Rust type for D-Bus signature `signature`, as an argument if `input`, else as a result.
+fn rust_type(signature: &str, input: bool) -> Result<String, String> {
+	let mut rest = signature;
+	let ty = parse_type(&mut rest, input, true)?;
+	if rest.is_empty() {
+		Ok(ty)
+	} else {
+		Err(format!("`{signature}` is not a single type"))
+	}
+}
+
+fn parse_type(rest: &mut &str, input: bool, outer: bool) -> Result<String, String> {
+	let signature = *rest;
+	let mut chars = signature.chars();
+	let code = chars.next().ok_or("signature ends early")?;
+	*rest = chars.as_str();
+	// References to accessible objects, `(so)`, are `Accessible`s when they are returned.
+	if !input && signature.starts_with("(so)") {
+		*rest = &signature[4..];
+		return Ok("Accessible".to_string());
+	}
+	let borrowed = |ty: &str| if input && outer { format!("&{ty}") } else { ty.to_string() };
+	let ty = match code {
+		'y' => "u8".to_string(),
+		'b' => "bool".to_string(),
+		'n' => "i16".to_string(),
+		'q' => "u16".to_string(),
+		'i' => "i32".to_string(),
+		'u' => "u32".to_string(),
+		'x' => "i64".to_string(),
+		't' => "u64".to_string(),
+		'd' => "f64".to_string(),
+		's' if input => "&str".to_string(),
+		's' => "String".to_string(),
+		'o' if input => borrowed("zbus::zvariant::ObjectPath<'_>"),
+		'o' => "zbus::zvariant::OwnedObjectPath".to_string(),
+		'v' if input => borrowed("zbus::zvariant::Value<'_>"),
+		'v' => "zbus::zvariant::OwnedValue".to_string(),
+		'a' if rest.starts_with('{') => {
+			*rest = &rest[1..];
+			let key = parse_type(rest, input, false)?;
+			let value = parse_type(rest, input, false)?;
+			*rest = rest.strip_prefix('}').ok_or("unterminated dictionary")?;
+			format!("std::collections::HashMap<{key}, {value}>")
+		}
+		'a' => {
+			let element = parse_type(rest, input, false)?;
+			if input {
+				format!("&[{element}]")
+			} else {
+				format!("Vec<{element}>")
+			}
+		}
+		'(' => {
+			let mut fields = Vec::new();
+			while !rest.starts_with(')') {
+				fields.push(parse_type(rest, input, false)?);
+			}
+			*rest = &rest[1..];
+			borrowed(&format!("({})", fields.join(", ")))
+		}
+		_ => return Err(format!("unsupported type `{code}` in `{signature}`")),
+	};
+	Ok(ty)
+}
+
+/// The member `zbus` derives from a method name: `get_nselections` becomes `GetNselections`.
+fn pascal(name: &str) -> String {
+	name.split('_')
+		.map(|part| {
+			let mut chars = part.chars();
+			chars
+				.next()
+				.map(|c| c.to_uppercase().chain(chars).collect::<String>())
+				.unwrap_or_default()
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn maps_types() {
+		assert_eq!(rust_type("a{ss}", false).unwrap(), "std::collections::HashMap<String, String>");
+		assert_eq!(rust_type("(so)", true).unwrap(), "&(&str, zbus::zvariant::ObjectPath<'_>)");
+		assert_eq!(rust_type("a(so)", false).unwrap(), "Vec<Accessible>");
+		assert_eq!(rust_type("as", true).unwrap(), "&[&str]");
+		assert!(rust_type("ii", false).is_err());
+	}
+
+	#[test]
+	fn names_methods() {
+		assert_eq!(snake("xClipType"), "x_clip_type");
+		assert_eq!(pascal("get_nselections"), "GetNselections");
+	}
+}
@@ -0,0 +1,175 @@
+//! Just enough of an XML reader for D-Bus introspection data.
+//!
+//! Elements and their attributes are kept; text, comments, processing instructions and
+//! doctype declarations are skipped.
+
+/// An element and the elements nested in it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Element {
+	pub name: String,
+	pub attributes: Vec<(String, String)>,
+	pub children: Vec<Element>,
+}
+
+impl Element {
+	/// The value of attribute `name`.
+	pub fn attribute(&self, name: &str) -> Option<&str> {
+		self.attributes
+			.iter()
+			.find(|(n, _)| n == name)
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// The nested elements called `name`.
+	pub fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+		self.children.iter().filter(move |child| child.name == name)
+	}
+}
+
+/// Parses a document, returning its root element.
+pub fn parse(text: &str) -> Result<Element, String> {
+	// The bottom of the stack collects the root element.
+	let mut stack =
+		vec![Element { name: String::new(), attributes: Vec::new(), children: Vec::new() }];
+	let mut rest = text;
+	while let Some(start) = rest.find('<') {
+		rest = &rest[start..];
+		if let Some(after) = rest.strip_prefix("<!--") {
+			rest = skip_past(after, "-->")?;
+		} else if let Some(after) = rest.strip_prefix("<?") {
+			rest = skip_past(after, "?>")?;
+		} else if let Some(after) = rest.strip_prefix("<!") {
+			rest = skip_past(after, ">")?;
+		} else if let Some(after) = rest.strip_prefix("</") {
+			let end = after.find('>').ok_or("unterminated closing tag")?;
+			let name = after[..end].trim();
+			rest = &after[end + 1..];
+			let element = stack
+				.pop()
+				.filter(|_| !stack.is_empty())
+				.ok_or("unexpected closing tag")?;
+			if element.name != name {
+				return Err(format!("`</{name}>` closes `<{}>`", element.name));
+			}
+			stack
+				.last_mut()
+				.expect("the root collector stays")
+				.children
+				.push(element);
+		} else {
+			let (tag, after) = split_tag(&rest[1..])?;
+			rest = after;
+			let (tag, empty) = match tag.strip_suffix('/') {
+				Some(tag) => (tag, true),
+				None => (tag, false),
+			};
+			let element = parse_tag(tag)?;
+			if empty {
+				stack
+					.last_mut()
+					.expect("the root collector stays")
+					.children
+					.push(element);
+			} else {
+				stack.push(element);
+			}
+		}
+	}
+	if stack.len() != 1 {
+		return Err(format!("`<{}>` is not closed", stack[stack.len() - 1].name));
+	}
+	let mut roots = stack.pop().expect("the root collector stays").children;
+	match roots.len() {
+		1 => Ok(roots.remove(0)),
+		n => Err(format!("expected one root element, found {n}")),
+	}
+}
+
+fn skip_past<'a>(text: &'a str, end: &str) -> Result<&'a str, String> {
+	let index = text.find(end).ok_or_else(|| format!("missing `{end}`"))?;
+	Ok(&text[index + end.len()..])
+}
+
+/// Splits off the inside of a tag, up to the `>` which is not in a quoted attribute value.
+fn split_tag(text: &str) -> Result<(&str, &str), String> {
+	let mut quote = None;
+	for (index, c) in text.char_indices() {
+		match (quote, c) {
+			(None, '"' | '\'') => quote = Some(c),
+			(Some(q), _) if q == c => quote = None,
+			(None, '>') => return Ok((text[..index].trim(), &text[index + 1..])),
+			_ => {}
+		}
+	}
+	Err("unterminated tag".to_string())
+}
+
+fn parse_tag(tag: &str) -> Result<Element, String> {
+	let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+	let mut element =
+		Element { name: tag[..name_end].to_string(), attributes: Vec::new(), children: Vec::new() };
+	let mut rest = tag[name_end..].trim_start();
+	while !rest.is_empty() {
+		let equals = rest
+			.find('=')
+			.ok_or_else(|| format!("attribute without value in `<{tag}>`"))?;
+		let name = rest[..equals].trim().to_string();
+		let value = rest[equals + 1..].trim_start();
+		let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''));
+		let quote = quote.ok_or_else(|| format!("unquoted attribute `{name}` in `<{tag}>`"))?;
+		let end = value[1..]
+			.find(quote)
+			.ok_or_else(|| format!("unterminated `{name}` in `<{tag}>`"))?;
+		element.attributes.push((name, unescape(&value[1..=end])));
+		rest = value[end + 2..].trim_start();
+	}
+	Ok(element)
+}
+
+fn unescape(value: &str) -> String {
+	value
+		.replace("&lt;", "<")
+		.replace("&gt;", ">")
+		.replace("&quot;", "\"")
+		.replace("&apos;", "'")
+		.replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_introspection_data() {
+		let root = parse(
+			r#"<?xml version="1.0"?>
+			<!DOCTYPE node>
+			<node>
+			  <!-- <interface name="commented out"/> -->
+			  <interface name='org.example.Thing'>
+			    <signal name="Changed">
+			      <arg type="a{sv}"/>
+			      <annotation name="org.example.Type" value="QMap&lt;a, b&gt;"/>
+			    </signal>
+			  </interface>
+			</node>"#,
+		)
+		.unwrap();
+		assert_eq!(root.name, "node");
+		let interfaces: Vec<_> = root.children("interface").collect();
+		assert_eq!(interfaces.len(), 1);
+		assert_eq!(interfaces[0].attribute("name"), Some("org.example.Thing"));
+		let signal = interfaces[0].children("signal").next().unwrap();
+		assert_eq!(signal.children("arg").next().unwrap().attribute("type"), Some("a{sv}"));
+		let annotation = signal.children("annotation").next().unwrap();
+		assert_eq!(annotation.attribute("value"), Some("QMap<a, b>"));
+	}
+
+	#[test]
+	fn rejects_malformed_documents() {
+		assert!(parse("<node><interface></node>").is_err());
+		assert!(parse("<node>").is_err());
+		assert!(parse("<node/><node/>").is_err());
+		assert!(parse("<node name=unquoted/>").is_err());
+	}
+}